@@ -45,7 +45,7 @@ pub mod pallet {
     use frame_system::{ensure_root, pallet_prelude::*};
     use node_primitives::{
         deeper_node::NodeInterface,
-        user_privileges::{Privilege, UserPrivilegeInterface},
+        user_privileges::{Privilege, QuotaResource, UserPrivilegeInterface},
         DPR,
     };
 
@@ -618,6 +618,12 @@ pub mod pallet {
                 T::UserPrivilegeInterface::has_privilege(&who, Privilege::CreditAdmin),
                 Error::<T>::NotAdmin
             );
+            T::UserPrivilegeInterface::consume_quota(
+                &who,
+                Privilege::CreditAdmin,
+                QuotaResource::AdscMint,
+                amount.unique_saturated_into(),
+            )?;
             T::AdscCurrency::mint_into(T::AdscId::get(), &to, amount)?;
             Ok(())
         }
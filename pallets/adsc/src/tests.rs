@@ -29,13 +29,13 @@ use pallet_user_privileges::H160;
 use sp_core::H256;
 use sp_runtime::{
     traits::{BlakeTwo256, IdentityLookup},
-    BuildStorage, Perbill,
+    BuildStorage, DispatchResult, Perbill,
 };
 
 use super::*;
 use crate::{self as pallet_adsc};
 use node_primitives::{
-    user_privileges::{Privilege, UserPrivilegeInterface},
+    user_privileges::{Privilege, QuotaResource, UserPrivilegeInterface},
     BlockNumber, Moment, DPR,
 };
 
@@ -110,6 +110,7 @@ parameter_types! {
     pub const MinLockAmt: u32 = 100;
     pub const MaxDurationEras: u8 = 7;
     pub const MaxIpLength: usize = 256;
+    pub const ReputationDecay: sp_runtime::Perbill = sp_runtime::Perbill::from_percent(90);
 }
 impl pallet_deeper_node::Config for Test {
     type RuntimeEvent = RuntimeEvent;
@@ -120,6 +121,11 @@ impl pallet_deeper_node::Config for Test {
     type MaxIpLength = MaxIpLength;
     type WeightInfo = ();
     type VerifySignatureInterface = ();
+    type RegionOrigin = frame_system::EnsureRoot<Self::AccountId>;
+    type DeviceSignature = sp_runtime::testing::TestSignature;
+    type UnsignedPriority = frame_support::traits::ConstU64<700>;
+    type MaxCreditProofs = frame_support::traits::ConstU32<4>;
+    type ReputationDecay = ReputationDecay;
 }
 
 pub const MILLISECS_PER_BLOCK: Moment = 5000;
@@ -503,4 +509,17 @@ impl UserPrivilegeInterface<u64> for U128FakeUserPrivilege {
     fn has_evm_privilege(_user: &H160, _p: Privilege) -> bool {
         true
     }
+
+    fn has_approved_privilege(user: &u64, p: Privilege) -> bool {
+        Self::has_privilege(user, p)
+    }
+
+    fn consume_quota(
+        _user: &u64,
+        _p: Privilege,
+        _resource: QuotaResource,
+        _amount: u128,
+    ) -> DispatchResult {
+        Ok(())
+    }
 }
@@ -20,10 +20,15 @@
 use super::*;
 use crate::Pallet as CreditAccumulation;
 pub use frame_benchmarking::{account, benchmarks, whitelist_account, whitelisted_caller};
-use frame_support::{assert_ok, traits::Currency};
-use frame_system::RawOrigin;
-use hex_literal::hex;
-use node_primitives::AccountCreator;
+use frame_support::{
+    assert_ok,
+    traits::{Currency, Get},
+    BoundedVec,
+};
+use frame_system::{pallet_prelude::BlockNumberFor, RawOrigin};
+use node_primitives::{user_privileges::Privilege, AccountCreator, AtmosPurpose};
+use sp_io::crypto::{sr25519_generate, sr25519_sign};
+use sp_runtime::traits::StaticLookup;
 
 pub fn create_funded_user<T: Config>(string: &'static str, balance_factor: u32) -> T::AccountId {
     let user = T::AccountCreator::create_account(string);
@@ -33,33 +38,158 @@ pub fn create_funded_user<T: Config>(string: &'static str, balance_factor: u32)
     user
 }
 
-benchmarks! {
-    add_credit_by_traffic {
-        let alice = create_funded_user::<T>("Alice", 100);
-        let bob = create_funded_user::<T>("Bob", 100);
-        // OK
-        assert_ok!(CreditAccumulation::<T>::set_atmos_pubkey(
+/// Sign `message` with a keystore key, adding the key to the atomos signer set first
+pub fn atmos_signature<T: Config>(message: &AtmosMessageOf<T>) -> Vec<u8> {
+    let public = sr25519_generate(0.into(), Some(b"//Atmos".to_vec()));
+    let key = T::AccountId::decode(&mut &public.encode()[..]).expect("32 byte account id");
+    if !CreditAccumulation::<T>::atmos_signers()
+        .iter()
+        .any(|signer| signer.key == key)
+    {
+        assert_ok!(CreditAccumulation::<T>::add_atmos_signer(
             RawOrigin::Root.into(),
-            bob,
+            key,
+            0u32.into(),
+            None,
         ));
+    }
+    let hash = sp_io::hashing::blake2_256(&message.encode());
+    sr25519_sign(0.into(), &public, &hash)
+        .expect("keystore key should sign")
+        .encode()
+}
+
+benchmarks! {
+    where_clause { where T: pallet_user_privileges::Config }
 
-        // OK
+    add_credit_by_traffic {
+        let alice = create_funded_user::<T>("Alice", 100);
         let nonce: u64 = 0;
-        let signature: [u8; 64] = hex!("5071a1a526b1d2d1833e4de43d1ce22ad3506de2e10ee4a9c18c0b310c54286b9cb10bfb4ee12be6b93e91337de0fa2ea2edd787d083db36211109bdc8438989");
-    }: _(RawOrigin::Signed(alice.clone()), nonce, signature.into())
+        let valid_until: BlockNumberFor<T> = 100u32.into();
+        let message = CreditAccumulation::<T>::atmos_message(
+            AtmosPurpose::Traffic,
+            nonce,
+            valid_until,
+            sp_io::hashing::blake2_256(&[]),
+            alice.clone(),
+        );
+        let signature = atmos_signature::<T>(&message);
+    }: _(RawOrigin::Signed(alice.clone()), nonce, valid_until, signature)
     verify {
         assert_eq!(
             CreditAccumulation::<T>::atmos_nonce(alice), Some(1)
         );
     }
 
-    set_atmos_pubkey {
+    add_atmos_signer {
+        for i in 1..T::MaxAtmosSigners::get() {
+            let signer: T::AccountId = account("signer", i, 0);
+            assert_ok!(CreditAccumulation::<T>::add_atmos_signer(
+                RawOrigin::Root.into(),
+                signer,
+                0u32.into(),
+                None,
+            ));
+        }
+        let bob = create_funded_user::<T>("Bob", 100);
+    }: _(RawOrigin::Root, bob.clone(), 0u32.into(), None)
+    verify {
+        assert!(CreditAccumulation::<T>::atmos_signers().iter().any(|signer| signer.key == bob));
+    }
+
+    retire_atmos_signer {
+        for i in 1..T::MaxAtmosSigners::get() {
+            let signer: T::AccountId = account("signer", i, 0);
+            assert_ok!(CreditAccumulation::<T>::add_atmos_signer(
+                RawOrigin::Root.into(),
+                signer,
+                0u32.into(),
+                None,
+            ));
+        }
         let bob = create_funded_user::<T>("Bob", 100);
+        assert_ok!(CreditAccumulation::<T>::add_atmos_signer(
+            RawOrigin::Root.into(),
+            bob.clone(),
+            0u32.into(),
+            None,
+        ));
+    }: _(RawOrigin::Root, bob.clone(), 100u32.into())
+    verify {
+        assert!(CreditAccumulation::<T>::atmos_signers()
+            .iter()
+            .any(|signer| signer.key == bob && signer.retirement == Some(100u32.into())));
+    }
+
+    remove_atmos_signer {
+        for i in 1..T::MaxAtmosSigners::get() {
+            let signer: T::AccountId = account("signer", i, 0);
+            assert_ok!(CreditAccumulation::<T>::add_atmos_signer(
+                RawOrigin::Root.into(),
+                signer,
+                0u32.into(),
+                None,
+            ));
+        }
+        let bob = create_funded_user::<T>("Bob", 100);
+        assert_ok!(CreditAccumulation::<T>::add_atmos_signer(
+            RawOrigin::Root.into(),
+            bob.clone(),
+            0u32.into(),
+            None,
+        ));
     }: _(RawOrigin::Root, bob.clone())
     verify {
-        assert_eq!(
-            CreditAccumulation::<T>::atmos_accountid(), Some(bob)
-        );
+        assert!(!CreditAccumulation::<T>::atmos_signers().iter().any(|signer| signer.key == bob));
+    }
+
+    relay_traffic_attestations {
+        let n in 1 .. T::MaxRelayBatch::get();
+        let relayer = create_funded_user::<T>("relayer", 100);
+        assert_ok!(pallet_user_privileges::Pallet::<T>::set_user_privilege(
+            RawOrigin::Root.into(),
+            T::Lookup::unlookup(relayer.clone()),
+            Privilege::AtmosRelayer,
+        ));
+        let valid_until: BlockNumberFor<T> = 100u32.into();
+        let attestations: Vec<_> = (0..n)
+            .map(|i| {
+                let server: T::AccountId = account("server", i, 0);
+                let message = CreditAccumulation::<T>::atmos_message(
+                    AtmosPurpose::Traffic,
+                    0,
+                    valid_until,
+                    sp_io::hashing::blake2_256(&[]),
+                    server.clone(),
+                );
+                TrafficAttestation {
+                    server,
+                    nonce: 0,
+                    valid_until,
+                    signature: atmos_signature::<T>(&message),
+                }
+            })
+            .collect();
+        let last: T::AccountId = account("server", n - 1, 0);
+        let attestations: BoundedVec<_, T::MaxRelayBatch> = attestations.try_into().unwrap();
+    }: _(RawOrigin::Signed(relayer), attestations)
+    verify {
+        assert_eq!(CreditAccumulation::<T>::atmos_nonce(last), Some(1));
+    }
+
+    set_atmos_threshold {
+        for i in 0..T::MaxAtmosSigners::get() {
+            let signer: T::AccountId = account("signer", i, 0);
+            assert_ok!(CreditAccumulation::<T>::add_atmos_signer(
+                RawOrigin::Root.into(),
+                signer,
+                0u32.into(),
+                None,
+            ));
+        }
+    }: _(RawOrigin::Root, T::MaxAtmosSigners::get())
+    verify {
+        assert_eq!(CreditAccumulation::<T>::atmos_threshold(), T::MaxAtmosSigners::get());
     }
 }
 
@@ -73,7 +203,11 @@ mod tests {
     fn test_benchmarks() {
         new_test_ext().execute_with(|| {
             assert_ok!(Pallet::<Test>::test_benchmark_add_credit_by_traffic());
-            assert_ok!(Pallet::<Test>::test_benchmark_set_atmos_pubkey());
+            assert_ok!(Pallet::<Test>::test_benchmark_add_atmos_signer());
+            assert_ok!(Pallet::<Test>::test_benchmark_retire_atmos_signer());
+            assert_ok!(Pallet::<Test>::test_benchmark_remove_atmos_signer());
+            assert_ok!(Pallet::<Test>::test_benchmark_set_atmos_threshold());
+            assert_ok!(Pallet::<Test>::test_benchmark_relay_traffic_attestations());
         });
     }
 }
@@ -21,6 +21,10 @@
 
 #![cfg_attr(not(feature = "std"), no_std)]
 
+use codec::{Decode, Encode};
+use scale_info::TypeInfo;
+use sp_runtime::RuntimeDebug;
+
 pub use pallet::*;
 
 #[cfg(test)]
@@ -35,24 +39,54 @@ mod testing_utils;
 #[cfg(any(feature = "runtime-benchmarks", test))]
 pub mod benchmarking;
 
-#[cfg(any(feature = "runtime-benchmarks"))]
 use sp_std::prelude::*;
 
+pub mod migration;
 pub mod weights;
 
+// length of an sr25519 signature
+const SIGNATURE_LEN: usize = 64;
+
+/// A key atomos signs with and the blocks it is active between
+#[derive(Decode, Encode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+pub struct AtmosSigner<AccountId, BlockNumber> {
+    pub key: AccountId,
+    /// first block the key is active at
+    pub activation: BlockNumber,
+    /// first block the key is no longer active at
+    pub retirement: Option<BlockNumber>,
+}
+
+/// A traffic attestation a relayer submits on behalf of a device
+#[derive(Decode, Encode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+pub struct TrafficAttestation<AccountId, BlockNumber> {
+    pub server: AccountId,
+    pub nonce: u64,
+    pub valid_until: BlockNumber,
+    pub signature: Vec<u8>,
+}
+
 #[frame_support::pallet]
 pub mod pallet {
+    use super::*;
     use crate::weights::WeightInfo;
     use frame_support::{
-        dispatch::DispatchResultWithPostInfo, pallet_prelude::*, traits::Currency,
+        dispatch::{DispatchResultWithPostInfo, Pays},
+        pallet_prelude::*,
+        storage::with_storage_layer,
+        traits::Currency,
     };
     use frame_system::pallet_prelude::*;
     #[cfg(feature = "runtime-benchmarks")]
     use node_primitives::AccountCreator;
-    use node_primitives::{credit::CreditInterface, VerifySignatureInterface};
+    use node_primitives::{
+        credit::CreditInterface,
+        user_privileges::{Privilege, UserPrivilegeInterface},
+        AtmosMessage, AtmosPurpose, VerifySignatureInterface,
+    };
     use sp_core::{crypto::UncheckedFrom, sr25519};
-    use sp_io::crypto::sr25519_verify;
-    use sp_runtime::traits::TrailingZeroInput;
+    use sp_io::{crypto::sr25519_verify, hashing::blake2_256};
+    use sp_runtime::traits::Zero;
     use sp_std::prelude::Vec;
 
     /// Configure the pallet by specifying the parameters and types on which it depends.
@@ -67,12 +101,39 @@ pub mod pallet {
         type WeightInfo: WeightInfo;
         #[cfg(feature = "runtime-benchmarks")]
         type AccountCreator: AccountCreator<Self::AccountId>;
+        /// Maximum number of keys in the atomos signer set.
+        #[pallet::constant]
+        type MaxAtmosSigners: Get<u32>;
+        type UserPrivilegeInterface: UserPrivilegeInterface<Self::AccountId>;
+        /// Maximum number of attestations in one relayed batch.
+        #[pallet::constant]
+        type MaxRelayBatch: Get<u32>;
+        /// Blocks a retired key stays in the signer set for signatures in flight; it is pruned
+        /// from the set afterwards.
+        #[pallet::constant]
+        type AtmosSignerGracePeriod: Get<BlockNumberFor<Self>>;
     }
 
     type BalanceOf<T> =
         <<T as Config>::Currency as Currency<<T as frame_system::Config>::AccountId>>::Balance;
 
+    pub type AtmosSignerOf<T> =
+        AtmosSigner<<T as frame_system::Config>::AccountId, BlockNumberFor<T>>;
+
+    pub type AtmosMessageOf<T> = AtmosMessage<
+        <T as frame_system::Config>::AccountId,
+        BlockNumberFor<T>,
+        <T as frame_system::Config>::Hash,
+    >;
+
+    pub type TrafficAttestationOf<T> =
+        TrafficAttestation<<T as frame_system::Config>::AccountId, BlockNumberFor<T>>;
+
+    /// The current storage version.
+    const STORAGE_VERSION: StorageVersion = StorageVersion::new(1);
+
     #[pallet::pallet]
+    #[pallet::storage_version(STORAGE_VERSION)]
     #[pallet::without_storage_info]
     pub struct Pallet<T>(_);
 
@@ -82,19 +143,39 @@ pub mod pallet {
     pub(super) type AtmosNonce<T: Config> =
         StorageMap<_, Blake2_128Concat, T::AccountId, u64, OptionQuery>;
 
+    // block at which the current atmos nonce of an account became usable
     #[pallet::storage]
-    #[pallet::getter(fn atmos_accountid)]
-    pub(super) type AtmosAccountid<T: Config> = StorageValue<_, T::AccountId>;
+    #[pallet::getter(fn atmos_nonce_since)]
+    pub(super) type AtmosNonceSince<T: Config> =
+        StorageMap<_, Blake2_128Concat, T::AccountId, BlockNumberFor<T>, OptionQuery>;
 
+    /// Keys atomos signs with, including retired keys which may still have signatures in flight
     #[pallet::storage]
-    #[pallet::getter(fn tmp_atmos_accountid)]
-    pub(super) type TmpAtmosAccountid<T: Config> = StorageValue<_, T::AccountId>;
+    #[pallet::getter(fn atmos_signers)]
+    pub type AtmosSigners<T: Config> =
+        StorageValue<_, BoundedVec<AtmosSignerOf<T>, T::MaxAtmosSigners>, ValueQuery>;
+
+    /// Bumped on every change of the signer set
+    #[pallet::storage]
+    #[pallet::getter(fn atmos_signer_set_version)]
+    pub type AtmosSignerSetVersion<T: Config> = StorageValue<_, u32, ValueQuery>;
+
+    /// Number of signers an aggregated attestation needs
+    #[pallet::storage]
+    #[pallet::getter(fn atmos_threshold)]
+    pub type AtmosThreshold<T: Config> = StorageValue<_, u32, ValueQuery>;
 
     #[pallet::event]
     //#[pallet::metadata(T::AccountId = "AccountId", T::BlockNumber = "BlockNumber")]
     #[pallet::generate_deposit(pub(super) fn deposit_event)]
     pub enum Event<T: Config> {
         AtmosSignatureValid(T::AccountId),
+        /// the atomos signer set changed, new version
+        AtmosSignerSetUpdated(u32),
+        /// number of signers an aggregated attestation needs
+        AtmosThresholdSet(u32),
+        /// relayer, result of each attestation in the batch
+        TrafficAttestationsRelayed(T::AccountId, Vec<(T::AccountId, DispatchResult)>),
     }
 
     #[pallet::error]
@@ -103,6 +184,24 @@ pub mod pallet {
         InvalidSignature,
         /// Invalid atomos nonce
         InvalidAtomosNonce,
+        /// signer set is full
+        TooManyAtmosSigners,
+        /// key is already in the signer set
+        AtmosSignerExists,
+        /// key is not in the signer set
+        AtmosSignerNotFound,
+        /// retirement is not after activation
+        InvalidSignerWindow,
+        /// threshold is zero or larger than the signer set can be
+        InvalidThreshold,
+        /// not enough signers attested the message
+        InsufficientAttestations,
+        /// the attestation is past its valid_until block
+        AtmosMessageExpired,
+        /// origin does not have the AtmosRelayer privilege
+        NotAtmosRelayer,
+        /// relayed batch has no attestation
+        EmptyAttestationBatch,
     }
 
     // Dispatchable functions allows users to interact with the pallet and invoke state changes.
@@ -115,89 +214,299 @@ pub mod pallet {
         pub fn add_credit_by_traffic(
             origin: OriginFor<T>,
             nonce: u64,
+            valid_until: BlockNumberFor<T>,
             signature: Vec<u8>,
         ) -> DispatchResultWithPostInfo {
             let server = ensure_signed(origin)?;
+            Self::do_add_credit_by_traffic(server, nonce, valid_until, &signature)?;
+            Ok(().into())
+        }
 
-            let atmos_nonce_of_server = Self::atmos_nonce(&server).unwrap_or_default();
+        /// Submit traffic attestations on behalf of devices. Every attestation is applied on its
+        /// own; the batch is free when all of them are valid.
+        #[pallet::call_index(7)]
+        #[pallet::weight(T::WeightInfo::relay_traffic_attestations(attestations.len() as u32))]
+        pub fn relay_traffic_attestations(
+            origin: OriginFor<T>,
+            attestations: BoundedVec<TrafficAttestationOf<T>, T::MaxRelayBatch>,
+        ) -> DispatchResultWithPostInfo {
+            let relayer = ensure_signed(origin)?;
             ensure!(
-                nonce == atmos_nonce_of_server,
-                Error::<T>::InvalidAtomosNonce
+                T::UserPrivilegeInterface::has_privilege(&relayer, Privilege::AtmosRelayer),
+                Error::<T>::NotAtmosRelayer
             );
+            ensure!(!attestations.is_empty(), Error::<T>::EmptyAttestationBatch);
 
-            Self::verify_atomos_signature(nonce, &signature, server.clone())?;
-            Self::deposit_event(Event::AtmosSignatureValid(server.clone()));
-            AtmosNonce::<T>::insert(&server, atmos_nonce_of_server + 1u64);
-            T::CreditInterface::update_credit_by_traffic(server);
+            let results: Vec<_> = attestations
+                .into_iter()
+                .map(|attestation| {
+                    let result = with_storage_layer(|| {
+                        Self::do_add_credit_by_traffic(
+                            attestation.server.clone(),
+                            attestation.nonce,
+                            attestation.valid_until,
+                            &attestation.signature,
+                        )
+                    });
+                    (attestation.server, result)
+                })
+                .collect();
+            let pays_fee = if results.iter().all(|(_, result)| result.is_ok()) {
+                Pays::No
+            } else {
+                Pays::Yes
+            };
+            Self::deposit_event(Event::TrafficAttestationsRelayed(relayer, results));
+            Ok(pays_fee.into())
+        }
+
+        /// Add a key to the atomos signer set. Keys are rotated by adding the new key before
+        /// the old one retires, so both are active while signatures are in flight.
+        #[pallet::call_index(3)]
+        #[pallet::weight(T::WeightInfo::add_atmos_signer())]
+        pub fn add_atmos_signer(
+            origin: OriginFor<T>,
+            key: T::AccountId,
+            activation: BlockNumberFor<T>,
+            retirement: Option<BlockNumberFor<T>>,
+        ) -> DispatchResultWithPostInfo {
+            ensure_root(origin)?;
+            ensure!(
+                retirement.map_or(true, |retirement| retirement > activation),
+                Error::<T>::InvalidSignerWindow
+            );
+            <AtmosSigners<T>>::try_mutate(|signers| -> DispatchResult {
+                Self::prune_retired_signers(signers);
+                ensure!(
+                    !signers.iter().any(|signer| signer.key == key),
+                    Error::<T>::AtmosSignerExists
+                );
+                signers
+                    .try_push(AtmosSigner {
+                        key,
+                        activation,
+                        retirement,
+                    })
+                    .map_err(|_| Error::<T>::TooManyAtmosSigners)?;
+                Ok(())
+            })?;
+            Self::signer_set_updated();
+            Ok(().into())
+        }
+
+        /// Retire a key at `at`. Signatures for nonces which became usable before `at` are
+        /// still accepted.
+        #[pallet::call_index(4)]
+        #[pallet::weight(T::WeightInfo::retire_atmos_signer())]
+        pub fn retire_atmos_signer(
+            origin: OriginFor<T>,
+            key: T::AccountId,
+            at: BlockNumberFor<T>,
+        ) -> DispatchResultWithPostInfo {
+            ensure_root(origin)?;
+            <AtmosSigners<T>>::try_mutate(|signers| -> DispatchResult {
+                Self::prune_retired_signers(signers);
+                let signer = signers
+                    .iter_mut()
+                    .find(|signer| signer.key == key)
+                    .ok_or(Error::<T>::AtmosSignerNotFound)?;
+                ensure!(at > signer.activation, Error::<T>::InvalidSignerWindow);
+                signer.retirement = Some(at);
+                Ok(())
+            })?;
+            Self::signer_set_updated();
             Ok(().into())
         }
 
-        #[pallet::call_index(1)]
-        #[pallet::weight(T::WeightInfo::set_atmos_pubkey())]
-        pub fn set_atmos_pubkey(
+        /// Remove a key at once, e.g. when it is compromised
+        #[pallet::call_index(5)]
+        #[pallet::weight(T::WeightInfo::remove_atmos_signer())]
+        pub fn remove_atmos_signer(
             origin: OriginFor<T>,
-            pubkey: T::AccountId,
+            key: T::AccountId,
         ) -> DispatchResultWithPostInfo {
             ensure_root(origin)?;
-            <AtmosAccountid<T>>::put(pubkey);
+            <AtmosSigners<T>>::try_mutate(|signers| -> DispatchResult {
+                let len = signers.len();
+                signers.retain(|signer| signer.key != key);
+                ensure!(signers.len() < len, Error::<T>::AtmosSignerNotFound);
+                Ok(())
+            })?;
+            Self::signer_set_updated();
             Ok(().into())
         }
 
-        #[pallet::call_index(2)]
-        #[pallet::weight(T::WeightInfo::set_atmos_pubkey())]
-        pub fn set_atmos_tmp_pubkey(
+        #[pallet::call_index(6)]
+        #[pallet::weight(T::WeightInfo::set_atmos_threshold())]
+        pub fn set_atmos_threshold(
             origin: OriginFor<T>,
-            pubkey: T::AccountId,
+            threshold: u32,
         ) -> DispatchResultWithPostInfo {
             ensure_root(origin)?;
-            <TmpAtmosAccountid<T>>::put(pubkey);
+            let now = <frame_system::Pallet<T>>::block_number();
+            let signers = Self::atmos_signers()
+                .iter()
+                .filter(|signer| {
+                    signer
+                        .retirement
+                        .map_or(true, |retirement| retirement > now)
+                })
+                .count() as u32;
+            ensure!(
+                threshold > 0 && threshold <= signers,
+                Error::<T>::InvalidThreshold
+            );
+            <AtmosThreshold<T>>::put(threshold);
+            Self::deposit_event(Event::AtmosThresholdSet(threshold));
             Ok(().into())
         }
     }
 
     impl<T: Config> Pallet<T> {
-        pub fn verify_atomos_signature(
+        fn do_add_credit_by_traffic(
+            server: T::AccountId,
             nonce: u64,
+            valid_until: BlockNumberFor<T>,
             signature: &[u8],
+        ) -> DispatchResult {
+            let atmos_nonce_of_server = Self::atmos_nonce(&server).unwrap_or_default();
+            ensure!(
+                nonce == atmos_nonce_of_server,
+                Error::<T>::InvalidAtomosNonce
+            );
+
+            // traffic attestations carry no payload
+            let message = Self::atmos_message(
+                AtmosPurpose::Traffic,
+                nonce,
+                valid_until,
+                blake2_256(&[]),
+                server.clone(),
+            );
+            Self::verify_atomos_signature(&message, signature).map_err(|e| e.error)?;
+            Self::deposit_event(Event::AtmosSignatureValid(server.clone()));
+            AtmosNonce::<T>::insert(&server, atmos_nonce_of_server + 1u64);
+            AtmosNonceSince::<T>::insert(&server, <frame_system::Pallet<T>>::block_number());
+            T::CreditInterface::update_credit_by_traffic(server);
+            Ok(())
+        }
+
+        /// Consume `nonce` if it is the next atmos nonce of `sender`
+        pub fn consume_atmos_nonce(sender: &T::AccountId, nonce: u64) -> DispatchResult {
+            ensure!(
+                nonce == Self::atmos_nonce(sender).unwrap_or_default(),
+                Error::<T>::InvalidAtomosNonce
+            );
+            AtmosNonce::<T>::insert(sender, nonce + 1u64);
+            AtmosNonceSince::<T>::insert(sender, <frame_system::Pallet<T>>::block_number());
+            Ok(())
+        }
+
+        // drop the keys retired for longer than the grace period
+        fn prune_retired_signers(signers: &mut BoundedVec<AtmosSignerOf<T>, T::MaxAtmosSigners>) {
+            let now = <frame_system::Pallet<T>>::block_number();
+            let grace = T::AtmosSignerGracePeriod::get();
+            signers.retain(|signer| {
+                signer
+                    .retirement
+                    .map_or(true, |retirement| retirement.saturating_add(grace) > now)
+            });
+        }
+
+        fn signer_set_updated() {
+            let version = <AtmosSignerSetVersion<T>>::mutate(|version| {
+                *version = version.saturating_add(1);
+                *version
+            });
+            Self::deposit_event(Event::AtmosSignerSetUpdated(version));
+        }
+
+        /// Keys active at some block between the block the current nonce of `sender`
+        /// became usable and now
+        pub fn active_atmos_signers(sender: &T::AccountId) -> Vec<T::AccountId> {
+            let now = <frame_system::Pallet<T>>::block_number();
+            let since = Self::atmos_nonce_since(sender).unwrap_or(now).min(now);
+            Self::atmos_signers()
+                .into_iter()
+                .filter(|signer| {
+                    signer.activation <= now
+                        && signer
+                            .retirement
+                            .map_or(true, |retirement| retirement > since)
+                })
+                .map(|signer| signer.key)
+                .collect()
+        }
+
+        /// The message atomos signs for `sender`, bound to this chain
+        pub fn atmos_message(
+            purpose: AtmosPurpose,
+            nonce: u64,
+            valid_until: BlockNumberFor<T>,
+            payload_hash: [u8; 32],
             sender: T::AccountId,
-        ) -> DispatchResultWithPostInfo {
-            let zero_account = T::AccountId::decode(&mut TrailingZeroInput::new(&[][..]))
-                .expect("infinite input; qed");
-            let atomos_accountid = Self::atmos_accountid().unwrap_or(zero_account.clone());
-            let tmp_atomos_accountid = Self::tmp_atmos_accountid().unwrap_or(zero_account);
-            match Self::do_verify(nonce, signature, sender.clone(), atomos_accountid) {
-                Err(_) => Self::do_verify(nonce, signature, sender, tmp_atomos_accountid),
-                Ok(_) => Ok(().into()),
+        ) -> AtmosMessageOf<T> {
+            AtmosMessage {
+                purpose,
+                genesis_hash: <frame_system::Pallet<T>>::block_hash(BlockNumberFor::<T>::zero()),
+                nonce,
+                sender,
+                valid_until,
+                payload_hash,
             }
         }
 
-        pub fn verify_atomos_new_signature(
-            nonce: u64,
+        pub fn verify_atomos_signature(
+            message: &AtmosMessageOf<T>,
             signature: &[u8],
-            sender: T::AccountId,
         ) -> DispatchResultWithPostInfo {
-            let zero_account = T::AccountId::decode(&mut TrailingZeroInput::new(&[][..]))
-                .expect("infinite input; qed");
-            let atomos_accountid = Self::atmos_accountid().unwrap_or(zero_account.clone());
-            Self::do_verify(nonce, signature, sender.clone(), atomos_accountid)
+            ensure!(
+                message.valid_until >= <frame_system::Pallet<T>>::block_number(),
+                Error::<T>::AtmosMessageExpired
+            );
+            let verified = Self::active_atmos_signers(&message.sender)
+                .into_iter()
+                .any(|key| Self::do_verify(message, signature, key).is_ok());
+            ensure!(verified, Error::<T>::InvalidSignature);
+            Ok(().into())
+        }
+
+        /// Check that at least `AtmosThreshold` distinct active signers signed the message
+        pub fn verify_atomos_attestation(
+            message: &AtmosMessageOf<T>,
+            signatures: &[Vec<u8>],
+        ) -> DispatchResultWithPostInfo {
+            ensure!(
+                message.valid_until >= <frame_system::Pallet<T>>::block_number(),
+                Error::<T>::AtmosMessageExpired
+            );
+            // a signature only verifies under the key that made it
+            let attested = Self::active_atmos_signers(&message.sender)
+                .into_iter()
+                .filter(|key| {
+                    signatures
+                        .iter()
+                        .any(|signature| Self::do_verify(message, signature, key.clone()).is_ok())
+                })
+                .count() as u32;
+            ensure!(
+                attested >= Self::atmos_threshold().max(1),
+                Error::<T>::InsufficientAttestations
+            );
+            Ok(().into())
         }
 
         fn do_verify(
-            nonce: u64,
+            message: &AtmosMessageOf<T>,
             signature: &[u8],
-            sender: T::AccountId,
             atomos_accountid: T::AccountId,
         ) -> DispatchResultWithPostInfo {
             let mut pk = [0u8; 32];
             pk.copy_from_slice(&atomos_accountid.encode());
 
             let pub_key = sr25519::Public::from_raw(pk);
-            let sig = sr25519::Signature::from_slice(&signature);
-            let mut data = Vec::new();
-            data.extend_from_slice(&atomos_accountid.encode());
-            data.extend_from_slice(&nonce.to_be_bytes());
-            data.extend_from_slice(&sender.encode());
-            let msg = sp_io::hashing::blake2_256(&data);
+            let sig = sr25519::Signature::from_slice(signature);
+            let msg = blake2_256(&message.encode());
 
             let verified = sr25519_verify(
                 &sig.unwrap_or(UncheckedFrom::unchecked_from([0; 64])),
@@ -209,17 +518,37 @@ pub mod pallet {
         }
     }
 
-    impl<T: Config> VerifySignatureInterface<T::AccountId> for Pallet<T> {
-        fn verify_atomos_signature(nonce: u64, signature: Vec<u8>, sender: T::AccountId) -> bool {
-            Self::verify_atomos_signature(nonce, &signature, sender.clone()).is_ok()
-        }
-
-        fn verify_atomos_new_signature(
+    impl<T: Config> VerifySignatureInterface<T::AccountId, BlockNumberFor<T>> for Pallet<T> {
+        fn verify_atomos_signature(
+            purpose: AtmosPurpose,
             nonce: u64,
+            valid_until: BlockNumberFor<T>,
+            payload_hash: [u8; 32],
             signature: Vec<u8>,
             sender: T::AccountId,
         ) -> bool {
-            Self::verify_atomos_new_signature(nonce, &signature, sender.clone()).is_ok()
+            let needs_attestation = purpose.needs_attestation();
+            let message = Self::atmos_message(purpose, nonce, valid_until, payload_hash, sender);
+            if !needs_attestation {
+                return Self::verify_atomos_signature(&message, &signature).is_ok();
+            }
+            // the signatures of the attestation are concatenated
+            let count = signature.len() / SIGNATURE_LEN;
+            if count == 0
+                || count > T::MaxAtmosSigners::get() as usize
+                || signature.len() % SIGNATURE_LEN != 0
+            {
+                return false;
+            }
+            let signatures: Vec<Vec<u8>> = signature
+                .chunks(SIGNATURE_LEN)
+                .map(|signature| signature.to_vec())
+                .collect();
+            Self::verify_atomos_attestation(&message, &signatures).is_ok()
+        }
+
+        fn consume_atomos_nonce(sender: &T::AccountId, nonce: u64) -> bool {
+            Self::consume_atmos_nonce(sender, nonce).is_ok()
         }
     }
 }
@@ -0,0 +1,66 @@
+use super::*;
+pub mod v1 {
+    use frame_support::{pallet_prelude::*, weights::Weight};
+
+    use super::*;
+    use frame_support::traits::OnRuntimeUpgrade;
+    use frame_system::pallet_prelude::BlockNumberFor;
+    use sp_runtime::traits::Zero;
+
+    #[frame_support::storage_alias]
+    type AtmosAccountid<T: Config> =
+        StorageValue<Pallet<T>, <T as frame_system::Config>::AccountId, OptionQuery>;
+
+    #[frame_support::storage_alias]
+    type TmpAtmosAccountid<T: Config> =
+        StorageValue<Pallet<T>, <T as frame_system::Config>::AccountId, OptionQuery>;
+
+    /// Move the primary and temporary atmos keys into the signer set. Both stay active
+    /// until they are retired.
+    pub struct MigrateToV1<T>(sp_std::marker::PhantomData<T>);
+    impl<T: Config> OnRuntimeUpgrade for MigrateToV1<T> {
+        fn on_runtime_upgrade() -> Weight {
+            let current_version = Pallet::<T>::current_storage_version();
+            let onchain_version = Pallet::<T>::on_chain_storage_version();
+            if onchain_version == 0 && current_version >= 1 {
+                let mut signers = AtmosSigners::<T>::get();
+                for key in [AtmosAccountid::<T>::take(), TmpAtmosAccountid::<T>::take()]
+                    .into_iter()
+                    .flatten()
+                {
+                    if signers.iter().any(|signer| signer.key == key) {
+                        continue;
+                    }
+                    let signer = AtmosSigner {
+                        key,
+                        activation: BlockNumberFor::<T>::zero(),
+                        retirement: None,
+                    };
+                    if signers.try_push(signer).is_err() {
+                        log::warn!(
+                            target: "runtime::credit-accumulation",
+                            "Atmos signer set is full, dropping a legacy key"
+                        );
+                    }
+                }
+                let migrated = signers.len();
+                AtmosSigners::<T>::put(signers);
+                AtmosSignerSetVersion::<T>::mutate(|version| *version = version.saturating_add(1));
+
+                StorageVersion::new(1).put::<Pallet<T>>();
+                log::info!(
+                    target: "runtime::credit-accumulation",
+                    "Moved {} atmos keys into the signer set, storage to version 1",
+                    migrated
+                );
+                T::DbWeight::get().reads_writes(5, 6)
+            } else {
+                log::info!(
+                    target: "runtime::credit-accumulation",
+                    "Migration did not execute. This probably should be removed"
+                );
+                T::DbWeight::get().reads(1)
+            }
+        }
+    }
+}
@@ -50,6 +50,7 @@ frame_support::construct_runtime!(
         CreditAccumulation: pallet_credit_accumulation::{Pallet, Call, Storage, Event<T>},
         Timestamp: pallet_timestamp::{Pallet, Call, Storage, Inherent},
         Uniques: pallet_uniques::{Pallet, Call, Storage, Event<T>},
+        UserPrivileges: pallet_user_privileges::{Pallet, Call, Storage, Event<T>},
     }
 );
 
@@ -165,6 +166,7 @@ impl pallet_uniques::Config for Test {
 
 parameter_types! {
     pub const MaxBurnCreditPerAddress: u32 = 50;
+    pub const AnomalyDeviations: u32 = 3;
 }
 
 impl pallet_credit::Config for Test {
@@ -181,12 +183,14 @@ impl pallet_credit::Config for Test {
     type BurnedTo = ();
     type UserPrivilegeInterface = ();
     type MaxBurnCreditPerAddress = MaxBurnCreditPerAddress;
+    type AnomalyDeviations = AnomalyDeviations;
 }
 
 parameter_types! {
     pub const MinLockAmt: u32 = 100;
     pub const MaxDurationEras: u8 = 7;
     pub const MaxIpLength: usize = 256;
+    pub const ReputationDecay: sp_runtime::Perbill = sp_runtime::Perbill::from_percent(90);
 }
 impl pallet_deeper_node::Config for Test {
     type RuntimeEvent = RuntimeEvent;
@@ -197,6 +201,11 @@ impl pallet_deeper_node::Config for Test {
     type MaxIpLength = MaxIpLength;
     type WeightInfo = ();
     type VerifySignatureInterface = ();
+    type RegionOrigin = frame_system::EnsureRoot<Self::AccountId>;
+    type DeviceSignature = sp_runtime::testing::TestSignature;
+    type UnsignedPriority = frame_support::traits::ConstU64<700>;
+    type MaxCreditProofs = frame_support::traits::ConstU32<4>;
+    type ReputationDecay = ReputationDecay;
 }
 
 parameter_types! {
@@ -208,6 +217,21 @@ impl pallet_credit_accumulation::Config for Test {
     type Currency = Balances;
     type CreditInterface = Credit;
     type WeightInfo = ();
+    type MaxAtmosSigners = ConstU32<4>;
+    type UserPrivilegeInterface = UserPrivileges;
+    type MaxRelayBatch = ConstU32<4>;
+    type AtmosSignerGracePeriod = frame_support::traits::ConstU64<100>;
+}
+
+impl pallet_user_privileges::Config for Test {
+    type RuntimeEvent = RuntimeEvent;
+    type ForceOrigin = frame_system::EnsureRoot<AccountId>;
+    type WeightInfo = ();
+    type BlocksPerDay = frame_support::traits::ConstU64<14_400>;
+    type RuntimeCall = RuntimeCall;
+    type ProposalTimeout = frame_support::traits::ConstU64<100>;
+    type MaxApprovals = frame_support::traits::ConstU32<16>;
+    type ProposableCalls = frame_support::traits::Everything;
 }
 
 // Build genesis storage according to the mock runtime.
@@ -13,59 +13,122 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-use crate::{mock::*, testing_utils::*, AtmosNonce, Error};
+use crate::{
+    mock::*, testing_utils::*, AtmosMessageOf, AtmosNonce, AtmosNonceSince, AtmosSigner, Error,
+    TrafficAttestation,
+};
 use codec::Encode;
 use frame_support::{
-    assert_noop, assert_ok, dispatch::DispatchErrorWithPostInfo, error::BadOrigin,
+    assert_noop, assert_ok,
+    dispatch::{DispatchErrorWithPostInfo, Pays},
+    error::BadOrigin,
 };
 use frame_system::RawOrigin;
-use hex_literal::hex;
+use node_primitives::{user_privileges::Privilege, AtmosPurpose, VerifySignatureInterface};
 use pallet_credit::LastCreditUpdateTimestamp;
 use pallet_deeper_node::OnboardTime;
-use sp_core::testing::SR25519;
-use sp_io::crypto::sr25519_sign;
+use sp_core::{crypto::AccountId32, testing::SR25519};
+use sp_io::{crypto::sr25519_sign, hashing::blake2_256};
+
+const VALID_UNTIL: u64 = 1_000;
+
+fn atmos_sign(signer: &AccountId32, message: &AtmosMessageOf<Test>) -> Vec<u8> {
+    let mut pk = [0u8; 32];
+    pk.copy_from_slice(&signer.encode());
+    let pub_key = sp_core::sr25519::Public::from_raw(pk);
+    let msg = blake2_256(&message.encode());
+    sr25519_sign(SR25519, &pub_key, &msg).unwrap().encode()
+}
+
+fn traffic_message(nonce: u64, sender: &AccountId32) -> AtmosMessageOf<Test> {
+    CreditAccumulation::atmos_message(
+        AtmosPurpose::Traffic,
+        nonce,
+        VALID_UNTIL,
+        blake2_256(&[]),
+        sender.clone(),
+    )
+}
+
+// signed by bob, the key in the test keystore
+fn traffic_signature(nonce: u64, sender: &AccountId32) -> Vec<u8> {
+    atmos_sign(&bob(), &traffic_message(nonce, sender))
+}
 
 #[test]
 fn add_credit_by_traffic() {
     new_test_ext().execute_with(|| {
         // OK
-        assert_ok!(CreditAccumulation::set_atmos_pubkey(
+        assert_ok!(CreditAccumulation::add_atmos_signer(
             RawOrigin::Root.into(),
             bob(),
+            0,
+            None,
         ));
 
         // InvalidAtomosNonce
         let nonce: u64 = 1;
-        let signature: [u8; 64] = hex!("2623f985877cc214821b3c01e364ae14a88a2042222a4bcf7580b48e407c2764c8527be4157c4953378a43f2f7da896d7c0cd268e69f70e601b44db020a05889");
-        assert_eq!(CreditAccumulation::add_credit_by_traffic(RuntimeOrigin::signed(alice()), nonce, signature.into()),
-        Err(DispatchErrorWithPostInfo::from(Error::<Test>::InvalidAtomosNonce)));
+        let signature = traffic_signature(nonce, &alice());
+        assert_eq!(
+            CreditAccumulation::add_credit_by_traffic(
+                RuntimeOrigin::signed(alice()),
+                nonce,
+                VALID_UNTIL,
+                signature
+            ),
+            Err(DispatchErrorWithPostInfo::from(
+                Error::<Test>::InvalidAtomosNonce
+            ))
+        );
 
         // OK
         let nonce: u64 = 0;
-        let signature: [u8; 64] = hex!("5071a1a526b1d2d1833e4de43d1ce22ad3506de2e10ee4a9c18c0b310c54286b9cb10bfb4ee12be6b93e91337de0fa2ea2edd787d083db36211109bdc8438989");
+        let signature = traffic_signature(nonce, &alice());
         assert_ok!(CreditAccumulation::add_credit_by_traffic(
             RuntimeOrigin::signed(alice()),
-            nonce, signature.into()
+            nonce,
+            VALID_UNTIL,
+            signature
         ));
 
         // InvalidAtomosNonce
         let nonce: u64 = 0;
-        let signature: [u8; 64] = hex!("5071a1a526b1d2d1833e4de43d1ce22ad3506de2e10ee4a9c18c0b310c54286b9cb10bfb4ee12be6b93e91337de0fa2ea2edd787d083db36211109bdc8438989");
-        assert_eq!(CreditAccumulation::add_credit_by_traffic(RuntimeOrigin::signed(alice()), nonce, signature.into()),
-        Err(DispatchErrorWithPostInfo::from(Error::<Test>::InvalidAtomosNonce)));
+        let signature = traffic_signature(nonce, &alice());
+        assert_eq!(
+            CreditAccumulation::add_credit_by_traffic(
+                RuntimeOrigin::signed(alice()),
+                nonce,
+                VALID_UNTIL,
+                signature
+            ),
+            Err(DispatchErrorWithPostInfo::from(
+                Error::<Test>::InvalidAtomosNonce
+            ))
+        );
 
         // InvalidSignature
         let nonce: u64 = 0;
-        let signature: [u8; 64] = hex!("5071a1a526b1d2d1833e4de43d1ce22ad3506de2e10ee4a9c18c0b310c54286b9cb10bfb4ee12be6b93e91337de0fa2ea2edd787d083db36211109bdc8438989");
-        assert_eq!(CreditAccumulation::add_credit_by_traffic(RuntimeOrigin::signed(bob()), nonce, signature.into()),
-        Err(DispatchErrorWithPostInfo::from(Error::<Test>::InvalidSignature)));
+        let signature = traffic_signature(nonce, &alice());
+        assert_eq!(
+            CreditAccumulation::add_credit_by_traffic(
+                RuntimeOrigin::signed(bob()),
+                nonce,
+                VALID_UNTIL,
+                signature
+            ),
+            Err(DispatchErrorWithPostInfo::from(
+                Error::<Test>::InvalidSignature
+            ))
+        );
 
         // OK
         let nonce: u64 = 1;
-        let signature: [u8; 64] = hex!("2623f985877cc214821b3c01e364ae14a88a2042222a4bcf7580b48e407c2764c8527be4157c4953378a43f2f7da896d7c0cd268e69f70e601b44db020a05889");
+        let signature = traffic_signature(nonce, &alice());
         assert_ok!(CreditAccumulation::add_credit_by_traffic(
             RuntimeOrigin::signed(alice()),
-            nonce, signature.into()
+            nonce,
+            VALID_UNTIL,
+            signature
         ));
     });
 }
@@ -73,9 +136,11 @@ fn add_credit_by_traffic() {
 #[test]
 fn add_one_credit_one_era() {
     new_test_ext().execute_with(|| {
-        assert_ok!(CreditAccumulation::set_atmos_pubkey(
+        assert_ok!(CreditAccumulation::add_atmos_signer(
             RawOrigin::Root.into(),
             bob(),
+            0,
+            None,
         ));
 
         let nonce: u64 = 1;
@@ -83,11 +148,13 @@ fn add_one_credit_one_era() {
         OnboardTime::<Test>::insert(alice(), 40); // block2
         LastCreditUpdateTimestamp::<Test>::insert(alice(), 40); // block2
 
-        run_to_block(BLOCKS_PER_ERA+2);
-        let signature: [u8; 64] = hex!("34fb245d1d6df01f8177a8e3d29d30a63eb22b9d6c691a97536e1a2805953951194250865971237cf70edb934d76c91744460fe78a7cb86b537ab56146e32b81");
+        run_to_block(BLOCKS_PER_ERA + 2);
+        let signature = traffic_signature(nonce, &alice());
         assert_ok!(CreditAccumulation::add_credit_by_traffic(
             RuntimeOrigin::signed(alice()),
-            nonce, signature.into()
+            nonce,
+            VALID_UNTIL,
+            signature
         ));
 
         assert_eq!(400, Credit::last_credit_update_timestamp(alice()).unwrap()); // (72+2)*5+30
@@ -96,12 +163,18 @@ fn add_one_credit_one_era() {
         let mut events = <frame_system::Pallet<Test>>::events();
         assert_eq!(
             events.pop().expect("should get first events").event,
-            crate::tests::RuntimeEvent::from(pallet_credit::Event::CreditDataAddedByTraffic(alice(), 211))
+            crate::tests::RuntimeEvent::from(pallet_credit::Event::CreditDataAddedByTraffic(
+                alice(),
+                211
+            ))
         );
 
         assert_eq!(
             events.pop().expect("should get second events").event,
-            crate::tests::RuntimeEvent::from(pallet_credit::Event::CreditUpdateSuccess(alice(), 211))
+            crate::tests::RuntimeEvent::from(pallet_credit::Event::CreditUpdateSuccess(
+                alice(),
+                211
+            ))
         );
 
         assert_eq!(
@@ -116,9 +189,11 @@ fn add_one_credit_one_era() {
 #[test]
 fn not_add_credit_less_than_one_era() {
     new_test_ext().execute_with(|| {
-        assert_ok!(CreditAccumulation::set_atmos_pubkey(
+        assert_ok!(CreditAccumulation::add_atmos_signer(
             RawOrigin::Root.into(),
             bob(),
+            0,
+            None,
         ));
 
         let nonce: u64 = 1;
@@ -126,11 +201,13 @@ fn not_add_credit_less_than_one_era() {
         OnboardTime::<Test>::insert(alice(), 40); // block2
         LastCreditUpdateTimestamp::<Test>::insert(alice(), 40); // block2
 
-        run_to_block(BLOCKS_PER_ERA+1);
-        let signature: [u8; 64] = hex!("34fb245d1d6df01f8177a8e3d29d30a63eb22b9d6c691a97536e1a2805953951194250865971237cf70edb934d76c91744460fe78a7cb86b537ab56146e32b81");
+        run_to_block(BLOCKS_PER_ERA + 1);
+        let signature = traffic_signature(nonce, &alice());
         assert_ok!(CreditAccumulation::add_credit_by_traffic(
             RuntimeOrigin::signed(alice()),
-            nonce, signature.into()
+            nonce,
+            VALID_UNTIL,
+            signature
         ));
 
         assert_eq!(40, Credit::last_credit_update_timestamp(alice()).unwrap()); // (72+2)*5+30
@@ -149,9 +226,11 @@ fn not_add_credit_less_than_one_era() {
 #[test]
 fn only_add_one_credit_even_if_more_eras() {
     new_test_ext().execute_with(|| {
-        assert_ok!(CreditAccumulation::set_atmos_pubkey(
+        assert_ok!(CreditAccumulation::add_atmos_signer(
             RawOrigin::Root.into(),
             bob(),
+            0,
+            None,
         ));
 
         let nonce: u64 = 1;
@@ -159,11 +238,13 @@ fn only_add_one_credit_even_if_more_eras() {
         OnboardTime::<Test>::insert(alice(), 40); // block2
         LastCreditUpdateTimestamp::<Test>::insert(alice(), 40); // block2
 
-        run_to_block(2*BLOCKS_PER_ERA+3);
-        let signature: [u8; 64] = hex!("34fb245d1d6df01f8177a8e3d29d30a63eb22b9d6c691a97536e1a2805953951194250865971237cf70edb934d76c91744460fe78a7cb86b537ab56146e32b81");
+        run_to_block(2 * BLOCKS_PER_ERA + 3);
+        let signature = traffic_signature(nonce, &alice());
         assert_ok!(CreditAccumulation::add_credit_by_traffic(
             RuntimeOrigin::signed(alice()),
-            nonce, signature.into()
+            nonce,
+            VALID_UNTIL,
+            signature
         ));
 
         assert_eq!(765, Credit::last_credit_update_timestamp(alice()).unwrap()); // (2*72+3)*5+30
@@ -172,12 +253,18 @@ fn only_add_one_credit_even_if_more_eras() {
         let mut events = <frame_system::Pallet<Test>>::events();
         assert_eq!(
             events.pop().expect("should get first events").event,
-            crate::tests::RuntimeEvent::from(pallet_credit::Event::CreditDataAddedByTraffic(alice(), 211))
+            crate::tests::RuntimeEvent::from(pallet_credit::Event::CreditDataAddedByTraffic(
+                alice(),
+                211
+            ))
         );
 
         assert_eq!(
             events.pop().expect("should get second events").event,
-            crate::tests::RuntimeEvent::from(pallet_credit::Event::CreditUpdateSuccess(alice(), 211))
+            crate::tests::RuntimeEvent::from(pallet_credit::Event::CreditUpdateSuccess(
+                alice(),
+                211
+            ))
         );
 
         assert_eq!(
@@ -190,51 +277,458 @@ fn only_add_one_credit_even_if_more_eras() {
 }
 
 #[test]
-fn set_atmos_pubkey() {
+fn atmos_signer_set() {
     new_test_ext().execute_with(|| {
         // OK
-        assert_ok!(CreditAccumulation::set_atmos_pubkey(
+        assert_ok!(CreditAccumulation::add_atmos_signer(
             RawOrigin::Root.into(),
             bob(),
+            0,
+            None,
         ));
+        assert_eq!(CreditAccumulation::atmos_signer_set_version(), 1);
 
         // BadOrigin
         assert_noop!(
-            CreditAccumulation::set_atmos_pubkey(RuntimeOrigin::signed(alice()), bob(),),
+            CreditAccumulation::add_atmos_signer(RuntimeOrigin::signed(alice()), bob(), 0, None),
             BadOrigin
         );
+
+        // AtmosSignerExists
+        assert_noop!(
+            CreditAccumulation::add_atmos_signer(RawOrigin::Root.into(), bob(), 5, None),
+            Error::<Test>::AtmosSignerExists
+        );
+
+        // InvalidSignerWindow
+        assert_noop!(
+            CreditAccumulation::add_atmos_signer(RawOrigin::Root.into(), charlie(), 5, Some(5)),
+            Error::<Test>::InvalidSignerWindow
+        );
+
+        // TooManyAtmosSigners
+        for seed in 0..3u8 {
+            assert_ok!(CreditAccumulation::add_atmos_signer(
+                RawOrigin::Root.into(),
+                AccountId32::new([seed; 32]),
+                0,
+                None,
+            ));
+        }
+        assert_noop!(
+            CreditAccumulation::add_atmos_signer(RawOrigin::Root.into(), charlie(), 0, None),
+            Error::<Test>::TooManyAtmosSigners
+        );
+
+        // retire and remove
+        assert_ok!(CreditAccumulation::retire_atmos_signer(
+            RawOrigin::Root.into(),
+            bob(),
+            10,
+        ));
+        assert_eq!(
+            CreditAccumulation::atmos_signers()[0],
+            AtmosSigner {
+                key: bob(),
+                activation: 0,
+                retirement: Some(10),
+            }
+        );
+        assert_ok!(CreditAccumulation::remove_atmos_signer(
+            RawOrigin::Root.into(),
+            bob(),
+        ));
+        assert_noop!(
+            CreditAccumulation::retire_atmos_signer(RawOrigin::Root.into(), bob(), 10),
+            Error::<Test>::AtmosSignerNotFound
+        );
+        assert_noop!(
+            CreditAccumulation::remove_atmos_signer(RawOrigin::Root.into(), bob()),
+            Error::<Test>::AtmosSignerNotFound
+        );
+        assert_eq!(CreditAccumulation::atmos_signers().len(), 3);
+        assert_eq!(CreditAccumulation::atmos_signer_set_version(), 6);
+
+        // threshold
+        assert_noop!(
+            CreditAccumulation::set_atmos_threshold(RawOrigin::Root.into(), 0),
+            Error::<Test>::InvalidThreshold
+        );
+        assert_noop!(
+            CreditAccumulation::set_atmos_threshold(RawOrigin::Root.into(), 5),
+            Error::<Test>::InvalidThreshold
+        );
+        // the threshold can not exceed the number of keys in the set
+        assert_noop!(
+            CreditAccumulation::set_atmos_threshold(RawOrigin::Root.into(), 4),
+            Error::<Test>::InvalidThreshold
+        );
+        assert_ok!(CreditAccumulation::set_atmos_threshold(
+            RawOrigin::Root.into(),
+            2
+        ));
+        assert_eq!(CreditAccumulation::atmos_threshold(), 2);
     });
 }
 
 #[test]
 fn verify_atomos_signature() {
     new_test_ext().execute_with(|| {
-        assert_ok!(CreditAccumulation::set_atmos_pubkey(
+        assert_ok!(CreditAccumulation::add_atmos_signer(
             RawOrigin::Root.into(),
             charlie(),
+            0,
+            None,
         ));
-        assert_ok!(CreditAccumulation::set_atmos_tmp_pubkey(
+        let nonce: u64 = 1;
+        let message = traffic_message(nonce, &alice());
+        let sig = atmos_sign(&bob(), &message);
+        assert_noop!(
+            CreditAccumulation::verify_atomos_signature(&message, &sig),
+            Error::<Test>::InvalidSignature
+        );
+
+        // any key of the set is accepted
+        assert_ok!(CreditAccumulation::add_atmos_signer(
             RawOrigin::Root.into(),
             bob(),
+            0,
+            None,
         ));
+        assert_ok!(CreditAccumulation::verify_atomos_signature(&message, &sig));
+    });
+}
+
+#[test]
+fn consume_atomos_nonce() {
+    new_test_ext().execute_with(|| {
+        run_to_block(3);
+        assert!(
+            !<CreditAccumulation as VerifySignatureInterface<_, _>>::consume_atomos_nonce(
+                &alice(),
+                1
+            )
+        );
+        assert!(
+            <CreditAccumulation as VerifySignatureInterface<_, _>>::consume_atomos_nonce(
+                &alice(),
+                0
+            )
+        );
+        assert_eq!(CreditAccumulation::atmos_nonce(alice()), Some(1));
+        assert_eq!(CreditAccumulation::atmos_nonce_since(alice()), Some(3));
+        // a nonce is used once
+        assert!(
+            !<CreditAccumulation as VerifySignatureInterface<_, _>>::consume_atomos_nonce(
+                &alice(),
+                0
+            )
+        );
+    });
+}
 
+#[test]
+fn atmos_signer_rotation() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(CreditAccumulation::add_atmos_signer(
+            RawOrigin::Root.into(),
+            bob(),
+            5,
+            Some(10),
+        ));
         let nonce: u64 = 1;
-        let atomos_accountid = CreditAccumulation::tmp_atmos_accountid().unwrap();
-        let mut pk = [0u8; 32];
-        pk.copy_from_slice(&atomos_accountid.encode());
-        let pub_key = sp_core::sr25519::Public::from_raw(pk);
-
-        let mut data = Vec::new();
-        data.extend_from_slice(&bob().encode());
-        data.extend_from_slice(&nonce.to_be_bytes());
-        data.extend_from_slice(&alice().encode());
-        let msg = sp_io::hashing::blake2_256(&data);
-
-        let sig = sr25519_sign(SR25519, &pub_key, &msg).unwrap();
-        assert_ok!(CreditAccumulation::verify_atomos_signature(
+        let message = traffic_message(nonce, &alice());
+        let sig = atmos_sign(&bob(), &message);
+
+        // not active yet
+        run_to_block(4);
+        assert_noop!(
+            CreditAccumulation::verify_atomos_signature(&message, &sig),
+            Error::<Test>::InvalidSignature
+        );
+
+        run_to_block(8);
+        AtmosNonceSince::<Test>::insert(alice(), 8);
+        assert_ok!(CreditAccumulation::verify_atomos_signature(&message, &sig));
+
+        // retired, but the nonce became usable while the key was active
+        run_to_block(20);
+        assert_ok!(CreditAccumulation::verify_atomos_signature(&message, &sig));
+
+        // nonce became usable after the key retired
+        AtmosNonceSince::<Test>::insert(alice(), 12);
+        assert_noop!(
+            CreditAccumulation::verify_atomos_signature(&message, &sig),
+            Error::<Test>::InvalidSignature
+        );
+    });
+}
+
+#[test]
+fn verify_atomos_attestation() {
+    new_test_ext().execute_with(|| {
+        let charlie_key = sp_io::crypto::sr25519_generate(SR25519, Some(b"//Charlie".to_vec()));
+        assert_eq!(AccountId32::from(charlie_key), charlie());
+        assert_ok!(CreditAccumulation::add_atmos_signer(
+            RawOrigin::Root.into(),
+            bob(),
+            0,
+            None,
+        ));
+        assert_ok!(CreditAccumulation::add_atmos_signer(
+            RawOrigin::Root.into(),
+            charlie(),
+            0,
+            None,
+        ));
+        assert_ok!(CreditAccumulation::set_atmos_threshold(
+            RawOrigin::Root.into(),
+            2
+        ));
+
+        let nonce: u64 = 1;
+        let message = traffic_message(nonce, &alice());
+        let bob_sig = atmos_sign(&bob(), &message);
+        let charlie_sig = atmos_sign(&charlie(), &message);
+
+        // the same signer twice does not count twice
+        assert_noop!(
+            CreditAccumulation::verify_atomos_attestation(
+                &message,
+                &[bob_sig.clone(), bob_sig.clone()]
+            ),
+            Error::<Test>::InsufficientAttestations
+        );
+        assert_ok!(CreditAccumulation::verify_atomos_attestation(
+            &message,
+            &[bob_sig, charlie_sig]
+        ));
+
+        // messages which move value need the attestation, its signatures concatenated
+        let payload_hash = blake2_256(&1u8.encode());
+        let message = CreditAccumulation::atmos_message(
+            AtmosPurpose::StakingDelegate,
             nonce,
-            &sig.encode(),
-            alice()
+            VALID_UNTIL,
+            payload_hash,
+            alice(),
+        );
+        let bob_sig = atmos_sign(&bob(), &message);
+        let charlie_sig = atmos_sign(&charlie(), &message);
+        let verify = |signature: Vec<u8>| {
+            <CreditAccumulation as VerifySignatureInterface<_, _>>::verify_atomos_signature(
+                AtmosPurpose::StakingDelegate,
+                nonce,
+                VALID_UNTIL,
+                payload_hash,
+                signature,
+                alice(),
+            )
+        };
+        assert!(!verify(bob_sig.clone()));
+        assert!(!verify(
+            [bob_sig.clone(), charlie_sig[..32].to_vec()].concat()
+        ));
+        assert!(verify([bob_sig, charlie_sig].concat()));
+    });
+}
+
+#[test]
+fn retired_atmos_signers_pruned() {
+    new_test_ext().execute_with(|| {
+        for key in [bob(), charlie()] {
+            assert_ok!(CreditAccumulation::add_atmos_signer(
+                RawOrigin::Root.into(),
+                key,
+                0,
+                None,
+            ));
+        }
+        assert_ok!(CreditAccumulation::retire_atmos_signer(
+            RawOrigin::Root.into(),
+            bob(),
+            10,
+        ));
+        run_to_block(20);
+        // a retired key does not count for the threshold
+        assert_noop!(
+            CreditAccumulation::set_atmos_threshold(RawOrigin::Root.into(), 2),
+            Error::<Test>::InvalidThreshold
+        );
+
+        // retired keys stay in the set during the grace period
+        assert_ok!(CreditAccumulation::add_atmos_signer(
+            RawOrigin::Root.into(),
+            AccountId32::new([1; 32]),
+            0,
+            None,
+        ));
+        assert_eq!(CreditAccumulation::atmos_signers().len(), 3);
+
+        run_to_block(110);
+        assert_ok!(CreditAccumulation::add_atmos_signer(
+            RawOrigin::Root.into(),
+            AccountId32::new([2; 32]),
+            0,
+            None,
         ));
+        assert_eq!(CreditAccumulation::atmos_signers().len(), 3);
+        assert!(!CreditAccumulation::atmos_signers()
+            .iter()
+            .any(|signer| signer.key == bob()));
+    });
+}
+
+#[test]
+fn atmos_message_purpose_and_expiry() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(CreditAccumulation::add_atmos_signer(
+            RawOrigin::Root.into(),
+            bob(),
+            0,
+            None,
+        ));
+        let nonce: u64 = 0;
+        let signature = traffic_signature(nonce, &alice());
+        assert!(
+            <CreditAccumulation as VerifySignatureInterface<_, _>>::verify_atomos_signature(
+                AtmosPurpose::Traffic,
+                nonce,
+                VALID_UNTIL,
+                blake2_256(&[]),
+                signature.clone(),
+                alice()
+            )
+        );
+
+        // a traffic attestation is not a staking delegation
+        assert!(
+            !<CreditAccumulation as VerifySignatureInterface<_, _>>::verify_atomos_signature(
+                AtmosPurpose::StakingDelegate,
+                nonce,
+                VALID_UNTIL,
+                blake2_256(&[]),
+                signature.clone(),
+                alice()
+            )
+        );
+
+        // the payload and expiry are signed too
+        assert!(
+            !<CreditAccumulation as VerifySignatureInterface<_, _>>::verify_atomos_signature(
+                AtmosPurpose::Traffic,
+                nonce,
+                VALID_UNTIL + 1,
+                blake2_256(&[]),
+                signature.clone(),
+                alice()
+            )
+        );
+        assert!(
+            !<CreditAccumulation as VerifySignatureInterface<_, _>>::verify_atomos_signature(
+                AtmosPurpose::Traffic,
+                nonce,
+                VALID_UNTIL,
+                blake2_256(&1u8.encode()),
+                signature.clone(),
+                alice()
+            )
+        );
+
+        // expired
+        run_to_block(VALID_UNTIL + 1);
+        assert_eq!(
+            CreditAccumulation::add_credit_by_traffic(
+                RuntimeOrigin::signed(alice()),
+                nonce,
+                VALID_UNTIL,
+                signature
+            ),
+            Err(DispatchErrorWithPostInfo::from(
+                Error::<Test>::AtmosMessageExpired
+            ))
+        );
+    });
+}
+
+#[test]
+fn relay_traffic_attestations() {
+    new_test_ext().execute_with(|| {
+        run_to_block(1);
+        assert_ok!(CreditAccumulation::add_atmos_signer(
+            RawOrigin::Root.into(),
+            bob(),
+            0,
+            None,
+        ));
+        let attestation =
+            |server: AccountId32, nonce: u64, signature: Vec<u8>| TrafficAttestation {
+                server,
+                nonce,
+                valid_until: VALID_UNTIL,
+                signature,
+            };
+
+        // NotAtmosRelayer
+        assert_noop!(
+            CreditAccumulation::relay_traffic_attestations(
+                RuntimeOrigin::signed(charlie()),
+                vec![attestation(alice(), 0, traffic_signature(0, &alice()))]
+                    .try_into()
+                    .unwrap()
+            ),
+            Error::<Test>::NotAtmosRelayer
+        );
+        assert_ok!(UserPrivileges::set_user_privilege(
+            RawOrigin::Root.into(),
+            charlie(),
+            Privilege::AtmosRelayer
+        ));
+
+        // EmptyAttestationBatch, an empty batch would be free
+        assert_noop!(
+            CreditAccumulation::relay_traffic_attestations(
+                RuntimeOrigin::signed(charlie()),
+                Default::default()
+            ),
+            Error::<Test>::EmptyAttestationBatch
+        );
+
+        // every attestation is applied on its own
+        let post_info = CreditAccumulation::relay_traffic_attestations(
+            RuntimeOrigin::signed(charlie()),
+            vec![
+                attestation(alice(), 0, traffic_signature(0, &alice())),
+                attestation(bob(), 0, traffic_signature(0, &alice())),
+                attestation(alice(), 1, traffic_signature(1, &alice())),
+            ]
+            .try_into()
+            .unwrap(),
+        )
+        .unwrap();
+        assert_eq!(post_info.pays_fee, Pays::Yes);
+        assert_eq!(CreditAccumulation::atmos_nonce(alice()), Some(2));
+        assert_eq!(CreditAccumulation::atmos_nonce(bob()), None);
+        System::assert_last_event(RuntimeEvent::CreditAccumulation(
+            crate::Event::TrafficAttestationsRelayed(
+                charlie(),
+                vec![
+                    (alice(), Ok(())),
+                    (bob(), Err(Error::<Test>::InvalidSignature.into())),
+                    (alice(), Ok(())),
+                ],
+            ),
+        ));
+
+        // the relayer does not pay for a fully valid batch
+        let post_info = CreditAccumulation::relay_traffic_attestations(
+            RuntimeOrigin::signed(charlie()),
+            vec![attestation(alice(), 2, traffic_signature(2, &alice()))]
+                .try_into()
+                .unwrap(),
+        )
+        .unwrap();
+        assert_eq!(post_info.pays_fee, Pays::No);
+        assert_eq!(CreditAccumulation::atmos_nonce(alice()), Some(3));
     });
 }
@@ -35,7 +35,11 @@ use core::marker::PhantomData;
 /// Weight functions needed for `pallet_credit_accumulation`.
 pub trait WeightInfo {
 	fn add_credit_by_traffic() -> Weight;
-	fn set_atmos_pubkey() -> Weight;
+	fn add_atmos_signer() -> Weight;
+	fn retire_atmos_signer() -> Weight;
+	fn remove_atmos_signer() -> Weight;
+	fn set_atmos_threshold() -> Weight;
+	fn relay_traffic_attestations(n: u32, ) -> Weight;
 }
 
 /// Weights for `pallet_credit_accumulation` using the Substrate node and recommended hardware.
@@ -43,10 +47,12 @@ pub struct SubstrateWeight<T>(PhantomData<T>);
 impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	/// Storage: `CreditAccumulation::AtmosNonce` (r:1 w:1)
 	/// Proof: `CreditAccumulation::AtmosNonce` (`max_values`: None, `max_size`: None, mode: `Measured`)
-	/// Storage: `CreditAccumulation::AtmosAccountid` (r:1 w:0)
-	/// Proof: `CreditAccumulation::AtmosAccountid` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
-	/// Storage: `CreditAccumulation::TmpAtmosAccountid` (r:1 w:0)
-	/// Proof: `CreditAccumulation::TmpAtmosAccountid` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// Storage: `CreditAccumulation::AtmosNonceSince` (r:1 w:1)
+	/// Proof: `CreditAccumulation::AtmosNonceSince` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `CreditAccumulation::AtmosSigners` (r:1 w:0)
+	/// Proof: `CreditAccumulation::AtmosSigners` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// Storage: `System::BlockHash` (r:1 w:0)
+	/// Proof: `System::BlockHash` (`max_values`: None, `max_size`: Some(44), added: 2519, mode: `MaxEncodedLen`)
 	/// Storage: `DeeperNode::OnboardTime` (r:1 w:0)
 	/// Proof: `DeeperNode::OnboardTime` (`max_values`: None, `max_size`: None, mode: `Measured`)
 	fn add_credit_by_traffic() -> Weight {
@@ -55,29 +61,99 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 		//  Estimated: `3843`
 		// Minimum execution time: 584_376_000 picoseconds.
 		Weight::from_parts(596_257_000, 3843)
-			.saturating_add(T::DbWeight::get().reads(4_u64))
-			.saturating_add(T::DbWeight::get().writes(1_u64))
+			.saturating_add(T::DbWeight::get().reads(5_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+	/// Storage: `CreditAccumulation::AtmosSigners` (r:1 w:1)
+	/// Proof: `CreditAccumulation::AtmosSigners` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// Storage: `CreditAccumulation::AtmosSignerSetVersion` (r:1 w:1)
+	/// Proof: `CreditAccumulation::AtmosSignerSetVersion` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	fn add_atmos_signer() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `297`
+		//  Estimated: `1782`
+		// Minimum execution time: 98_412_000 picoseconds.
+		Weight::from_parts(102_873_000, 1782)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
 	}
-	/// Storage: `CreditAccumulation::AtmosAccountid` (r:0 w:1)
-	/// Proof: `CreditAccumulation::AtmosAccountid` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
-	fn set_atmos_pubkey() -> Weight {
+	/// Storage: `CreditAccumulation::AtmosSigners` (r:1 w:1)
+	/// Proof: `CreditAccumulation::AtmosSigners` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// Storage: `CreditAccumulation::AtmosSignerSetVersion` (r:1 w:1)
+	/// Proof: `CreditAccumulation::AtmosSignerSetVersion` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	fn retire_atmos_signer() -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `0`
-		//  Estimated: `0`
-		// Minimum execution time: 86_237_000 picoseconds.
-		Weight::from_parts(90_966_000, 0)
+		//  Measured:  `297`
+		//  Estimated: `1782`
+		// Minimum execution time: 97_105_000 picoseconds.
+		Weight::from_parts(101_330_000, 1782)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+	/// Storage: `CreditAccumulation::AtmosSigners` (r:1 w:1)
+	/// Proof: `CreditAccumulation::AtmosSigners` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// Storage: `CreditAccumulation::AtmosSignerSetVersion` (r:1 w:1)
+	/// Proof: `CreditAccumulation::AtmosSignerSetVersion` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	fn remove_atmos_signer() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `297`
+		//  Estimated: `1782`
+		// Minimum execution time: 96_688_000 picoseconds.
+		Weight::from_parts(100_941_000, 1782)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+	/// Storage: `CreditAccumulation::AtmosSigners` (r:1 w:0)
+	/// Proof: `CreditAccumulation::AtmosSigners` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// Storage: `CreditAccumulation::AtmosThreshold` (r:0 w:1)
+	/// Proof: `CreditAccumulation::AtmosThreshold` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	fn set_atmos_threshold() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `297`
+		//  Estimated: `1782`
+		// Minimum execution time: 91_637_000 picoseconds.
+		Weight::from_parts(95_118_000, 1782)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
 			.saturating_add(T::DbWeight::get().writes(1_u64))
 	}
+	/// Storage: `UserPrivileges::UserPrivileges` (r:1 w:0)
+	/// Proof: `UserPrivileges::UserPrivileges` (`max_values`: None, `max_size`: Some(48), added: 2523, mode: `MaxEncodedLen`)
+	/// Storage: `CreditAccumulation::AtmosNonce` (r:64 w:64)
+	/// Proof: `CreditAccumulation::AtmosNonce` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `CreditAccumulation::AtmosNonceSince` (r:64 w:64)
+	/// Proof: `CreditAccumulation::AtmosNonceSince` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `CreditAccumulation::AtmosSigners` (r:1 w:0)
+	/// Proof: `CreditAccumulation::AtmosSigners` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// Storage: `System::BlockHash` (r:1 w:0)
+	/// Proof: `System::BlockHash` (`max_values`: None, `max_size`: Some(44), added: 2519, mode: `MaxEncodedLen`)
+	/// Storage: `DeeperNode::OnboardTime` (r:64 w:0)
+	/// Proof: `DeeperNode::OnboardTime` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// The range of component `n` is `[1, 64]`.
+	fn relay_traffic_attestations(n: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `412 + n * (163 ±0)`
+		//  Estimated: `3877 + n * (2638 ±0)`
+		// Minimum execution time: 612_804_000 picoseconds.
+		Weight::from_parts(78_413_200, 3877)
+			// Standard Error: 96_218
+			.saturating_add(Weight::from_parts(571_932_450, 0).saturating_mul(n.into()))
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().reads((3_u64).saturating_mul(n.into())))
+			.saturating_add(T::DbWeight::get().writes((2_u64).saturating_mul(n.into())))
+			.saturating_add(Weight::from_parts(0, 2638).saturating_mul(n.into()))
+	}
 }
 
 // For backwards compatibility and tests.
 impl WeightInfo for () {
 	/// Storage: `CreditAccumulation::AtmosNonce` (r:1 w:1)
 	/// Proof: `CreditAccumulation::AtmosNonce` (`max_values`: None, `max_size`: None, mode: `Measured`)
-	/// Storage: `CreditAccumulation::AtmosAccountid` (r:1 w:0)
-	/// Proof: `CreditAccumulation::AtmosAccountid` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
-	/// Storage: `CreditAccumulation::TmpAtmosAccountid` (r:1 w:0)
-	/// Proof: `CreditAccumulation::TmpAtmosAccountid` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// Storage: `CreditAccumulation::AtmosNonceSince` (r:1 w:1)
+	/// Proof: `CreditAccumulation::AtmosNonceSince` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `CreditAccumulation::AtmosSigners` (r:1 w:0)
+	/// Proof: `CreditAccumulation::AtmosSigners` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// Storage: `System::BlockHash` (r:1 w:0)
+	/// Proof: `System::BlockHash` (`max_values`: None, `max_size`: Some(44), added: 2519, mode: `MaxEncodedLen`)
 	/// Storage: `DeeperNode::OnboardTime` (r:1 w:0)
 	/// Proof: `DeeperNode::OnboardTime` (`max_values`: None, `max_size`: None, mode: `Measured`)
 	fn add_credit_by_traffic() -> Weight {
@@ -86,17 +162,85 @@ impl WeightInfo for () {
 		//  Estimated: `3843`
 		// Minimum execution time: 584_376_000 picoseconds.
 		Weight::from_parts(596_257_000, 3843)
-			.saturating_add(RocksDbWeight::get().reads(4_u64))
-			.saturating_add(RocksDbWeight::get().writes(1_u64))
+			.saturating_add(RocksDbWeight::get().reads(5_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+	/// Storage: `CreditAccumulation::AtmosSigners` (r:1 w:1)
+	/// Proof: `CreditAccumulation::AtmosSigners` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// Storage: `CreditAccumulation::AtmosSignerSetVersion` (r:1 w:1)
+	/// Proof: `CreditAccumulation::AtmosSignerSetVersion` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	fn add_atmos_signer() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `297`
+		//  Estimated: `1782`
+		// Minimum execution time: 98_412_000 picoseconds.
+		Weight::from_parts(102_873_000, 1782)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
 	}
-	/// Storage: `CreditAccumulation::AtmosAccountid` (r:0 w:1)
-	/// Proof: `CreditAccumulation::AtmosAccountid` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
-	fn set_atmos_pubkey() -> Weight {
+	/// Storage: `CreditAccumulation::AtmosSigners` (r:1 w:1)
+	/// Proof: `CreditAccumulation::AtmosSigners` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// Storage: `CreditAccumulation::AtmosSignerSetVersion` (r:1 w:1)
+	/// Proof: `CreditAccumulation::AtmosSignerSetVersion` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	fn retire_atmos_signer() -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `0`
-		//  Estimated: `0`
-		// Minimum execution time: 86_237_000 picoseconds.
-		Weight::from_parts(90_966_000, 0)
+		//  Measured:  `297`
+		//  Estimated: `1782`
+		// Minimum execution time: 97_105_000 picoseconds.
+		Weight::from_parts(101_330_000, 1782)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+	/// Storage: `CreditAccumulation::AtmosSigners` (r:1 w:1)
+	/// Proof: `CreditAccumulation::AtmosSigners` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// Storage: `CreditAccumulation::AtmosSignerSetVersion` (r:1 w:1)
+	/// Proof: `CreditAccumulation::AtmosSignerSetVersion` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	fn remove_atmos_signer() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `297`
+		//  Estimated: `1782`
+		// Minimum execution time: 96_688_000 picoseconds.
+		Weight::from_parts(100_941_000, 1782)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+	/// Storage: `CreditAccumulation::AtmosSigners` (r:1 w:0)
+	/// Proof: `CreditAccumulation::AtmosSigners` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// Storage: `CreditAccumulation::AtmosThreshold` (r:0 w:1)
+	/// Proof: `CreditAccumulation::AtmosThreshold` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	fn set_atmos_threshold() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `297`
+		//  Estimated: `1782`
+		// Minimum execution time: 91_637_000 picoseconds.
+		Weight::from_parts(95_118_000, 1782)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
 			.saturating_add(RocksDbWeight::get().writes(1_u64))
 	}
+	/// Storage: `UserPrivileges::UserPrivileges` (r:1 w:0)
+	/// Proof: `UserPrivileges::UserPrivileges` (`max_values`: None, `max_size`: Some(48), added: 2523, mode: `MaxEncodedLen`)
+	/// Storage: `CreditAccumulation::AtmosNonce` (r:64 w:64)
+	/// Proof: `CreditAccumulation::AtmosNonce` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `CreditAccumulation::AtmosNonceSince` (r:64 w:64)
+	/// Proof: `CreditAccumulation::AtmosNonceSince` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `CreditAccumulation::AtmosSigners` (r:1 w:0)
+	/// Proof: `CreditAccumulation::AtmosSigners` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// Storage: `System::BlockHash` (r:1 w:0)
+	/// Proof: `System::BlockHash` (`max_values`: None, `max_size`: Some(44), added: 2519, mode: `MaxEncodedLen`)
+	/// Storage: `DeeperNode::OnboardTime` (r:64 w:0)
+	/// Proof: `DeeperNode::OnboardTime` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// The range of component `n` is `[1, 64]`.
+	fn relay_traffic_attestations(n: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `412 + n * (163 ±0)`
+		//  Estimated: `3877 + n * (2638 ±0)`
+		// Minimum execution time: 612_804_000 picoseconds.
+		Weight::from_parts(78_413_200, 3877)
+			// Standard Error: 96_218
+			.saturating_add(Weight::from_parts(571_932_450, 0).saturating_mul(n.into()))
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().reads((3_u64).saturating_mul(n.into())))
+			.saturating_add(RocksDbWeight::get().writes((2_u64).saturating_mul(n.into())))
+			.saturating_add(Weight::from_parts(0, 2638).saturating_mul(n.into()))
+	}
 }
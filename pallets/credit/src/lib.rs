@@ -29,6 +29,11 @@ mod credit_setting;
 pub mod weights;
 pub(crate) const LOG_TARGET: &'static str = "credit";
 pub(crate) const USDT_CAMPAIGN_ID: u16 = 5;
+/// Credited accounts an era needs before its traffic statistics are used to flag anomalies
+pub(crate) const MIN_ANOMALY_SAMPLES: u32 = 16;
+/// Traffic credit increases an account needs before its own history is used to flag anomalies
+pub(crate) const MIN_ACCOUNT_ANOMALY_SAMPLES: u32 = 4;
+pub(crate) const SECS_PER_DAY: u64 = 86_400;
 
 // syntactic sugar for logging.
 #[macro_export]
@@ -65,7 +70,7 @@ pub mod pallet {
             CREDIT_CAP_ONE_ERAS, DEFAULT_REWARD_ERAS, OLD_REWARD_ERAS,
         },
         deeper_node::NodeInterface,
-        user_privileges::{Privilege, UserPrivilegeInterface},
+        user_privileges::{Privilege, QuotaResource, UserPrivilegeInterface},
         DPR,
     };
     use scale_info::prelude::string::{String, ToString};
@@ -107,6 +112,11 @@ pub mod pallet {
 
         #[pallet::constant]
         type MaxBurnCreditPerAddress: Get<u32>;
+
+        /// How many standard deviations below the mean traffic of the previous era an
+        /// account's traffic must be to hold its traffic credit for review
+        #[pallet::constant]
+        type AnomalyDeviations: Get<u32>;
     }
 
     pub type BalanceOf<T> =
@@ -128,6 +138,25 @@ pub mod pallet {
         V5_0_0,
     }
 
+    /// Network wide limits on the credit added by traffic, `None` means unlimited
+    #[derive(
+        Encode, Decode, Clone, Copy, Default, PartialEq, Eq, RuntimeDebug, MaxEncodedLen, TypeInfo,
+    )]
+    pub struct TrafficCreditCap {
+        pub per_era: Option<u64>,
+        pub per_day: Option<u64>,
+    }
+
+    /// Daily sharing traffic of the accounts credited by traffic in an era
+    #[derive(
+        Encode, Decode, Clone, Copy, Default, PartialEq, Eq, RuntimeDebug, MaxEncodedLen, TypeInfo,
+    )]
+    pub struct TrafficStat {
+        pub count: u32,
+        pub sum: u128,
+        pub sum_sq: u128,
+    }
+
     #[pallet::pallet]
     #[pallet::without_storage_info]
     pub struct Pallet<T>(_);
@@ -327,6 +356,37 @@ pub mod pallet {
     pub(crate) type DPRPerCreditBurned<T: Config> =
         StorageValue<_, BalanceOf<T>, ValueQuery, DefaultBurnDpr<T>>;
 
+    #[pallet::storage]
+    #[pallet::getter(fn traffic_credit_cap)]
+    pub(crate) type TrafficCreditCaps<T: Config> = StorageValue<_, TrafficCreditCap, ValueQuery>;
+
+    /// Credit added by traffic in the era
+    #[pallet::storage]
+    pub(crate) type TrafficCreditIssuedInEra<T: Config> =
+        StorageValue<_, (EraIndex, u64), ValueQuery>;
+
+    /// Credit added by traffic in the day, days are counted from the unix epoch
+    #[pallet::storage]
+    pub(crate) type TrafficCreditIssuedInDay<T: Config> = StorageValue<_, (u64, u64), ValueQuery>;
+
+    /// Traffic statistics of the current and the previous era
+    #[pallet::storage]
+    #[pallet::getter(fn traffic_stat)]
+    pub(crate) type TrafficStats<T: Config> =
+        StorageMap<_, Twox64Concat, EraIndex, TrafficStat, ValueQuery>;
+
+    /// Traffic statistics of the traffic credit increases of each account
+    #[pallet::storage]
+    #[pallet::getter(fn account_traffic_stat)]
+    pub(crate) type AccountTrafficStats<T: Config> =
+        StorageMap<_, Blake2_128Concat, T::AccountId, TrafficStat, ValueQuery>;
+
+    /// Traffic credit held for review by a credit admin
+    #[pallet::storage]
+    #[pallet::getter(fn pending_traffic_credit)]
+    pub(crate) type PendingTrafficCredit<T: Config> =
+        StorageMap<_, Blake2_128Concat, T::AccountId, u64, OptionQuery>;
+
     #[pallet::genesis_config]
     #[derive(frame_support::DefaultNoBound)]
     pub struct GenesisConfig<T: Config> {
@@ -371,6 +431,15 @@ pub mod pallet {
         SetAdmin(T::AccountId),
         UnstakingResult(T::AccountId, String),
         DPRPrice(BalanceOf<T>, H160),
+        TrafficCreditCapSet(TrafficCreditCap),
+        /// The per era or per day cap is reached, the account retries later
+        TrafficCreditCapReached(T::AccountId),
+        /// Account, daily sharing traffic
+        TrafficCreditFlagged(T::AccountId, u64),
+        /// Account, credit pending review
+        TrafficCreditHeld(T::AccountId, u64),
+        /// Account, credit, approved
+        TrafficCreditReviewed(T::AccountId, u64, bool),
     }
 
     #[pallet::error]
@@ -405,6 +474,8 @@ pub mod pallet {
         PriceZero,
         /// not device admin
         NotDeviceAdmin,
+        /// no traffic credit pending review
+        NoPendingTrafficCredit,
     }
 
     #[pallet::hooks]
@@ -739,13 +810,20 @@ pub mod pallet {
         }
 
         #[pallet::call_index(12)]
-        #[pallet::weight(Weight::from_parts(10_000u64,0) + T::DbWeight::get().reads_writes(0,1))]
+        #[pallet::weight(Weight::from_parts(10_000u64,0) + T::DbWeight::get().reads_writes(5,3))]
         pub fn set_user_staking_credit(
             origin: OriginFor<T>,
             user_scores: Vec<(T::AccountId, u64)>,
         ) -> DispatchResult {
             let admin = ensure_signed(origin)?;
             ensure!(Self::is_admin(&admin), Error::<T>::NotAdmin);
+            // every score set counts against the quota
+            T::UserPrivilegeInterface::consume_quota(
+                &admin,
+                Privilege::CreditAdmin,
+                QuotaResource::StakingCredit,
+                user_scores.len() as u128,
+            )?;
 
             for (user, score) in user_scores {
                 UserStakingCredit::<T>::insert(user, score);
@@ -884,6 +962,48 @@ pub mod pallet {
             DPRPerCreditBurned::<T>::put(amount);
             Ok(().into())
         }
+
+        #[pallet::call_index(21)]
+        #[pallet::weight(Weight::from_parts(10_000u64,0) + T::DbWeight::get().writes(1))]
+        pub fn set_traffic_credit_cap(
+            origin: OriginFor<T>,
+            cap: TrafficCreditCap,
+        ) -> DispatchResult {
+            ensure_root(origin)?;
+            TrafficCreditCaps::<T>::put(cap);
+            Self::deposit_event(Event::TrafficCreditCapSet(cap));
+            Ok(().into())
+        }
+
+        /// Credit or drop the traffic credit held for an account. Approved credit counts
+        /// towards the caps but is not limited by them.
+        #[pallet::call_index(22)]
+        #[pallet::weight(Weight::from_parts(10_000u64,0) + T::DbWeight::get().reads_writes(6,5))]
+        pub fn review_traffic_credit(
+            origin: OriginFor<T>,
+            account_id: T::AccountId,
+            approve: bool,
+        ) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+            ensure!(Self::is_admin(&who), Error::<T>::NotAdmin);
+            let pending = PendingTrafficCredit::<T>::take(&account_id)
+                .ok_or(Error::<T>::NoPendingTrafficCredit)?;
+            if approve {
+                ensure!(
+                    UserCredit::<T>::contains_key(&account_id),
+                    Error::<T>::AccountNoExistInUserCredit
+                );
+                let new_credit = Self::get_credit_score(&account_id)
+                    .unwrap_or(0)
+                    .saturating_add(pending);
+                Self::_update_credit(&account_id, new_credit);
+                let current_era = Self::get_current_era();
+                Self::update_credit_history(&account_id, current_era);
+                Self::record_traffic_credit(current_era, T::UnixTime::now().as_secs(), pending);
+            }
+            Self::deposit_event(Event::TrafficCreditReviewed(account_id, pending, approve));
+            Ok(().into())
+        }
     }
 
     impl<T: Config> Pallet<T> {
@@ -1525,12 +1645,42 @@ pub mod pallet {
                 now_as_secs,
             );
             if time_eras >= CREDIT_CAP_ONE_ERAS {
+                let traffic = T::NodeInterface::get_daily_sharing_traffic(&server_id);
+                let pending = PendingTrafficCredit::<T>::contains_key(&server_id);
+                if pending || Self::is_traffic_anomalous(&server_id, current_era, traffic) {
+                    if !pending {
+                        Self::deposit_event(Event::TrafficCreditFlagged(
+                            server_id.clone(),
+                            traffic.unwrap_or_default(),
+                        ));
+                    }
+                    let held = PendingTrafficCredit::<T>::mutate(&server_id, |held| {
+                        let held = held.get_or_insert(0);
+                        *held = held.saturating_add(One::one());
+                        *held
+                    });
+                    LastCreditUpdateTimestamp::<T>::insert(&server_id, now_as_secs);
+                    Self::deposit_event(Event::TrafficCreditHeld(server_id.clone(), held));
+                    if era_used {
+                        LastCreditUpdate::<T>::remove(server_id);
+                    }
+                    return;
+                }
+                if !Self::traffic_credit_below_cap(current_era, now_as_secs) {
+                    // the timestamp is kept so that the device is credited once the cap resets
+                    Self::deposit_event(Event::TrafficCreditCapReached(server_id));
+                    return;
+                }
                 let new_credit = Self::get_credit_score(&server_id)
                     .unwrap_or(0)
                     .saturating_add(One::one());
                 if Self::_update_credit(&server_id, new_credit) {
                     LastCreditUpdateTimestamp::<T>::insert(&server_id, now_as_secs);
                     Self::update_credit_history(&server_id, current_era);
+                    Self::record_traffic_credit(current_era, now_as_secs, One::one());
+                    if let Some(traffic) = traffic {
+                        Self::record_traffic_stat(&server_id, current_era, traffic);
+                    }
                     Self::deposit_event(Event::CreditDataAddedByTraffic(
                         server_id.clone(),
                         new_credit,
@@ -1550,6 +1700,96 @@ pub mod pallet {
             }
         }
 
+        /// The traffic is anomalous when it is missing or more than `AnomalyDeviations` standard
+        /// deviations below the mean traffic of the accounts credited in the previous era, or
+        /// when it is below half the mean traffic of the account's own earlier credit increases
+        fn is_traffic_anomalous(
+            server_id: &T::AccountId,
+            current_era: EraIndex,
+            traffic: Option<u64>,
+        ) -> bool {
+            let stat = Self::traffic_stat(current_era.saturating_sub(1));
+            let traffic = match traffic {
+                Some(traffic) => U256::from(traffic),
+                None => return stat.count >= MIN_ANOMALY_SAMPLES,
+            };
+            // the account keeps earning credit at the same rate while its traffic collapses
+            let own = Self::account_traffic_stat(server_id);
+            if own.count >= MIN_ACCOUNT_ANOMALY_SAMPLES
+                && U256::from(own.count)
+                    .saturating_mul(traffic)
+                    .saturating_mul(U256::from(2u8))
+                    < U256::from(own.sum)
+            {
+                return true;
+            }
+            if stat.count < MIN_ANOMALY_SAMPLES {
+                return false;
+            }
+            // compare n * (mean - traffic) with k * n * stddev, both sides squared
+            let count = U256::from(stat.count);
+            let sum = U256::from(stat.sum);
+            let scaled_traffic = count.saturating_mul(traffic);
+            if scaled_traffic >= sum {
+                return false;
+            }
+            let gap = sum - scaled_traffic;
+            let variance = count
+                .saturating_mul(U256::from(stat.sum_sq))
+                .saturating_sub(sum.saturating_mul(sum));
+            let deviations = U256::from(T::AnomalyDeviations::get());
+            gap.saturating_mul(gap)
+                > deviations
+                    .saturating_mul(deviations)
+                    .saturating_mul(variance)
+        }
+
+        fn traffic_credit_below_cap(current_era: EraIndex, now_as_secs: u64) -> bool {
+            let cap = Self::traffic_credit_cap();
+            let era_issued = match TrafficCreditIssuedInEra::<T>::get() {
+                (era, issued) if era == current_era => issued,
+                _ => 0,
+            };
+            let day_issued = match TrafficCreditIssuedInDay::<T>::get() {
+                (day, issued) if day == now_as_secs / SECS_PER_DAY => issued,
+                _ => 0,
+            };
+            cap.per_era.map_or(true, |cap| era_issued < cap)
+                && cap.per_day.map_or(true, |cap| day_issued < cap)
+        }
+
+        fn record_traffic_credit(current_era: EraIndex, now_as_secs: u64, credit: u64) {
+            TrafficCreditIssuedInEra::<T>::mutate(|(era, issued)| {
+                if *era != current_era {
+                    *era = current_era;
+                    *issued = 0;
+                }
+                *issued = issued.saturating_add(credit);
+            });
+            let today = now_as_secs / SECS_PER_DAY;
+            TrafficCreditIssuedInDay::<T>::mutate(|(day, issued)| {
+                if *day != today {
+                    *day = today;
+                    *issued = 0;
+                }
+                *issued = issued.saturating_add(credit);
+            });
+        }
+
+        fn record_traffic_stat(server_id: &T::AccountId, current_era: EraIndex, traffic: u64) {
+            if !TrafficStats::<T>::contains_key(current_era) {
+                TrafficStats::<T>::remove(current_era.saturating_sub(2));
+            }
+            let traffic = u128::from(traffic);
+            let add_sample = |stat: &mut TrafficStat| {
+                stat.count = stat.count.saturating_add(1);
+                stat.sum = stat.sum.saturating_add(traffic);
+                stat.sum_sq = stat.sum_sq.saturating_add(traffic.saturating_mul(traffic));
+            };
+            TrafficStats::<T>::mutate(current_era, add_sample);
+            AccountTrafficStats::<T>::mutate(server_id, add_sample);
+        }
+
         fn update_credit_by_tip(who: T::AccountId, add_credit: u64) {
             let onboard_era = Self::get_onboard_era(&who);
             if onboard_era.is_none() {
@@ -137,6 +137,7 @@ parameter_types! {
     pub const MinLockAmt: u32 = 100;
     pub const MaxDurationEras: u8 = 7;
     pub const MaxIpLength: usize = 256;
+    pub const ReputationDecay: sp_runtime::Perbill = sp_runtime::Perbill::from_percent(90);
 }
 impl pallet_deeper_node::Config for Test {
     type RuntimeEvent = RuntimeEvent;
@@ -147,6 +148,11 @@ impl pallet_deeper_node::Config for Test {
     type MaxIpLength = MaxIpLength;
     type WeightInfo = ();
     type VerifySignatureInterface = ();
+    type RegionOrigin = frame_system::EnsureRoot<Self::AccountId>;
+    type DeviceSignature = sp_runtime::testing::TestSignature;
+    type UnsignedPriority = frame_support::traits::ConstU64<700>;
+    type MaxCreditProofs = frame_support::traits::ConstU32<4>;
+    type ReputationDecay = ReputationDecay;
 }
 
 pub const MILLISECS_PER_BLOCK: Moment = 5000;
@@ -178,6 +184,11 @@ impl pallet_user_privileges::Config for Test {
     type RuntimeEvent = RuntimeEvent;
     type ForceOrigin = frame_system::EnsureRoot<u64>;
     type WeightInfo = ();
+    type BlocksPerDay = frame_support::traits::ConstU64<14_400>;
+    type RuntimeCall = RuntimeCall;
+    type ProposalTimeout = frame_support::traits::ConstU64<100>;
+    type MaxApprovals = frame_support::traits::ConstU32<16>;
+    type ProposableCalls = frame_support::traits::Everything;
 }
 
 const MILLICENTS: Balance = 10_000_000_000_000;
@@ -213,6 +224,7 @@ impl pallet_uniques::Config for Test {
 
 parameter_types! {
     pub const MaxBurnCreditPerAddress: u32 = 50;
+    pub const AnomalyDeviations: u32 = 3;
 }
 
 impl pallet_credit::Config for Test {
@@ -229,6 +241,7 @@ impl pallet_credit::Config for Test {
     type BurnedTo = Treasury;
     type UserPrivilegeInterface = UserPrivileges;
     type MaxBurnCreditPerAddress = MaxBurnCreditPerAddress;
+    type AnomalyDeviations = AnomalyDeviations;
 }
 
 // Build genesis storage according to the mock runtime.
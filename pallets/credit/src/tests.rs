@@ -14,7 +14,10 @@
 // limitations under the License.
 
 #[cfg(test)]
-use crate::{mock::*, CampaignIdSwitch, Error, MaintainDevices, UserCredit, UserCreditHistory};
+use crate::{
+    mock::*, AccountTrafficStats, CampaignIdSwitch, Error, MaintainDevices, TrafficCreditCap,
+    TrafficStat, TrafficStats, UserCredit, UserCreditHistory,
+};
 use frame_support::{assert_err, assert_noop, assert_ok, traits::Currency};
 use frame_system::RawOrigin;
 use node_primitives::{
@@ -355,6 +358,182 @@ fn update_credit_by_traffic() {
     });
 }
 
+#[test]
+fn traffic_credit_cap() {
+    new_test_ext().execute_with(|| {
+        let cap = TrafficCreditCap {
+            per_era: Some(1),
+            per_day: Some(3),
+        };
+        assert_noop!(
+            Credit::set_traffic_credit_cap(RuntimeOrigin::signed(1), cap),
+            BadOrigin
+        );
+        assert_ok!(Credit::set_traffic_credit_cap(RuntimeOrigin::root(), cap));
+        assert_eq!(Credit::traffic_credit_cap(), cap);
+
+        assert_ok!(DeeperNode::im_online(RuntimeOrigin::signed(1)));
+        assert_ok!(DeeperNode::im_online(RuntimeOrigin::signed(2)));
+        run_to_block(BLOCKS_PER_ERA * 2);
+        Credit::update_credit_by_traffic(1);
+        assert_eq!(Credit::user_credit(&1).unwrap().credit, 1);
+        // the era cap is reached, 2 is credited in the next era
+        Credit::update_credit_by_traffic(2);
+        assert_eq!(Credit::user_credit(&2).unwrap().credit, 0);
+        assert!(System::events()
+            .iter()
+            .any(|record| record.event
+                == RuntimeEvent::Credit(crate::Event::TrafficCreditCapReached(2))));
+
+        run_to_block(BLOCKS_PER_ERA * 3);
+        Credit::update_credit_by_traffic(2);
+        assert_eq!(Credit::user_credit(&2).unwrap().credit, 1);
+
+        run_to_block(BLOCKS_PER_ERA * 4);
+        Credit::update_credit_by_traffic(1);
+        assert_eq!(Credit::user_credit(&1).unwrap().credit, 2);
+        // all eras so far are in the same day and the day cap is reached
+        run_to_block(BLOCKS_PER_ERA * 5);
+        Credit::update_credit_by_traffic(2);
+        assert_eq!(Credit::user_credit(&2).unwrap().credit, 1);
+
+        assert_ok!(Credit::set_traffic_credit_cap(
+            RuntimeOrigin::root(),
+            TrafficCreditCap::default()
+        ));
+        Credit::update_credit_by_traffic(2);
+        assert_eq!(Credit::user_credit(&2).unwrap().credit, 2);
+    });
+}
+
+#[test]
+fn anomalous_traffic_credit_is_held_for_review() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(DeeperNode::im_online(RuntimeOrigin::signed(1)));
+        assert_ok!(DeeperNode::im_online(RuntimeOrigin::signed(2)));
+        pallet_deeper_node::DeviceCreditProof::<Test>::insert(1, (0, 100, 86_400));
+        pallet_deeper_node::DeviceCreditProof::<Test>::insert(2, (0, 950, 86_400));
+        run_to_block(BLOCKS_PER_ERA * 2);
+        // sixteen accounts with a traffic of 900 or 1100 in the previous era
+        TrafficStats::<Test>::insert(
+            1,
+            TrafficStat {
+                count: 16,
+                sum: 16_000,
+                sum_sq: 8 * (900 * 900 + 1_100 * 1_100),
+            },
+        );
+
+        Credit::update_credit_by_traffic(1);
+        Credit::update_credit_by_traffic(2);
+        assert_eq!(Credit::user_credit(&1).unwrap().credit, 0);
+        assert_eq!(Credit::user_credit(&2).unwrap().credit, 1);
+        assert_eq!(Credit::pending_traffic_credit(&1), Some(1));
+        assert_eq!(Credit::pending_traffic_credit(&2), None);
+        assert_eq!(Credit::traffic_stat(2).count, 1);
+        assert!(System::events().iter().any(|record| record.event
+            == RuntimeEvent::Credit(crate::Event::TrafficCreditFlagged(1, 100))));
+
+        // held accounts stay held until they are reviewed
+        run_to_block(BLOCKS_PER_ERA * 3);
+        Credit::update_credit_by_traffic(1);
+        assert_eq!(Credit::pending_traffic_credit(&1), Some(2));
+
+        assert_noop!(
+            Credit::review_traffic_credit(RuntimeOrigin::signed(3), 1, true),
+            Error::<Test>::NotAdmin
+        );
+        assert_ok!(UserPrivileges::set_user_privilege(
+            RuntimeOrigin::root(),
+            3,
+            Privilege::CreditAdmin
+        ));
+        assert_noop!(
+            Credit::review_traffic_credit(RuntimeOrigin::signed(3), 2, true),
+            Error::<Test>::NoPendingTrafficCredit
+        );
+        assert_ok!(Credit::review_traffic_credit(
+            RuntimeOrigin::signed(3),
+            1,
+            true
+        ));
+        assert_eq!(Credit::user_credit(&1).unwrap().credit, 2);
+        assert_eq!(Credit::pending_traffic_credit(&1), None);
+
+        run_to_block(BLOCKS_PER_ERA * 4);
+        TrafficStats::<Test>::insert(
+            3,
+            TrafficStat {
+                count: 16,
+                sum: 16_000,
+                sum_sq: 8 * (900 * 900 + 1_100 * 1_100),
+            },
+        );
+        Credit::update_credit_by_traffic(1);
+        assert_eq!(Credit::pending_traffic_credit(&1), Some(1));
+        assert_ok!(Credit::review_traffic_credit(
+            RuntimeOrigin::signed(3),
+            1,
+            false
+        ));
+        assert_eq!(Credit::user_credit(&1).unwrap().credit, 2);
+        assert_eq!(Credit::pending_traffic_credit(&1), None);
+    });
+}
+
+#[test]
+fn missing_or_collapsed_traffic_is_anomalous() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(DeeperNode::im_online(RuntimeOrigin::signed(1)));
+        assert_ok!(DeeperNode::im_online(RuntimeOrigin::signed(2)));
+        pallet_deeper_node::DeviceCreditProof::<Test>::insert(2, (0, 400, 86_400));
+        run_to_block(BLOCKS_PER_ERA * 2);
+        TrafficStats::<Test>::insert(
+            1,
+            TrafficStat {
+                count: 16,
+                sum: 16_000,
+                sum_sq: 8 * (900 * 900 + 1_100 * 1_100),
+            },
+        );
+        // an account without a credit proof is flagged once the era has enough samples
+        Credit::update_credit_by_traffic(1);
+        assert_eq!(Credit::user_credit(&1).unwrap().credit, 0);
+        assert_eq!(Credit::pending_traffic_credit(&1), Some(1));
+
+        // four earlier credit increases backed by a traffic of 1000
+        AccountTrafficStats::<Test>::insert(
+            2,
+            TrafficStat {
+                count: 4,
+                sum: 4_000,
+                sum_sq: 4 * 1_000 * 1_000,
+            },
+        );
+        TrafficStats::<Test>::remove(1);
+        Credit::update_credit_by_traffic(2);
+        assert_eq!(Credit::user_credit(&2).unwrap().credit, 0);
+        assert_eq!(Credit::pending_traffic_credit(&2), Some(1));
+        assert!(System::events().iter().any(|record| record.event
+            == RuntimeEvent::Credit(crate::Event::TrafficCreditFlagged(2, 400))));
+
+        // traffic above half of its own mean keeps being credited
+        assert_ok!(DeeperNode::im_online(RuntimeOrigin::signed(4)));
+        pallet_deeper_node::DeviceCreditProof::<Test>::insert(4, (0, 600, 86_400));
+        AccountTrafficStats::<Test>::insert(
+            4,
+            TrafficStat {
+                count: 4,
+                sum: 4_000,
+                sum_sq: 4 * 1_000 * 1_000,
+            },
+        );
+        Credit::update_credit_by_traffic(4);
+        assert_eq!(Credit::user_credit(&4).unwrap().credit, 1);
+        assert_eq!(Credit::account_traffic_stat(&4).count, 5);
+    });
+}
+
 #[test]
 fn update_credit_by_tip() {
     new_test_ext().execute_with(|| {
@@ -23,7 +23,12 @@ use core::str::FromStr;
 pub use frame_benchmarking::{account, benchmarks, whitelist_account, whitelisted_caller};
 use frame_support::{assert_ok, traits::Currency};
 use frame_system::RawOrigin;
-use node_primitives::AccountCreator;
+use node_primitives::{
+    deeper_node::{Endpoint, EndpointRecord, IpAddress, TunnelProtocol},
+    AccountCreator, AtmosPurpose,
+};
+use sp_io::crypto::{ecdsa_generate, ecdsa_sign_prehashed};
+use sp_runtime::traits::TrailingZeroInput;
 use sp_std::vec;
 
 const SEED: u32 = 0;
@@ -42,6 +47,51 @@ pub fn create_funded_user<T: Config>(
     user
 }
 
+/// Eth address of a new keystore key and its signature binding `account` as reward address
+fn eth_signed_mapping<T: Config>(account: &T::AccountId) -> (H160, [u8; 65]) {
+    let public = ecdsa_generate(0.into(), None);
+    let nonce = DeeperNode::<T>::reward_mapping_nonce(account);
+    let hash = DeeperNode::<T>::reward_mapping_hash(account, nonce);
+    let signature = ecdsa_sign_prehashed(0.into(), &public, &hash)
+        .expect("keystore key should sign")
+        .0;
+    let eth_address =
+        DeeperNode::<T>::eth_recover(&signature, &hash).expect("signature should recover");
+    (eth_address, signature)
+}
+
+/// Atomos signature for nonce 0 of `sender`, valid until block 100
+fn atmos_signed<T: Config + pallet_credit_accumulation::Config>(
+    purpose: AtmosPurpose,
+    payload_hash: [u8; 32],
+    sender: &T::AccountId,
+) -> Vec<u8> {
+    let message = pallet_credit_accumulation::Pallet::<T>::atmos_message(
+        purpose,
+        0,
+        100u32.into(),
+        payload_hash,
+        sender.clone(),
+    );
+    pallet_credit_accumulation::benchmarking::atmos_signature::<T>(&message)
+}
+
+/// List `n` servers in `country`.
+pub fn register_servers<T: Config>(country: &str, n: u32) -> Result<(), &'static str> {
+    for i in 0..n {
+        let server = create_funded_user::<T>("server", i, 100);
+        DeeperNode::<T>::register_device_deprecated(
+            RawOrigin::Signed(server.clone()).into(),
+            vec![1, 2, 3, 4],
+            country.as_bytes().to_vec(),
+        )
+        .map_err(|_| "register device failed")?;
+        DeeperNode::<T>::register_server(RawOrigin::Signed(server).into(), 1)
+            .map_err(|_| "register server failed")?;
+    }
+    Ok(())
+}
+
 benchmarks! {
     where_clause { where T: Config, T: pallet_credit_accumulation::Config }
     register_device_deprecated {
@@ -50,7 +100,7 @@ benchmarks! {
     }: _(RawOrigin::Signed(user.clone()), vec![1, 2, 3, 4], "US".as_bytes().to_vec())
     verify {
         let node = DeeperNode::<T>::device_info(user);
-        assert_eq!(node.ipv4, vec![1, 2, 3, 4]);
+        assert!(DeeperNode::<T>::server_endpoint(&user).is_some());
         assert_eq!(node.country, "US".as_bytes().to_vec());
     }
 
@@ -59,24 +109,27 @@ benchmarks! {
         let user = create_funded_user::<T>("user",USER_SEED, 100);
         DeeperNode::<T>::register_device_deprecated(RawOrigin::Signed(user.clone()).into(), vec![1, 2, 3, 4], "US".as_bytes().to_vec())?;
         let node = DeeperNode::<T>::device_info(user.clone());
-        assert_eq!(node.ipv4, vec![1, 2, 3, 4]);
+        assert!(DeeperNode::<T>::server_endpoint(&user).is_some());
         assert_eq!(node.country, "US".as_bytes().to_vec());
     }: _(RawOrigin::Signed(user.clone()))
     verify {
     }
 
+    // the cost of listing a server must not depend on the number of listed servers
     register_server {
+        let s in 0 .. 1000;
         DeeperNode::<T>::setup_region_map();
+        register_servers::<T>("US", s)?;
         let user = create_funded_user::<T>("user",USER_SEED, 100);
         DeeperNode::<T>::register_device_deprecated(RawOrigin::Signed(user.clone()).into(), vec![1, 2, 3, 4], "US".as_bytes().to_vec())?;
         let node = DeeperNode::<T>::device_info(user.clone());
-        assert_eq!(node.ipv4, vec![1, 2, 3, 4]);
+        assert!(DeeperNode::<T>::server_endpoint(&user).is_some());
         assert_eq!(node.country, "US".as_bytes().to_vec());
     }: _(RawOrigin::Signed(user.clone()), 1)
     verify {
         let servers = DeeperNode::<T>::servers_by_country("US".as_bytes().to_vec());
-        let index = servers.iter().position(|x| *x == user);
-        assert_eq!(index, Some(0));
+        assert!(servers.contains(&user));
+        assert_eq!(DeeperNode::<T>::server_count_by_country("US".as_bytes().to_vec()), s + 1);
     }
 
     update_server {
@@ -84,7 +137,7 @@ benchmarks! {
         let user = create_funded_user::<T>("user",USER_SEED, 100);
         DeeperNode::<T>::register_device_deprecated(RawOrigin::Signed(user.clone()).into(), vec![1, 2, 3, 4], "US".as_bytes().to_vec())?;
         let node = DeeperNode::<T>::device_info(user.clone());
-        assert_eq!(node.ipv4, vec![1, 2, 3, 4]);
+        assert!(DeeperNode::<T>::server_endpoint(&user).is_some());
         assert_eq!(node.country, "US".as_bytes().to_vec());
     }: _(RawOrigin::Signed(user.clone()), 1)
     verify {
@@ -95,7 +148,7 @@ benchmarks! {
         let user = create_funded_user::<T>("user",USER_SEED, 100);
         DeeperNode::<T>::register_device_deprecated(RawOrigin::Signed(user.clone()).into(), vec![1, 2, 3, 4], "US".as_bytes().to_vec())?;
         let node = DeeperNode::<T>::device_info(user.clone());
-        assert_eq!(node.ipv4, vec![1, 2, 3, 4]);
+        assert!(DeeperNode::<T>::server_endpoint(&user).is_some());
         assert_eq!(node.country, "US".as_bytes().to_vec());
         DeeperNode::<T>::register_server(RawOrigin::Signed(user.clone()).into(), 1)?;
     }: _(RawOrigin::Signed(user.clone()))
@@ -111,49 +164,110 @@ benchmarks! {
 
     report_credit_proof {
         let admin = <T as pallet_credit_accumulation::Config>::AccountCreator::create_account("Alice");
-        let bob = <T as pallet_credit_accumulation::Config>::AccountCreator::create_account("Bob");
-        assert_ok!(pallet_credit_accumulation::Pallet::<T>::set_atmos_pubkey(
-            RawOrigin::Root.into(),
-            bob,
-        ));
-        let signature: [u8; 64] = hex_literal::hex!("5071a1a526b1d2d1833e4de43d1ce22ad3506de2e10ee4a9c18c0b310c54286b9cb10bfb4ee12be6b93e91337de0fa2ea2edd787d083db36211109bdc8438989");
-
-    }: _(RawOrigin::Signed(admin.clone()),0,
-    signature.into(), 1655007560, 1073741824000000, 4294967295)
+        let proof = (1655007560u32, 1073741824000000u64, 4294967295u32);
+        let signature = atmos_signed::<T>(AtmosPurpose::CreditProof, blake2_256(&proof.encode()), &admin);
+    }: _(RawOrigin::Signed(admin.clone()), 0, 100u32.into(),
+    signature, 1655007560, 1073741824000000, 4294967295)
     verify {
         assert_eq!(DeviceCreditProof::<T>::get(&admin), (1655007560, 1073741824000000, 4294967295));
     }
 
     reward_mapping {
         let admin = <T as pallet_credit_accumulation::Config>::AccountCreator::create_account("Alice");
-        let bob = <T as pallet_credit_accumulation::Config>::AccountCreator::create_account("Bob");
-        assert_ok!(pallet_credit_accumulation::Pallet::<T>::set_atmos_pubkey(
-            RawOrigin::Root.into(),
-            bob,
-        ));
-        let signature: [u8; 64] = hex_literal::hex!("5071a1a526b1d2d1833e4de43d1ce22ad3506de2e10ee4a9c18c0b310c54286b9cb10bfb4ee12be6b93e91337de0fa2ea2edd787d083db36211109bdc8438989");
 
-        let evm_address = H160::from_str("1000000000000000000000000000000000000001").unwrap();
-    }: reward_mapping(RawOrigin::Signed(admin.clone()), 0, signature.into(), evm_address)
+        // rebinding is the worst case, the old address has to be unmapped
+        let evm_old_address = H160::from_str("1000000000000000000000000000000000000001").unwrap();
+        RewardsAccountsEVMtoDeeper::<T>::insert(evm_old_address, &admin);
+        RewardsAccountsDeepertoEVM::<T>::insert(&admin, evm_old_address);
+        let (evm_address, eth_signature) = eth_signed_mapping::<T>(&admin);
+        let signature = atmos_signed::<T>(AtmosPurpose::RewardMapping, blake2_256(&evm_address.encode()), &admin);
+    }: reward_mapping(RawOrigin::Signed(admin.clone()), 0, 100u32.into(), signature, evm_address, eth_signature)
     verify {
         assert_eq!(RewardsAccountsDeepertoEVM::<T>::get(&admin), Some(evm_address));
+        assert_eq!(RewardsAccountsEVMtoDeeper::<T>::get(evm_old_address), None);
     }
 
     get_npow_reward {
         let admin = <T as pallet_credit_accumulation::Config>::AccountCreator::create_account("Alice");
-        let bob = <T as pallet_credit_accumulation::Config>::AccountCreator::create_account("Bob");
         let existential_deposit = <T as pallet::Config>::Currency::minimum_balance();
         let _ = <T as pallet::Config>::Currency::make_free_balance_be(&admin, existential_deposit*2u32.into());
+        let (evm_address, eth_signature) = eth_signed_mapping::<T>(&admin);
+        let signature = atmos_signed::<T>(AtmosPurpose::RewardMapping, blake2_256(&evm_address.encode()), &admin);
+        let _ = DeeperNode::<T>::reward_mapping(RawOrigin::Signed(admin.clone()).into(), 0, 100u32.into(), signature, evm_address, eth_signature);
+    }: _(RawOrigin::Signed(admin))
+    verify {
+    }
+
+    insert_region {
+        DeeperNode::<T>::setup_region_map();
+    }: _(RawOrigin::Root, "QZ".as_bytes().to_vec(), "EUE".as_bytes().to_vec())
+    verify {
+        assert_eq!(DeeperNode::<T>::region_code("QZ".as_bytes().to_vec()), "EUE".as_bytes().to_vec());
+    }
+
+    reparent_region {
+        let s in 0 .. 1000;
+        DeeperNode::<T>::setup_region_map();
+        register_servers::<T>("US", s)?;
+    }: _(RawOrigin::Root, "US".as_bytes().to_vec(), "EUN".as_bytes().to_vec(), s)
+    verify {
+        assert_eq!(DeeperNode::<T>::server_count_by_region("EUN".as_bytes().to_vec()), s);
+        assert_eq!(DeeperNode::<T>::server_count_by_region("EURO".as_bytes().to_vec()), s);
+    }
+
+    retire_region {
+        DeeperNode::<T>::setup_region_map();
+    }: _(RawOrigin::Root, "US".as_bytes().to_vec())
+    verify {
+        assert!(!RegionMap::<T>::contains_key("US".as_bytes().to_vec()));
+    }
+
+    expire_server {
+        DeeperNode::<T>::setup_region_map();
+        let user = create_funded_user::<T>("user",USER_SEED, 100);
+        DeeperNode::<T>::register_device_deprecated(RawOrigin::Signed(user.clone()).into(), vec![1, 2, 3, 4], "US".as_bytes().to_vec())?;
+        DeeperNode::<T>::register_server(RawOrigin::Signed(user.clone()).into(), 1)?;
+    }: {
+        DeeperNode::<T>::expire_server(&user);
+    }
+    verify {
+        assert!(DeeperNode::<T>::servers_by_country("US".as_bytes().to_vec()).is_empty());
+    }
 
-        assert_ok!(pallet_credit_accumulation::Pallet::<T>::set_atmos_pubkey(
-            RawOrigin::Root.into(),
-            bob,
-        ));
-        let signature: [u8; 64] = hex_literal::hex!("5071a1a526b1d2d1833e4de43d1ce22ad3506de2e10ee4a9c18c0b310c54286b9cb10bfb4ee12be6b93e91337de0fa2ea2edd787d083db36211109bdc8438989");
+    heartbeat {
+        let user = create_funded_user::<T>("user",USER_SEED, 100);
+        let block = frame_system::Pallet::<T>::block_number();
+        // the signature is checked in validate_unsigned, not in the call
+        let signature = T::DeviceSignature::decode(&mut TrailingZeroInput::zeroes())
+            .map_err(|_| "invalid signature encoding")?;
+    }: _(RawOrigin::None, user.clone(), block, signature)
+    verify {
+        assert_eq!(DeeperNode::<T>::get_im_online(user), Some(block));
+    }
+
+    set_server_endpoint {
+        DeeperNode::<T>::setup_region_map();
+        let user = create_funded_user::<T>("user",USER_SEED, 100);
+        DeeperNode::<T>::register_device_deprecated(RawOrigin::Signed(user.clone()).into(), vec![1, 2, 3, 4], "US".as_bytes().to_vec())?;
+        let endpoint = EndpointRecord::Plain(Endpoint {
+            addresses: vec![IpAddress::V4([1, 2, 3, 4]), IpAddress::V6([1; 16])],
+            ports: vec![443, 1194, 51820],
+            protocols: vec![TunnelProtocol::Trident, TunnelProtocol::WireGuard, TunnelProtocol::OpenVpn],
+        });
+    }: _(RawOrigin::Signed(user.clone()), endpoint.clone())
+    verify {
+        assert_eq!(DeeperNode::<T>::server_endpoint(&user), Some(endpoint));
+    }
+
+    unbind_reward_mapping {
+        let user = create_funded_user::<T>("user",USER_SEED, 100);
         let evm_address = H160::from_str("1000000000000000000000000000000000000001").unwrap();
-        let _ = DeeperNode::<T>::reward_mapping(RawOrigin::Signed(admin.clone()).into(), 0, signature.into(), evm_address);
-    }: _(RawOrigin::Signed(admin))
+        RewardsAccountsEVMtoDeeper::<T>::insert(evm_address, &user);
+        RewardsAccountsDeepertoEVM::<T>::insert(&user, evm_address);
+    }: _(RawOrigin::Signed(user.clone()))
     verify {
+        assert_eq!(RewardsAccountsDeepertoEVM::<T>::get(&user), None);
+        assert_eq!(RewardsAccountsEVMtoDeeper::<T>::get(evm_address), None);
     }
 }
 
@@ -172,6 +286,13 @@ mod tests {
             assert_ok!(Pallet::<Test>::test_benchmark_update_server());
             assert_ok!(Pallet::<Test>::test_benchmark_unregister_server());
             assert_ok!(Pallet::<Test>::test_benchmark_im_online());
+            assert_ok!(Pallet::<Test>::test_benchmark_insert_region());
+            assert_ok!(Pallet::<Test>::test_benchmark_reparent_region());
+            assert_ok!(Pallet::<Test>::test_benchmark_retire_region());
+            assert_ok!(Pallet::<Test>::test_benchmark_expire_server());
+            assert_ok!(Pallet::<Test>::test_benchmark_heartbeat());
+            assert_ok!(Pallet::<Test>::test_benchmark_unbind_reward_mapping());
+            assert_ok!(Pallet::<Test>::test_benchmark_set_server_endpoint());
         });
     }
 }
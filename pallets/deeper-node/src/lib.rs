@@ -15,9 +15,15 @@
 
 #![cfg_attr(not(feature = "std"), no_std)]
 
-use codec::{Decode, Encode};
+use codec::{Decode, Encode, MaxEncodedLen};
+use frame_support::Parameter;
 use scale_info::TypeInfo;
 use sp_core::H160;
+use sp_io::{
+    crypto::secp256k1_ecdsa_recover,
+    hashing::{blake2_256, keccak_256},
+};
+use sp_runtime::traits::{IdentifyAccount, Verify};
 
 pub use pallet::*;
 
@@ -43,8 +49,57 @@ pub(crate) type IpV4 = Vec<u8>;
 #[derive(Decode, Encode, TypeInfo)]
 pub struct Node<AccountId, BlockNumber> {
     pub account_id: AccountId,
-    country: CountryRegion,
-    expire: BlockNumber,
+    pub country: CountryRegion,
+    pub expire: BlockNumber,
+}
+
+/// Signature of a device over a message, checked against the device account
+pub trait VerifyDevice<AccountId>: Parameter {
+    fn verify_device(&self, msg: &[u8], account: &AccountId) -> bool;
+}
+
+impl<S, AccountId> VerifyDevice<AccountId> for S
+where
+    S: Verify + Parameter,
+    <S::Signer as IdentifyAccount>::AccountId: Decode,
+    AccountId: Encode,
+{
+    fn verify_device(&self, msg: &[u8], account: &AccountId) -> bool {
+        // the device account is the public key of the device
+        match Decode::decode(&mut &account.encode()[..]) {
+            Ok(signer) => self.verify(msg, &signer),
+            Err(_) => false,
+        }
+    }
+}
+
+/// Context prepended to the heartbeat payload signed by a device
+pub const HEARTBEAT_CONTEXT: &[u8] = b"deeper-node:heartbeat";
+
+/// Number of silent eras after which an unsigned heartbeat gets the full priority
+pub const HEARTBEAT_PRIORITY_ERAS: u64 = 7;
+
+/// Context prepended to the reward mapping message signed by the eth address
+pub const REWARD_MAPPING_CONTEXT: &[u8] = b"deeper-node:reward-mapping";
+
+/// Seconds covered by a daily credit proof
+const SECS_PER_DAY: u64 = 86_400;
+
+/// Devices onboarded, i.e. online for the first time, and devices online in an era
+#[derive(Decode, Encode, Default, Clone, PartialEq, Eq, Debug, TypeInfo, MaxEncodedLen)]
+pub struct DeviceStats {
+    pub onboarded: u32,
+    pub active: u32,
+}
+
+/// Sums of the client ratings of a server, decayed up to `era`. Every rating adds its weight
+/// times `Perbill::one()` parts to the weight and its weight times its normalized score to
+/// the score.
+#[derive(Decode, Encode, Default, Clone, PartialEq, Eq, Debug, TypeInfo, MaxEncodedLen)]
+pub struct Ratings<BlockNumber> {
+    pub score: u64,
+    pub weight: u64,
+    pub era: BlockNumber,
 }
 
 impl<AccountId: Decode, BlockNumber: Default> Default for Node<AccountId, BlockNumber> {
@@ -69,7 +124,17 @@ pub mod pallet {
     };
 
     use frame_system::{self, ensure_signed, pallet_prelude::*};
-    use node_primitives::{deeper_node::NodeInterface, VerifySignatureInterface};
+    use node_primitives::{
+        deeper_node::{
+            DeviceStatus, Endpoint, EndpointRecord, IpAddress, NodeInterface, MAX_RATING,
+        },
+        sweep::sweep_expirations,
+        AtmosPurpose, VerifySignatureInterface,
+    };
+    use sp_runtime::{
+        traits::{SaturatedConversion, Saturating},
+        Perbill,
+    };
     use sp_std::convert::TryInto;
 
     /// Configure the pallet by specifying the parameters and types on which it depends.
@@ -85,14 +150,30 @@ pub mod pallet {
         type MaxIpLength: Get<usize>;
         /// Weight information for extrinsics in this pallet.
         type WeightInfo: WeightInfo;
-        type VerifySignatureInterface: VerifySignatureInterface<Self::AccountId>;
+        type VerifySignatureInterface: VerifySignatureInterface<
+            Self::AccountId,
+            BlockNumberFor<Self>,
+        >;
+        /// Origin allowed to update the region map.
+        type RegionOrigin: EnsureOrigin<Self::RuntimeOrigin>;
+        /// Signature of unsigned heartbeats, verified against the device account.
+        type DeviceSignature: VerifyDevice<Self::AccountId>;
+        /// Base priority of unsigned heartbeats.
+        #[pallet::constant]
+        type UnsignedPriority: Get<TransactionPriority>;
+        /// Number of credit proofs kept per device to compute its reliability.
+        #[pallet::constant]
+        type MaxCreditProofs: Get<u32>;
+        /// Share of the weight of past client ratings kept at every new era.
+        #[pallet::constant]
+        type ReputationDecay: Get<Perbill>;
     }
 
     type BalanceOf<T> =
         <<T as Config>::Currency as Currency<<T as frame_system::Config>::AccountId>>::Balance;
 
     /// The current storage version.
-    const STORAGE_VERSION: StorageVersion = StorageVersion::new(1);
+    const STORAGE_VERSION: StorageVersion = StorageVersion::new(5);
 
     #[pallet::pallet]
     #[pallet::storage_version(STORAGE_VERSION)]
@@ -108,6 +189,12 @@ pub mod pallet {
     pub(super) type RegionMap<T: Config> =
         StorageMap<_, Blake2_128Concat, CountryRegion, CountryRegion, ValueQuery>;
 
+    // number of region codes mapped to a region
+    #[pallet::storage]
+    #[pallet::getter(fn region_children)]
+    pub(super) type RegionChildren<T: Config> =
+        StorageMap<_, Blake2_128Concat, CountryRegion, u32, ValueQuery>;
+
     #[pallet::storage]
     pub(super) type DeviceInfo<T: Config> = StorageMap<
         _,
@@ -117,20 +204,69 @@ pub mod pallet {
         ValueQuery,
     >;
 
+    // servers listed in a country
     #[pallet::storage]
-    #[pallet::getter(fn servers_by_country)]
-    pub(super) type ServersByCountry<T: Config> =
-        StorageMap<_, Blake2_128Concat, CountryRegion, Vec<T::AccountId>, ValueQuery>;
+    pub(super) type ServersByCountry<T: Config> = StorageDoubleMap<
+        _,
+        Blake2_128Concat,
+        CountryRegion,
+        Blake2_128Concat,
+        T::AccountId,
+        (),
+        OptionQuery,
+    >;
+
+    // number of devices registered with a country code
+    #[pallet::storage]
+    #[pallet::getter(fn device_count_by_country)]
+    pub(super) type DeviceCountByCountry<T: Config> =
+        StorageMap<_, Blake2_128Concat, CountryRegion, u32, ValueQuery>;
+
+    // number of servers listed in a country
+    #[pallet::storage]
+    #[pallet::getter(fn server_count_by_country)]
+    pub(super) type ServerCountByCountry<T: Config> =
+        StorageMap<_, Blake2_128Concat, CountryRegion, u32, ValueQuery>;
 
     #[pallet::storage]
     #[pallet::getter(fn device_credit_proof)]
     pub type DeviceCreditProof<T: Config> =
         StorageMap<_, Blake2_128Concat, T::AccountId, (u32, u64, u32), ValueQuery>;
 
+    /// The last `MaxCreditProofs` credit proofs of a device, oldest first
+    #[pallet::storage]
+    #[pallet::getter(fn credit_proof_history)]
+    pub type CreditProofHistory<T: Config> = StorageMap<
+        _,
+        Blake2_128Concat,
+        T::AccountId,
+        BoundedVec<(u32, u64, u32), T::MaxCreditProofs>,
+        ValueQuery,
+    >;
+
+    /// Time decayed client ratings of a server
+    #[pallet::storage]
+    #[pallet::getter(fn server_ratings)]
+    pub type ServerRatings<T: Config> =
+        StorageMap<_, Blake2_128Concat, T::AccountId, Ratings<BlockNumberFor<T>>, ValueQuery>;
+
+    // servers listed in a level 2 or level 3 region
     #[pallet::storage]
-    #[pallet::getter(fn servers_by_region)]
-    pub(super) type ServersByRegion<T: Config> =
-        StorageMap<_, Blake2_128Concat, CountryRegion, Vec<T::AccountId>, ValueQuery>;
+    pub(super) type ServersByRegion<T: Config> = StorageDoubleMap<
+        _,
+        Blake2_128Concat,
+        CountryRegion,
+        Blake2_128Concat,
+        T::AccountId,
+        (),
+        OptionQuery,
+    >;
+
+    // number of servers listed in a level 2 or level 3 region
+    #[pallet::storage]
+    #[pallet::getter(fn server_count_by_region)]
+    pub(super) type ServerCountByRegion<T: Config> =
+        StorageMap<_, Blake2_128Concat, CountryRegion, u32, ValueQuery>;
 
     #[pallet::storage]
     #[pallet::getter(fn get_im_online)]
@@ -142,9 +278,29 @@ pub mod pallet {
     pub type OnboardTime<T: Config> =
         StorageMap<_, Blake2_128Concat, T::AccountId, BlockNumberFor<T>, OptionQuery>;
 
+    /// Devices which have ever been online
+    #[pallet::storage]
+    pub type DevicesOnboard<T: Config> =
+        CountedStorageMap<_, Blake2_128Concat, T::AccountId, (), OptionQuery>;
+
+    /// Devices onboarded and active in an era
     #[pallet::storage]
-    #[pallet::getter(fn devices_onboard)]
-    pub(super) type DevicesOnboard<T: Config> = StorageValue<_, Vec<T::AccountId>, ValueQuery>;
+    #[pallet::getter(fn era_device_stats)]
+    pub type EraDeviceStats<T: Config> =
+        StorageMap<_, Twox64Concat, BlockNumberFor<T>, DeviceStats, ValueQuery>;
+
+    /// Devices onboarded and active in an era, by the country they are registered in
+    #[pallet::storage]
+    #[pallet::getter(fn country_era_device_stats)]
+    pub type CountryEraDeviceStats<T: Config> = StorageDoubleMap<
+        _,
+        Twox64Concat,
+        BlockNumberFor<T>,
+        Blake2_128Concat,
+        CountryRegion,
+        DeviceStats,
+        ValueQuery,
+    >;
 
     /// Deeper Accounts Rewarded by NPoW(Evm_Address => Deeper_Address)
     #[pallet::storage]
@@ -158,6 +314,37 @@ pub mod pallet {
     pub type RewardsAccountsDeepertoEVM<T: Config> =
         StorageMap<_, Blake2_128Concat, T::AccountId, H160, OptionQuery>;
 
+    /// Nonce the eth address signs to bind a reward address, bumped on every binding
+    #[pallet::storage]
+    #[pallet::getter(fn reward_mapping_nonce)]
+    pub type RewardMappingNonce<T: Config> =
+        StorageMap<_, Blake2_128Concat, T::AccountId, u64, ValueQuery>;
+
+    /// How clients reach a server, published by the server itself
+    #[pallet::storage]
+    #[pallet::getter(fn server_endpoint)]
+    pub type ServerEndpoints<T: Config> =
+        StorageMap<_, Blake2_128Concat, T::AccountId, EndpointRecord, OptionQuery>;
+
+    // index of listed servers by the block their registration expires
+    #[pallet::storage]
+    #[pallet::getter(fn server_expiration)]
+    pub(super) type ServerExpirations<T: Config> = StorageDoubleMap<
+        _,
+        Twox64Concat,
+        BlockNumberFor<T>,
+        Blake2_128Concat,
+        T::AccountId,
+        (),
+        OptionQuery,
+    >;
+
+    // the earliest expiration block which has not been swept yet
+    #[pallet::storage]
+    #[pallet::getter(fn next_server_expiry_to_sweep)]
+    pub(super) type NextServerExpiryToSweep<T: Config> =
+        StorageValue<_, BlockNumberFor<T>, ValueQuery>;
+
     #[pallet::genesis_config]
     #[derive(frame_support::DefaultNoBound)]
     pub struct GenesisConfig<T: Config> {
@@ -190,12 +377,14 @@ pub mod pallet {
         RegisterNode(T::AccountId, CountryRegion),
         UnregisterNode(T::AccountId),
 
-        // add account into a country's server list
+        // add account into a country's server list: account, country, duration, list position.
+        // No longer emitted since storage version 3, the lists have no positions anymore.
         ServerCountryAdded(T::AccountId, CountryRegion, BlockNumberFor<T>, u64),
         // remove account from a country's server list
         ServerCountryRemoved(T::AccountId, CountryRegion),
 
-        // add account into a region's server list
+        // add account into a region's server list: account, region, duration, list position.
+        // No longer emitted since storage version 3, the lists have no positions anymore.
         ServerRegionAdded(T::AccountId, CountryRegion, BlockNumberFor<T>, u64),
         // remove account from a region's server list
         ServerRegionRemoved(T::AccountId, CountryRegion),
@@ -207,8 +396,28 @@ pub mod pallet {
         /// Bind worker eth_address to reward address
         RewardsAccounts(T::AccountId, H160),
 
+        /// Unbind worker eth_address from reward address
+        RewardsAccountsUnbound(T::AccountId, H160),
+
         /// send this event to let system mint dpr to user
         GetNpowReward(T::AccountId, H160),
+
+        /// region code and its new parent, the parent is None when the code is retired
+        RegionMapUpdated(CountryRegion, Option<CountryRegion>),
+
+        /// server registration expired and the server was removed from the server lists
+        ServerExpired(T::AccountId),
+
+        /// server published a new endpoint
+        ServerEndpointSet(T::AccountId),
+
+        /// account added to a country's server list: account, country, duration,
+        /// number of servers listed in the country
+        ServerCountryListed(T::AccountId, CountryRegion, BlockNumberFor<T>, u32),
+
+        /// account added to a region's server list: account, region, duration,
+        /// number of servers listed in the region
+        ServerRegionListed(T::AccountId, CountryRegion, BlockNumberFor<T>, u32),
     }
 
     // Errors inform users that something went wrong.
@@ -232,16 +441,42 @@ pub mod pallet {
         InvalidRegionMap,
         /// signature verify failed
         SignatureVerifyFailed,
+        /// eth signature is not from the eth address
+        EthSignatureVerifyFailed,
         /// ETH addresses are already bound
         EthAddressAlreadyMapped,
         /// No binding information
         NotBound,
         /// no coresponding evm address
         NpowRewardAddressNotFound,
+        /// region code is already in the region map
+        RegionAlreadyExists,
+        /// region still has country codes under it
+        RegionInUse,
+        /// country code is still used by registered devices
+        CountryInUse,
+        /// parent is not a level 1 or level 2 region of the region map
+        UnknownParent,
+        /// server count witness is lower than the number of listed servers
+        ServerWitnessTooLow,
+        /// encoded endpoint is longer than MaxIpLength
+        EndpointTooLong,
+        /// endpoint has no address, a zero port or an empty ciphertext
+        InvalidEndpoint,
+        /// atomos nonce was already used
+        InvalidAtomosNonce,
+        /// credit proof is not newer than the last proof of the device
+        StaleCreditProof,
+        /// the device already reported a credit proof for this day
+        CreditProofAlreadyReported,
     }
 
     #[pallet::hooks]
-    impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {}
+    impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
+        fn on_idle(now: BlockNumberFor<T>, remaining_weight: Weight) -> Weight {
+            Self::sweep_expired_servers(now, remaining_weight)
+        }
+    }
 
     // Dispatchable functions allows users to interact with the pallet and invoke state changes.
     // These functions materialize as "extrinsics", which are often compared to transactions.
@@ -252,10 +487,11 @@ pub mod pallet {
         #[pallet::weight(T::WeightInfo::register_device_deprecated())]
         pub fn register_device_deprecated(
             origin: OriginFor<T>,
-            _ip: IpV4,
+            ip: IpV4,
             country: CountryRegion,
         ) -> DispatchResultWithPostInfo {
             let sender = ensure_signed(origin)?;
+            ensure!(ip.len() <= T::MaxIpLength::get(), Error::<T>::InvalidIP);
             ensure!(<RegionMapInit<T>>::get(), Error::<T>::InvalidRegionMap);
             ensure!(
                 <RegionMap<T>>::contains_key(&country),
@@ -270,15 +506,29 @@ pub mod pallet {
                 };
                 T::Currency::reserve(&sender, BalanceOf::<T>::from(T::MinLockAmt::get()))?;
                 <DeviceInfo<T>>::insert(&sender, node);
+                <DeviceCountByCountry<T>>::mutate(&country, |count| *count += 1);
             } else {
                 <DeviceInfo<T>>::mutate(&sender, |node| {
                     if node.country != country {
                         let _ = Self::try_remove_server(&sender);
+                        Self::country_device_remove(&node.country);
+                        <DeviceCountByCountry<T>>::mutate(&country, |count| *count += 1);
                         node.country = country.clone();
                     }
                     node.expire = <frame_system::Pallet<T>>::block_number();
                 });
             }
+            // the legacy ip is published until the device sets a full endpoint
+            if !<ServerEndpoints<T>>::contains_key(&sender) {
+                if let Some(address) = Self::legacy_address(&ip) {
+                    let endpoint = Endpoint {
+                        addresses: sp_std::vec![address],
+                        ports: Vec::new(),
+                        protocols: Vec::new(),
+                    };
+                    <ServerEndpoints<T>>::insert(&sender, EndpointRecord::Plain(endpoint));
+                }
+            }
             Self::deposit_event(Event::RegisterNode(sender, country));
             Ok(().into())
         }
@@ -292,7 +542,9 @@ pub mod pallet {
                 Error::<T>::DeviceNotRegister
             );
             let _ = Self::try_remove_server(&sender);
-            <DeviceInfo<T>>::remove(&sender);
+            let node = <DeviceInfo<T>>::take(&sender);
+            Self::country_device_remove(&node.country);
+            <ServerEndpoints<T>>::remove(&sender);
             T::Currency::unreserve(&sender, BalanceOf::<T>::from(T::MinLockAmt::get()));
             Self::deposit_event(Event::UnregisterNode(sender));
             Ok(().into())
@@ -335,7 +587,12 @@ pub mod pallet {
             );
             let blocks = BlockNumberFor::<T>::from(duration_eras) * T::BlocksPerEra::get();
             <DeviceInfo<T>>::mutate(&sender, |node| {
-                node.expire = <frame_system::Pallet<T>>::block_number() + blocks;
+                let expire = <frame_system::Pallet<T>>::block_number() + blocks;
+                // only listed servers are in the expiry queue
+                if <ServerExpirations<T>>::take(node.expire, &sender).is_some() {
+                    <ServerExpirations<T>>::insert(expire, &sender, ());
+                }
+                node.expire = expire;
             });
             Ok(().into())
         }
@@ -356,13 +613,7 @@ pub mod pallet {
         #[pallet::weight(T::WeightInfo::im_online())]
         pub fn im_online(origin: OriginFor<T>) -> DispatchResultWithPostInfo {
             let sender = ensure_signed(origin)?;
-            let current_block = <frame_system::Pallet<T>>::block_number();
-            ImOnline::<T>::insert(&sender, current_block.clone());
-            if !OnboardTime::<T>::contains_key(&sender) {
-                OnboardTime::<T>::insert(&sender, current_block.clone());
-                DevicesOnboard::<T>::mutate(|devices| devices.push(sender.clone()));
-            }
-            Self::deposit_event(Event::ImOnline(sender, current_block));
+            Self::record_online(sender);
             Ok(().into())
         }
 
@@ -371,25 +622,46 @@ pub mod pallet {
         pub fn report_credit_proof(
             origin: OriginFor<T>,
             nonce: u64,
+            valid_until: BlockNumberFor<T>,
             signature: Vec<u8>,
             timestamp: u32,
             daily_sharing_traffic: u64,
             daily_uptime: u32,
         ) -> DispatchResultWithPostInfo {
             let device_account = ensure_signed(origin)?;
+            let proof = (timestamp, daily_sharing_traffic, daily_uptime);
+            // one proof per day, in order
+            if let Ok(last) = <DeviceCreditProof<T>>::try_get(&device_account) {
+                ensure!(timestamp > last.0, Error::<T>::StaleCreditProof);
+                ensure!(
+                    u64::from(timestamp) / SECS_PER_DAY > u64::from(last.0) / SECS_PER_DAY,
+                    Error::<T>::CreditProofAlreadyReported
+                );
+            }
             ensure!(
                 T::VerifySignatureInterface::verify_atomos_signature(
+                    AtmosPurpose::CreditProof,
                     nonce,
+                    valid_until,
+                    blake2_256(&proof.encode()),
                     signature,
                     device_account.clone()
                 ),
                 Error::<T>::SignatureVerifyFailed
             );
-
-            <DeviceCreditProof<T>>::insert(
-                &device_account,
-                (timestamp, daily_sharing_traffic, daily_uptime),
+            ensure!(
+                T::VerifySignatureInterface::consume_atomos_nonce(&device_account, nonce),
+                Error::<T>::InvalidAtomosNonce
             );
+
+            <DeviceCreditProof<T>>::insert(&device_account, proof);
+            <CreditProofHistory<T>>::mutate(&device_account, |history| {
+                // drop the oldest proof when the history is full
+                if history.try_push(proof).is_err() && !history.is_empty() {
+                    history.remove(0);
+                    let _ = history.try_push(proof);
+                }
+            });
             Self::deposit_event(Event::DeviceCreditProof(
                 device_account,
                 timestamp,
@@ -406,39 +678,46 @@ pub mod pallet {
         pub fn reward_mapping(
             origin: OriginFor<T>,
             nonce: u64,
+            valid_until: BlockNumberFor<T>,
             signature: Vec<u8>,
             eth_address: H160,
+            eth_signature: [u8; 65],
         ) -> DispatchResultWithPostInfo {
             let deeper_address = ensure_signed(origin)?;
 
             ensure!(
-                T::VerifySignatureInterface::verify_atomos_new_signature(
+                T::VerifySignatureInterface::verify_atomos_signature(
+                    AtmosPurpose::RewardMapping,
                     nonce,
+                    valid_until,
+                    blake2_256(&eth_address.encode()),
                     signature,
                     deeper_address.clone()
                 ),
                 Error::<T>::SignatureVerifyFailed
             );
 
+            // the eth address proves ownership by signing the deeper account and mapping nonce
+            let mapping_nonce = Self::reward_mapping_nonce(&deeper_address);
+            let hash = Self::reward_mapping_hash(&deeper_address, mapping_nonce);
+            ensure!(
+                Self::eth_recover(&eth_signature, &hash) == Some(eth_address),
+                Error::<T>::EthSignatureVerifyFailed
+            );
+
             ensure!(
                 !RewardsAccountsEVMtoDeeper::<T>::contains_key(&eth_address),
                 Error::<T>::EthAddressAlreadyMapped
             );
 
-            if RewardsAccountsDeepertoEVM::<T>::contains_key(&deeper_address) {
-                let evm_old_address = Self::rewards_accounts_deeper_evm(&deeper_address)
-                    .ok_or(Error::<T>::NotBound)?;
-                if eth_address != evm_old_address {
-                    RewardsAccountsEVMtoDeeper::<T>::insert(eth_address, &deeper_address);
-                    RewardsAccountsDeepertoEVM::<T>::insert(&deeper_address, eth_address);
-                    Self::deposit_event(Event::RewardsAccounts(deeper_address, eth_address));
-                }
-            } else {
-                RewardsAccountsEVMtoDeeper::<T>::insert(eth_address, &deeper_address);
-                RewardsAccountsDeepertoEVM::<T>::insert(&deeper_address, eth_address);
-
-                Self::deposit_event(Event::RewardsAccounts(deeper_address, eth_address));
+            if let Some(evm_old_address) = RewardsAccountsDeepertoEVM::<T>::get(&deeper_address) {
+                RewardsAccountsEVMtoDeeper::<T>::remove(evm_old_address);
             }
+            RewardsAccountsEVMtoDeeper::<T>::insert(eth_address, &deeper_address);
+            RewardsAccountsDeepertoEVM::<T>::insert(&deeper_address, eth_address);
+            RewardMappingNonce::<T>::insert(&deeper_address, mapping_nonce.saturating_add(1));
+
+            Self::deposit_event(Event::RewardsAccounts(deeper_address, eth_address));
             Ok(().into())
         }
 
@@ -454,9 +733,287 @@ pub mod pallet {
                 None => Err(Error::<T>::NpowRewardAddressNotFound)?,
             }
         }
+
+        /// Add a country code under a level 2 region,
+        /// or a level 2 region under a level 1 region.
+        #[pallet::call_index(9)]
+        #[pallet::weight(T::WeightInfo::insert_region())]
+        pub fn insert_region(
+            origin: OriginFor<T>,
+            code: CountryRegion,
+            parent: CountryRegion,
+        ) -> DispatchResultWithPostInfo {
+            T::RegionOrigin::ensure_origin(origin)?;
+            ensure!(
+                !code.is_empty() && !parent.is_empty() && code != parent,
+                Error::<T>::InvalidCode
+            );
+            ensure!(
+                !<RegionMap<T>>::contains_key(&code),
+                Error::<T>::RegionAlreadyExists
+            );
+            // a level 1 region can not become a child, a country can not become a parent
+            ensure!(
+                !Self::has_children(&code) && !Self::is_country(&parent),
+                Error::<T>::InvalidCode
+            );
+            // the parent is a level 2 region or a level 1 region with level 2 regions under it
+            ensure!(
+                <RegionMap<T>>::contains_key(&parent) || Self::has_children(&parent),
+                Error::<T>::UnknownParent
+            );
+            <RegionMap<T>>::insert(&code, &parent);
+            <RegionChildren<T>>::mutate(&parent, |count| *count += 1);
+            Self::deposit_event(Event::RegionMapUpdated(code, Some(parent)));
+            Ok(().into())
+        }
+
+        /// Move a country code to another level 2 region, or a level 2 region to another
+        /// level 1 region. Registered servers are moved to the server lists of the new parents.
+        /// `server_count` is a witness of the number of servers listed under `code`.
+        #[pallet::call_index(10)]
+        #[pallet::weight(T::WeightInfo::reparent_region(*server_count))]
+        pub fn reparent_region(
+            origin: OriginFor<T>,
+            code: CountryRegion,
+            new_parent: CountryRegion,
+            server_count: u32,
+        ) -> DispatchResultWithPostInfo {
+            T::RegionOrigin::ensure_origin(origin)?;
+            ensure!(<RegionMap<T>>::contains_key(&code), Error::<T>::InvalidCode);
+            let old_parent = <RegionMap<T>>::get(&code);
+            if Self::is_country(&code) {
+                ensure!(
+                    <RegionMap<T>>::contains_key(&new_parent) && !Self::is_country(&new_parent),
+                    Error::<T>::InvalidCode
+                );
+                ensure!(
+                    <ServerCountByCountry<T>>::get(&code) <= server_count,
+                    Error::<T>::ServerWitnessTooLow
+                );
+                let servers: Vec<T::AccountId> =
+                    <ServersByCountry<T>>::iter_key_prefix(&code).collect();
+                let old_top_region = <RegionMap<T>>::get(&old_parent);
+                let new_top_region = <RegionMap<T>>::get(&new_parent);
+                Self::move_servers(&servers, &old_parent, &new_parent);
+                if old_top_region != new_top_region {
+                    Self::move_servers(&servers, &old_top_region, &new_top_region);
+                }
+            } else {
+                ensure!(
+                    !new_parent.is_empty() && !<RegionMap<T>>::contains_key(&new_parent),
+                    Error::<T>::InvalidCode
+                );
+                ensure!(Self::has_children(&new_parent), Error::<T>::UnknownParent);
+                ensure!(
+                    <ServerCountByRegion<T>>::get(&code) <= server_count,
+                    Error::<T>::ServerWitnessTooLow
+                );
+                let servers: Vec<T::AccountId> =
+                    <ServersByRegion<T>>::iter_key_prefix(&code).collect();
+                Self::move_servers(&servers, &old_parent, &new_parent);
+            }
+            <RegionMap<T>>::insert(&code, &new_parent);
+            Self::region_child_remove(&old_parent);
+            <RegionChildren<T>>::mutate(&new_parent, |count| *count += 1);
+            Self::deposit_event(Event::RegionMapUpdated(code, Some(new_parent)));
+            Ok(().into())
+        }
+
+        /// Remove a country code or an empty level 2 region from the region map.
+        /// A code still used by registered devices can not be retired, the devices have to
+        /// move to another country first.
+        #[pallet::call_index(11)]
+        #[pallet::weight(T::WeightInfo::retire_region())]
+        pub fn retire_region(
+            origin: OriginFor<T>,
+            code: CountryRegion,
+        ) -> DispatchResultWithPostInfo {
+            T::RegionOrigin::ensure_origin(origin)?;
+            ensure!(<RegionMap<T>>::contains_key(&code), Error::<T>::InvalidCode);
+            ensure!(!Self::has_children(&code), Error::<T>::RegionInUse);
+            ensure!(
+                <DeviceCountByCountry<T>>::get(&code) == 0,
+                Error::<T>::CountryInUse
+            );
+            let parent = <RegionMap<T>>::take(&code);
+            Self::region_child_remove(&parent);
+            Self::deposit_event(Event::RegionMapUpdated(code, None));
+            Ok(().into())
+        }
+
+        /// Feeless `im_online` for devices: an unsigned heartbeat signed by the device account,
+        /// accepted once per era. The checks are done in `validate_unsigned`.
+        #[pallet::call_index(12)]
+        #[pallet::weight(T::WeightInfo::heartbeat())]
+        pub fn heartbeat(
+            origin: OriginFor<T>,
+            account: T::AccountId,
+            _block: BlockNumberFor<T>,
+            _signature: T::DeviceSignature,
+        ) -> DispatchResultWithPostInfo {
+            ensure_none(origin)?;
+            Self::record_online(account);
+            Ok(().into())
+        }
+
+        #[pallet::call_index(13)]
+        #[pallet::weight(T::WeightInfo::unbind_reward_mapping())]
+        pub fn unbind_reward_mapping(origin: OriginFor<T>) -> DispatchResultWithPostInfo {
+            let deeper_address = ensure_signed(origin)?;
+            let eth_address = RewardsAccountsDeepertoEVM::<T>::take(&deeper_address)
+                .ok_or(Error::<T>::NotBound)?;
+            RewardsAccountsEVMtoDeeper::<T>::remove(eth_address);
+            Self::deposit_event(Event::RewardsAccountsUnbound(deeper_address, eth_address));
+            Ok(().into())
+        }
+
+        /// Publish how clients reach the server: addresses, ports and tunnel protocols,
+        /// in the clear or encrypted to a published key
+        #[pallet::call_index(14)]
+        #[pallet::weight(T::WeightInfo::set_server_endpoint())]
+        pub fn set_server_endpoint(
+            origin: OriginFor<T>,
+            endpoint: EndpointRecord,
+        ) -> DispatchResultWithPostInfo {
+            let sender = ensure_signed(origin)?;
+            ensure!(
+                <DeviceInfo<T>>::contains_key(&sender),
+                Error::<T>::DeviceNotRegister
+            );
+            Self::validate_endpoint(&endpoint)?;
+            <ServerEndpoints<T>>::insert(&sender, endpoint);
+            Self::deposit_event(Event::ServerEndpointSet(sender));
+            Ok(().into())
+        }
+    }
+
+    #[pallet::validate_unsigned]
+    impl<T: Config> ValidateUnsigned for Pallet<T> {
+        type Call = Call<T>;
+
+        fn validate_unsigned(_source: TransactionSource, call: &Self::Call) -> TransactionValidity {
+            if let Call::heartbeat {
+                account,
+                block,
+                signature,
+            } = call
+            {
+                let now = <frame_system::Pallet<T>>::block_number();
+                if *block > now {
+                    return InvalidTransaction::Future.into();
+                }
+                let era = Self::era_of(now);
+                if Self::era_of(*block) < era {
+                    return InvalidTransaction::Stale.into();
+                }
+                // only registered devices, which have locked MinLockAmt, can send heartbeats
+                if !<DeviceInfo<T>>::contains_key(account) {
+                    return InvalidTransaction::BadSigner.into();
+                }
+                // one heartbeat per era, signed or unsigned
+                let last_era = <ImOnline<T>>::get(account).map(Self::era_of);
+                if last_era == Some(era) {
+                    return InvalidTransaction::Stale.into();
+                }
+                if !signature.verify_device(&Self::heartbeat_payload(account, *block), account) {
+                    return InvalidTransaction::BadProof.into();
+                }
+
+                // devices which were online recently yield to devices which were silent longer
+                let eras_silent = last_era
+                    .map(|last| era.saturating_sub(last).saturated_into::<u64>())
+                    .unwrap_or(HEARTBEAT_PRIORITY_ERAS)
+                    .min(HEARTBEAT_PRIORITY_ERAS);
+                let priority = T::UnsignedPriority::get() / HEARTBEAT_PRIORITY_ERAS * eras_silent;
+                let era_end = era.saturating_add(1u32.into()) * T::BlocksPerEra::get();
+                ValidTransaction::with_tag_prefix("DeeperNodeHeartbeat")
+                    .priority(priority)
+                    .and_provides((account, era))
+                    .longevity(era_end.saturating_sub(now).saturated_into::<u64>())
+                    .propagate(true)
+                    .build()
+            } else {
+                InvalidTransaction::Call.into()
+            }
+        }
     }
 
     impl<T: Config> Pallet<T> {
+        /// Payload a device signs to send an unsigned heartbeat
+        pub fn heartbeat_payload(account: &T::AccountId, block: BlockNumberFor<T>) -> Vec<u8> {
+            (HEARTBEAT_CONTEXT, account, block).encode()
+        }
+
+        /// EIP-191 (personal_sign) hash the eth address signs to bind a reward address
+        pub fn reward_mapping_hash(account: &T::AccountId, nonce: u64) -> [u8; 32] {
+            let message = (REWARD_MAPPING_CONTEXT, account, nonce).encode();
+            let mut len = Vec::new();
+            let mut n = message.len();
+            loop {
+                len.push(b'0' + (n % 10) as u8);
+                n /= 10;
+                if n == 0 {
+                    break;
+                }
+            }
+            len.reverse();
+            let mut data = b"\x19Ethereum Signed Message:\n".to_vec();
+            data.extend(len);
+            data.extend(message);
+            keccak_256(&data)
+        }
+
+        /// Eth address which produced `signature` over `hash`
+        pub fn eth_recover(signature: &[u8; 65], hash: &[u8; 32]) -> Option<H160> {
+            let pubkey = secp256k1_ecdsa_recover(signature, hash).ok()?;
+            Some(H160::from_slice(&keccak_256(&pubkey)[12..]))
+        }
+
+        pub(crate) fn era_of(block: BlockNumberFor<T>) -> BlockNumberFor<T> {
+            block / T::BlocksPerEra::get()
+        }
+
+        fn record_online(sender: T::AccountId) {
+            let current_block = <frame_system::Pallet<T>>::block_number();
+            let era = Self::era_of(current_block);
+            let country = <DeviceInfo<T>>::try_get(&sender)
+                .ok()
+                .map(|node| node.country);
+            let last_era = ImOnline::<T>::get(&sender).map(Self::era_of);
+            ImOnline::<T>::insert(&sender, current_block.clone());
+            if !OnboardTime::<T>::contains_key(&sender) {
+                OnboardTime::<T>::insert(&sender, current_block.clone());
+                DevicesOnboard::<T>::insert(&sender, ());
+                Self::update_device_stats(era, country.as_ref(), |stats| {
+                    stats.onboarded = stats.onboarded.saturating_add(1)
+                });
+            }
+            // a device is counted once per era it is active in
+            if last_era != Some(era) {
+                Self::update_device_stats(era, country.as_ref(), |stats| {
+                    stats.active = stats.active.saturating_add(1)
+                });
+            }
+            Self::deposit_event(Event::ImOnline(sender, current_block));
+        }
+
+        pub(crate) fn update_device_stats(
+            era: BlockNumberFor<T>,
+            country: Option<&CountryRegion>,
+            update: impl Fn(&mut DeviceStats),
+        ) {
+            <EraDeviceStats<T>>::mutate(era, &update);
+            if let Some(country) = country {
+                <CountryEraDeviceStats<T>>::mutate(era, country, &update);
+            }
+        }
+
+        /// Number of devices which have ever been online
+        pub fn devices_onboard_count() -> u32 {
+            DevicesOnboard::<T>::count()
+        }
+
         // try to remove an account from country and region server lists if exists
         fn try_remove_server(sender: &T::AccountId) -> DispatchResult {
             if <DeviceInfo<T>>::contains_key(&sender) {
@@ -465,19 +1022,13 @@ pub mod pallet {
                 let first_region = <RegionMap<T>>::get(&node.country);
                 let sec_region = <RegionMap<T>>::get(&first_region);
 
-                // remove from country server list
-                let mut server_list = <ServersByCountry<T>>::get(&node.country);
-                let _ = Self::country_list_remove(&mut server_list, &sender, &node.country);
-
-                // remove from level 3 region server list
-                server_list = <ServersByRegion<T>>::get(&first_region);
-                let _ = Self::region_list_remove(&mut server_list, &sender, &first_region);
-
-                // remove from level 2 region server list
-                server_list = <ServersByRegion<T>>::get(&sec_region);
-                let _ = Self::region_list_remove(&mut server_list, &sender, &sec_region);
+                // remove from country and region server lists
+                Self::country_list_remove(&sender, &node.country);
+                Self::region_list_remove(&sender, &first_region);
+                Self::region_list_remove(&sender, &sec_region);
 
                 // ensure consistency
+                <ServerExpirations<T>>::remove(node.expire, &sender);
                 node.expire = <frame_system::Pallet<T>>::block_number();
                 <DeviceInfo<T>>::insert(&sender, node);
             }
@@ -492,121 +1043,375 @@ pub mod pallet {
             let sec_region = <RegionMap<T>>::get(&first_region);
 
             // country registration
-            let mut country_server_list = <ServersByCountry<T>>::get(&node.country);
-            if !Self::country_list_insert(
-                &mut country_server_list,
-                &sender,
-                &node.country,
-                &duration,
-            ) {
+            if !Self::country_list_insert(&sender, &node.country, &duration) {
                 Err(Error::<T>::DoubleCountryRegistration)?
             }
 
             // level 3 region registration
-            let mut level3_server_list = <ServersByRegion<T>>::get(&first_region);
-            if !Self::region_list_insert(&mut level3_server_list, &sender, &first_region, &duration)
-            {
-                let _ = Self::country_list_remove(&mut country_server_list, &sender, &node.country);
+            if !Self::region_list_insert(&sender, &first_region, &duration) {
+                Self::country_list_remove(&sender, &node.country);
                 Err(Error::<T>::DoubleLevel3Registration)?
             }
 
             // level 2 region registration
-            let mut level2_server_list = <ServersByRegion<T>>::get(&sec_region);
-            if !Self::region_list_insert(&mut level2_server_list, &sender, &sec_region, &duration) {
-                let _ = Self::country_list_remove(&mut country_server_list, &sender, &node.country);
-                let _ = Self::region_list_remove(&mut level3_server_list, &sender, &first_region);
+            if !Self::region_list_insert(&sender, &sec_region, &duration) {
+                Self::country_list_remove(&sender, &node.country);
+                Self::region_list_remove(&sender, &first_region);
                 Err(Error::<T>::DoubleLevel2Registration)?
             }
 
             // ensure consistency
+            <ServerExpirations<T>>::remove(node.expire, &sender);
             node.expire = <frame_system::Pallet<T>>::block_number() + duration;
+            <ServerExpirations<T>>::insert(node.expire, &sender, ());
             <DeviceInfo<T>>::insert(&sender, node);
 
             Ok(())
         }
 
         fn country_list_insert(
-            servers: &mut Vec<T::AccountId>,
             account: &T::AccountId,
             country: &CountryRegion,
             duration: &BlockNumberFor<T>,
         ) -> bool {
-            match servers.binary_search(&account) {
-                Ok(_) => false,
-                Err(index) => {
-                    servers.insert(index, account.clone());
-                    <ServersByCountry<T>>::insert(&country, servers);
-                    Self::deposit_event(Event::ServerCountryAdded(
-                        account.clone(),
-                        country.clone(),
-                        duration.clone(),
-                        index as u64,
-                    ));
-                    true
-                }
+            if <ServersByCountry<T>>::contains_key(country, account) {
+                return false;
             }
+            <ServersByCountry<T>>::insert(country, account, ());
+            let count = <ServerCountByCountry<T>>::mutate(country, |count| {
+                *count = count.saturating_add(1);
+                *count
+            });
+            Self::deposit_event(Event::ServerCountryListed(
+                account.clone(),
+                country.clone(),
+                duration.clone(),
+                count,
+            ));
+            true
         }
 
-        fn country_list_remove(
-            servers: &mut Vec<T::AccountId>,
-            account: &T::AccountId,
-            country: &CountryRegion,
-        ) -> bool {
-            match servers.binary_search(&account) {
-                Ok(index) => {
-                    servers.remove(index);
-                    <ServersByCountry<T>>::insert(&country, servers);
-                    Self::deposit_event(Event::ServerCountryRemoved(
-                        account.clone(),
-                        country.clone(),
-                    ));
-                    true
-                }
-                Err(_) => false,
+        fn country_list_remove(account: &T::AccountId, country: &CountryRegion) -> bool {
+            if <ServersByCountry<T>>::take(country, account).is_none() {
+                return false;
             }
+            <ServerCountByCountry<T>>::mutate_exists(country, |count| {
+                *count = count
+                    .map(|count| count.saturating_sub(1))
+                    .filter(|count| *count > 0);
+            });
+            Self::deposit_event(Event::ServerCountryRemoved(
+                account.clone(),
+                country.clone(),
+            ));
+            true
         }
 
         fn region_list_insert(
-            servers: &mut Vec<T::AccountId>,
             account: &T::AccountId,
             region: &CountryRegion,
             duration: &BlockNumberFor<T>,
         ) -> bool {
-            match servers.binary_search(&account) {
-                Ok(_) => false,
-                Err(index) => {
-                    servers.insert(index, account.clone());
-                    <ServersByRegion<T>>::insert(&region, servers);
-                    Self::deposit_event(Event::ServerRegionAdded(
+            match Self::region_server_insert(account, region) {
+                Some(count) => {
+                    Self::deposit_event(Event::ServerRegionListed(
                         account.clone(),
                         region.clone(),
                         duration.clone(),
-                        index as u64,
+                        count,
                     ));
                     true
                 }
+                None => false,
             }
         }
 
-        fn region_list_remove(
-            servers: &mut Vec<T::AccountId>,
-            account: &T::AccountId,
-            region: &CountryRegion,
-        ) -> bool {
-            match servers.binary_search(&account) {
-                Ok(index) => {
-                    servers.remove(index);
-                    <ServersByRegion<T>>::insert(&region, servers);
-                    Self::deposit_event(Event::ServerRegionRemoved(
-                        account.clone(),
-                        region.clone(),
-                    ));
-                    true
+        fn region_list_remove(account: &T::AccountId, region: &CountryRegion) -> bool {
+            if !Self::region_server_remove(account, region) {
+                return false;
+            }
+            Self::deposit_event(Event::ServerRegionRemoved(account.clone(), region.clone()));
+            true
+        }
+
+        // list an account in a region, returns the new list size; None if already listed
+        fn region_server_insert(account: &T::AccountId, region: &CountryRegion) -> Option<u32> {
+            if <ServersByRegion<T>>::contains_key(region, account) {
+                return None;
+            }
+            <ServersByRegion<T>>::insert(region, account, ());
+            Some(<ServerCountByRegion<T>>::mutate(region, |count| {
+                *count = count.saturating_add(1);
+                *count
+            }))
+        }
+
+        fn region_server_remove(account: &T::AccountId, region: &CountryRegion) -> bool {
+            if <ServersByRegion<T>>::take(region, account).is_none() {
+                return false;
+            }
+            <ServerCountByRegion<T>>::mutate_exists(region, |count| {
+                *count = count
+                    .map(|count| count.saturating_sub(1))
+                    .filter(|count| *count > 0);
+            });
+            true
+        }
+
+        /// Remove the servers whose registration expired before `now` from the server lists,
+        /// within the remaining block weight
+        pub(crate) fn sweep_expired_servers(
+            now: BlockNumberFor<T>,
+            remaining_weight: Weight,
+        ) -> Weight {
+            sweep_expirations::<NextServerExpiryToSweep<T>, ServerExpirations<T>, _, _, _>(
+                now,
+                remaining_weight,
+                T::DbWeight::get(),
+                T::WeightInfo::expire_server(),
+                |_, server| Self::expire_server(&server),
+            )
+        }
+
+        /// Remove an expired server from the server lists
+        pub(crate) fn expire_server(server: &T::AccountId) {
+            let _ = Self::try_remove_server(server);
+            Self::deposit_event(Event::ServerExpired(server.clone()));
+        }
+
+        // a country code is mapped to a level 2 region, which is mapped to a level 1 region
+        fn is_country(code: &CountryRegion) -> bool {
+            <RegionMap<T>>::contains_key(code)
+                && <RegionMap<T>>::contains_key(<RegionMap<T>>::get(code))
+        }
+
+        fn has_children(code: &CountryRegion) -> bool {
+            <RegionChildren<T>>::get(code) > 0
+        }
+
+        fn region_child_remove(code: &CountryRegion) {
+            <RegionChildren<T>>::mutate_exists(code, |count| {
+                *count = count.map(|n| n.saturating_sub(1)).filter(|n| *n > 0)
+            });
+        }
+
+        fn country_device_remove(country: &CountryRegion) {
+            <DeviceCountByCountry<T>>::mutate_exists(country, |count| {
+                *count = count.map(|n| n.saturating_sub(1)).filter(|n| *n > 0)
+            });
+        }
+
+        /// Recount the region codes mapped to every region
+        pub(crate) fn count_region_children() -> u64 {
+            let _ = <RegionChildren<T>>::clear(u32::MAX, None);
+            let mut codes = 0u64;
+            for parent in <RegionMap<T>>::iter_values() {
+                codes += 1;
+                <RegionChildren<T>>::mutate(&parent, |count| *count += 1);
+            }
+            codes
+        }
+
+        // move servers from one region server list to another
+        fn move_servers(servers: &[T::AccountId], from: &CountryRegion, to: &CountryRegion) {
+            for server in servers {
+                Self::region_server_remove(server, from);
+                Self::region_server_insert(server, to);
+            }
+        }
+
+        fn validate_endpoint(endpoint: &EndpointRecord) -> DispatchResult {
+            ensure!(
+                endpoint.encoded_size() <= T::MaxIpLength::get(),
+                Error::<T>::EndpointTooLong
+            );
+            match endpoint {
+                EndpointRecord::Plain(endpoint) => {
+                    ensure!(!endpoint.addresses.is_empty(), Error::<T>::InvalidEndpoint);
+                    let unspecified = endpoint.addresses.iter().any(|address| match address {
+                        IpAddress::V4(octets) => octets == &[0u8; 4],
+                        IpAddress::V6(octets) => octets == &[0u8; 16],
+                    });
+                    ensure!(!unspecified, Error::<T>::InvalidIP);
+                    ensure!(!endpoint.ports.contains(&0), Error::<T>::InvalidEndpoint);
                 }
-                Err(_) => false,
+                EndpointRecord::Encrypted { ciphertext, .. } => {
+                    ensure!(!ciphertext.is_empty(), Error::<T>::InvalidEndpoint);
+                }
+            }
+            Ok(())
+        }
+
+        // raw 4 or 16 byte address passed to register_device_deprecated
+        fn legacy_address(ip: &[u8]) -> Option<IpAddress> {
+            match ip.len() {
+                4 => ip.try_into().ok().map(IpAddress::V4),
+                16 => ip.try_into().ok().map(IpAddress::V6),
+                _ => None,
             }
         }
 
+        /// All servers listed in a country, in storage order
+        pub fn servers_by_country(country: CountryRegion) -> Vec<T::AccountId> {
+            <ServersByCountry<T>>::iter_key_prefix(country).collect()
+        }
+
+        /// All servers listed in a level 2 or level 3 region, in storage order
+        pub fn servers_by_region(region: CountryRegion) -> Vec<T::AccountId> {
+            <ServersByRegion<T>>::iter_key_prefix(region).collect()
+        }
+
+        /// Active servers of a country and their endpoints in storage order,
+        /// starting after `start_after`
+        pub fn active_servers_by_country(
+            country: &CountryRegion,
+            start_after: Option<T::AccountId>,
+            limit: u32,
+        ) -> Vec<(T::AccountId, Option<EndpointRecord>)> {
+            let servers = match start_after {
+                Some(account) => <ServersByCountry<T>>::iter_key_prefix_from(
+                    country,
+                    <ServersByCountry<T>>::hashed_key_for(country, account),
+                ),
+                None => <ServersByCountry<T>>::iter_key_prefix(country),
+            };
+            Self::active_servers_page(servers, limit)
+        }
+
+        /// Active servers of a level 2 or level 3 region and their endpoints in storage order,
+        /// starting after `start_after`
+        pub fn active_servers_by_region(
+            region: &CountryRegion,
+            start_after: Option<T::AccountId>,
+            limit: u32,
+        ) -> Vec<(T::AccountId, Option<EndpointRecord>)> {
+            let servers = match start_after {
+                Some(account) => <ServersByRegion<T>>::iter_key_prefix_from(
+                    region,
+                    <ServersByRegion<T>>::hashed_key_for(region, account),
+                ),
+                None => <ServersByRegion<T>>::iter_key_prefix(region),
+            };
+            Self::active_servers_page(servers, limit)
+        }
+
+        // servers past their expire block are skipped
+        fn active_servers_page(
+            servers: impl Iterator<Item = T::AccountId>,
+            limit: u32,
+        ) -> Vec<(T::AccountId, Option<EndpointRecord>)> {
+            let current_block = <frame_system::Pallet<T>>::block_number();
+            servers
+                .filter(|server| <DeviceInfo<T>>::get(server).expire >= current_block)
+                .take(limit as usize)
+                .map(|server| {
+                    let endpoint = <ServerEndpoints<T>>::get(&server);
+                    (server, endpoint)
+                })
+                .collect()
+        }
+
+        /// Add a client rating, from 1 to MAX_RATING, counted `weight` times to the ratings
+        /// of a server
+        pub fn record_rating(server: &T::AccountId, rating: u8, weight: u64) {
+            let rating = rating.clamp(1, MAX_RATING);
+            let score = Perbill::from_rational(u32::from(rating - 1), u32::from(MAX_RATING - 1))
+                .deconstruct();
+            let era = Self::era_of(<frame_system::Pallet<T>>::block_number());
+            <ServerRatings<T>>::mutate(server, |ratings| {
+                let decay = Self::rating_decay(ratings.era, era);
+                ratings.score =
+                    (decay * ratings.score).saturating_add(u64::from(score).saturating_mul(weight));
+                ratings.weight = (decay * ratings.weight)
+                    .saturating_add(u64::from(Perbill::one().deconstruct()).saturating_mul(weight));
+                ratings.era = era;
+            });
+        }
+
+        /// Reputation of a server: the time decayed weighted average of its ratings, with one
+        /// neutral rating of weight one as prior so a few ratings can not make a new server look best or worst
+        pub fn reputation(server: &T::AccountId) -> Perbill {
+            let ratings = <ServerRatings<T>>::get(server);
+            let era = Self::era_of(<frame_system::Pallet<T>>::block_number());
+            let decay = Self::rating_decay(ratings.era, era);
+            let one = u64::from(Perbill::one().deconstruct());
+            Perbill::from_rational(
+                (decay * ratings.score).saturating_add(one / 2),
+                (decay * ratings.weight).saturating_add(one),
+            )
+        }
+
+        // share of the rating weight left after the eras between `from` and `to`
+        fn rating_decay(from: BlockNumberFor<T>, to: BlockNumberFor<T>) -> Perbill {
+            let eras: u32 = to.saturating_sub(from).saturated_into();
+            T::ReputationDecay::get().saturating_pow(eras as usize)
+        }
+
+        /// Reliability of a device from its credit proof history: the share of the covered
+        /// days the device was up counts for 70%, the steadiness of its daily traffic for 30%
+        pub fn reliability_score(account_id: &T::AccountId) -> Perbill {
+            let history = <CreditProofHistory<T>>::get(account_id);
+            if history.is_empty() {
+                return Perbill::zero();
+            }
+            let first = history
+                .iter()
+                .map(|proof| proof.0)
+                .min()
+                .unwrap_or_default();
+            let last = history
+                .iter()
+                .map(|proof| proof.0)
+                .max()
+                .unwrap_or_default();
+            // days without a proof count as down
+            let days = (u64::from(last - first) / SECS_PER_DAY + 1).max(history.len() as u64);
+            let uptime: u64 = history
+                .iter()
+                .map(|proof| u64::from(proof.2).min(SECS_PER_DAY))
+                .sum();
+            let uptime_ratio = Perbill::from_rational(uptime, days * SECS_PER_DAY);
+
+            // one minus the mean absolute deviation of the traffic relative to its mean
+            let count = history.len() as u128;
+            let total: u128 = history.iter().map(|proof| u128::from(proof.1)).sum();
+            let consistency = if total == 0 {
+                Perbill::zero()
+            } else {
+                let deviation: u128 = history
+                    .iter()
+                    .map(|proof| (count * u128::from(proof.1)).abs_diff(total))
+                    .sum();
+                Perbill::from_rational(deviation.min(count * total), count * total).left_from_one()
+            };
+            (Perbill::from_percent(70) * uptime_ratio)
+                .saturating_add(Perbill::from_percent(30) * consistency)
+        }
+
+        /// Registration, last heartbeat and eras offline of a device
+        pub fn device_status(
+            account_id: &T::AccountId,
+        ) -> Option<DeviceStatus<T::AccountId, BlockNumberFor<T>>> {
+            if !<DeviceInfo<T>>::contains_key(account_id) {
+                return None;
+            }
+            let node = <DeviceInfo<T>>::get(account_id);
+            Some(DeviceStatus {
+                account_id: node.account_id,
+                country: node.country,
+                expire: node.expire,
+                im_online: Self::get_im_online(account_id),
+                onboard_time: Self::onboard_time(account_id),
+                eras_offline: <Self as NodeInterface<_, _>>::get_eras_offline(account_id),
+                endpoint: <ServerEndpoints<T>>::get(account_id),
+            })
+        }
+
+        /// All (code, parent code) pairs of the region map
+        pub fn region_tree() -> Vec<(CountryRegion, CountryRegion)> {
+            <RegionMap<T>>::iter().collect()
+        }
+
         pub fn setup_region_map() {
             /* level 1 */
             /*
@@ -913,6 +1718,7 @@ pub mod pallet {
             <RegionMap<T>>::insert("NR".as_bytes().to_vec(), "OCN".as_bytes().to_vec());
             <RegionMap<T>>::insert("PW".as_bytes().to_vec(), "OCN".as_bytes().to_vec());
 
+            Self::count_region_children();
             <RegionMapInit<T>>::put(true);
         }
     }
@@ -933,6 +1739,26 @@ pub mod pallet {
             TryInto::<u32>::try_into(eras).ok().unwrap()
         }
 
+        fn get_reliability_score(account_id: &T::AccountId) -> Perbill {
+            Self::reliability_score(account_id)
+        }
+
+        fn rate_server(server: &T::AccountId, rating: u8, weight: u64) {
+            Self::record_rating(server, rating, weight)
+        }
+
+        fn get_reputation(server: &T::AccountId) -> Perbill {
+            Self::reputation(server)
+        }
+
+        fn get_daily_sharing_traffic(account_id: &T::AccountId) -> Option<u64> {
+            if <DeviceCreditProof<T>>::contains_key(account_id) {
+                Some(Self::device_credit_proof(account_id).1)
+            } else {
+                None
+            }
+        }
+
         fn get_accounts_deeper_evm(account_id: &T::AccountId) -> Option<H160> {
             Self::rewards_accounts_deeper_evm(account_id)
         }
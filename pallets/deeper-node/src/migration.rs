@@ -24,7 +24,7 @@ pub mod v1 {
         fn on_runtime_upgrade() -> Weight {
             let current_version = Pallet::<T>::current_storage_version();
             let onchain_version = Pallet::<T>::on_chain_storage_version();
-            if onchain_version == 0 && current_version == 1 {
+            if onchain_version == 0 && current_version >= 1 {
                 let mut translated = 0u64;
                 DeviceInfo::<T>::translate(
                     |_key, old_node: OldNode<T::AccountId, BlockNumberFor<T>>| {
@@ -38,14 +38,244 @@ pub mod v1 {
                     },
                 );
 
+                StorageVersion::new(1).put::<Pallet<T>>();
+                log::info!(
+                    target: LOG_TARGET,
+                    "Upgraded {} node, storage to version 1",
+                    translated
+                );
+                T::DbWeight::get().reads_writes(translated + 1, translated + 1)
+            } else {
+                log::info!(
+                    target: LOG_TARGET,
+                    "Migration did not execute. This probably should be removed"
+                );
+                T::DbWeight::get().reads(1)
+            }
+        }
+    }
+}
+
+pub mod v2 {
+    use frame_support::{pallet_prelude::*, weights::Weight, LOG_TARGET};
+
+    use super::*;
+    use frame_support::traits::OnRuntimeUpgrade;
+
+    // server lists before v3: one sorted vector per code
+    #[frame_support::storage_alias]
+    pub(crate) type ServersByCountry<T: Config> = StorageMap<
+        Pallet<T>,
+        Blake2_128Concat,
+        CountryRegion,
+        Vec<<T as frame_system::Config>::AccountId>,
+        ValueQuery,
+    >;
+
+    #[frame_support::storage_alias]
+    pub(crate) type ServersByRegion<T: Config> = StorageMap<
+        Pallet<T>,
+        Blake2_128Concat,
+        CountryRegion,
+        Vec<<T as frame_system::Config>::AccountId>,
+        ValueQuery,
+    >;
+
+    /// Queue the servers listed before the upgrade by the block their registration expires.
+    pub struct MigrateToV2<T>(sp_std::marker::PhantomData<T>);
+    impl<T: Config> OnRuntimeUpgrade for MigrateToV2<T> {
+        fn on_runtime_upgrade() -> Weight {
+            let current_version = Pallet::<T>::current_storage_version();
+            let onchain_version = Pallet::<T>::on_chain_storage_version();
+            if onchain_version == 1 && current_version >= 2 {
+                let mut countries = 0u64;
+                let mut queued = 0u64;
+                let mut earliest = <frame_system::Pallet<T>>::block_number();
+                for servers in ServersByCountry::<T>::iter_values() {
+                    countries += 1;
+                    for server in servers {
+                        queued += 1;
+                        let expire = DeviceInfo::<T>::get(&server).expire;
+                        if expire < earliest {
+                            earliest = expire;
+                        }
+                        ServerExpirations::<T>::insert(expire, server, ());
+                    }
+                }
+                NextServerExpiryToSweep::<T>::put(earliest);
+
+                StorageVersion::new(2).put::<Pallet<T>>();
+                log::info!(
+                    target: LOG_TARGET,
+                    "Queued {} servers, storage to version 2",
+                    queued
+                );
+                T::DbWeight::get().reads_writes(countries + queued + 2, queued + 2)
+            } else {
+                log::info!(
+                    target: LOG_TARGET,
+                    "Migration did not execute. This probably should be removed"
+                );
+                T::DbWeight::get().reads(1)
+            }
+        }
+    }
+}
+
+pub mod v3 {
+    use frame_support::{pallet_prelude::*, weights::Weight, LOG_TARGET};
+
+    use super::*;
+    use frame_support::traits::OnRuntimeUpgrade;
+
+    /// Move the server lists from one vector per code to one entry per listed server.
+    /// Listed servers have no position anymore: from this version on `ServerCountryListed`
+    /// and `ServerRegionListed`, carrying the list size, replace `ServerCountryAdded` and
+    /// `ServerRegionAdded`, which carried the list position.
+    pub struct MigrateToV3<T>(sp_std::marker::PhantomData<T>);
+    impl<T: Config> OnRuntimeUpgrade for MigrateToV3<T> {
+        fn on_runtime_upgrade() -> Weight {
+            let current_version = Pallet::<T>::current_storage_version();
+            let onchain_version = Pallet::<T>::on_chain_storage_version();
+            if onchain_version == 2 && current_version >= 3 {
+                // old and new entries share the storage prefix, drain before inserting
+                let countries: Vec<_> = v2::ServersByCountry::<T>::drain().collect();
+                let regions: Vec<_> = v2::ServersByRegion::<T>::drain().collect();
+                let lists = (countries.len() + regions.len()) as u64;
+                let mut listed = 0u64;
+                for (country, servers) in countries {
+                    listed += servers.len() as u64;
+                    ServerCountByCountry::<T>::insert(&country, servers.len() as u32);
+                    for server in servers {
+                        ServersByCountry::<T>::insert(&country, server, ());
+                    }
+                }
+                for (region, servers) in regions {
+                    listed += servers.len() as u64;
+                    ServerCountByRegion::<T>::insert(&region, servers.len() as u32);
+                    for server in servers {
+                        ServersByRegion::<T>::insert(&region, server, ());
+                    }
+                }
+
+                StorageVersion::new(3).put::<Pallet<T>>();
+                log::info!(
+                    target: LOG_TARGET,
+                    "Moved {} server list entries, storage to version 3",
+                    listed
+                );
+                T::DbWeight::get().reads_writes(lists + 1, 2 * lists + listed + 1)
+            } else {
+                log::info!(
+                    target: LOG_TARGET,
+                    "Migration did not execute. This probably should be removed"
+                );
+                T::DbWeight::get().reads(1)
+            }
+        }
+    }
+}
+
+pub mod v4 {
+    use frame_support::{pallet_prelude::*, weights::Weight, LOG_TARGET};
+
+    use super::*;
+    use frame_support::traits::OnRuntimeUpgrade;
+    use frame_system::pallet_prelude::BlockNumberFor;
+
+    // devices onboard before v4: one vector of all devices
+    #[frame_support::storage_alias]
+    pub(crate) type DevicesOnboard<T: Config> =
+        StorageValue<Pallet<T>, Vec<<T as frame_system::Config>::AccountId>, ValueQuery>;
+
+    /// Move the onboard devices into a counted map and backfill the device statistics:
+    /// onboarding per era from `OnboardTime`, activity of the current era from `ImOnline`.
+    pub struct MigrateToV4<T>(sp_std::marker::PhantomData<T>);
+    impl<T: Config> OnRuntimeUpgrade for MigrateToV4<T> {
+        fn on_runtime_upgrade() -> Weight {
+            let current_version = Pallet::<T>::current_storage_version();
+            let onchain_version = Pallet::<T>::on_chain_storage_version();
+            if onchain_version == 3 && current_version >= 4 {
+                // the old value shares the storage prefix of the new map, take it first
+                let devices = DevicesOnboard::<T>::take();
+                let onboard = devices.len() as u64;
+                for device in devices {
+                    crate::DevicesOnboard::<T>::insert(device, ());
+                }
+
+                let current_era = Pallet::<T>::era_of(<frame_system::Pallet<T>>::block_number());
+                let mut counted = 0u64;
+                for (device, block) in OnboardTime::<T>::iter() {
+                    counted += 1;
+                    let country = DeviceInfo::<T>::try_get(&device)
+                        .ok()
+                        .map(|node| node.country);
+                    Pallet::<T>::update_device_stats(
+                        Pallet::<T>::era_of(block),
+                        country.as_ref(),
+                        |stats| stats.onboarded = stats.onboarded.saturating_add(1),
+                    );
+                    let active = ImOnline::<T>::get(&device)
+                        .map(Pallet::<T>::era_of)
+                        .map_or(false, |era: BlockNumberFor<T>| era == current_era);
+                    if active {
+                        Pallet::<T>::update_device_stats(current_era, country.as_ref(), |stats| {
+                            stats.active = stats.active.saturating_add(1)
+                        });
+                    }
+                }
+
                 current_version.put::<Pallet<T>>();
                 log::info!(
                     target: LOG_TARGET,
-                    "Upgraded {} node, storage to version {:?}",
-                    translated,
+                    "Moved {} onboard devices, counted {}, storage to version {:?}",
+                    onboard,
+                    counted,
                     current_version
                 );
-                T::DbWeight::get().reads_writes(translated + 1, translated + 1)
+                // every device touches its era and country statistics at most twice
+                T::DbWeight::get().reads_writes(1 + 3 * counted, 2 + 2 * onboard + 4 * counted)
+            } else {
+                log::info!(
+                    target: LOG_TARGET,
+                    "Migration did not execute. This probably should be removed"
+                );
+                T::DbWeight::get().reads(1)
+            }
+        }
+    }
+}
+
+pub mod v5 {
+    use frame_support::{pallet_prelude::*, weights::Weight, LOG_TARGET};
+
+    use super::*;
+    use frame_support::traits::OnRuntimeUpgrade;
+
+    /// Backfill the number of region codes under every region and the number of devices
+    /// registered with every country code.
+    pub struct MigrateToV5<T>(sp_std::marker::PhantomData<T>);
+    impl<T: Config> OnRuntimeUpgrade for MigrateToV5<T> {
+        fn on_runtime_upgrade() -> Weight {
+            let current_version = Pallet::<T>::current_storage_version();
+            let onchain_version = Pallet::<T>::on_chain_storage_version();
+            if onchain_version == 4 && current_version >= 5 {
+                let codes = Pallet::<T>::count_region_children();
+                let mut devices = 0u64;
+                for node in DeviceInfo::<T>::iter_values() {
+                    devices += 1;
+                    DeviceCountByCountry::<T>::mutate(&node.country, |count| *count += 1);
+                }
+
+                current_version.put::<Pallet<T>>();
+                log::info!(
+                    target: LOG_TARGET,
+                    "Counted {} region codes and {} devices, storage to version {:?}",
+                    codes,
+                    devices,
+                    current_version
+                );
+                T::DbWeight::get().reads_writes(1 + 2 * codes + 2 * devices, 1 + codes + devices)
             } else {
                 log::info!(
                     target: LOG_TARGET,
@@ -36,7 +36,7 @@ frame_support::construct_runtime!(
     {
         System: frame_system::{Pallet, Call, Config<T>, Storage, Event<T>},
         Balances: pallet_balances::{Pallet, Call, Event<T>, Config<T>},
-        DeeperNode: pallet_deeper_node::{Pallet, Call, Storage, Event<T>},
+        DeeperNode: pallet_deeper_node::{Pallet, Call, Storage, Event<T>, ValidateUnsigned},
     }
 );
 
@@ -102,6 +102,7 @@ parameter_types! {
     pub const MaxDurationEras: u8 = 7;
     pub const BlocksPerEra: BlockNumber =  6 * EPOCH_DURATION_IN_BLOCKS;
     pub const MaxIpLength: usize = 256;
+    pub const ReputationDecay: sp_runtime::Perbill = sp_runtime::Perbill::from_percent(90);
 }
 impl pallet_deeper_node::Config for Test {
     type RuntimeEvent = RuntimeEvent;
@@ -112,6 +113,11 @@ impl pallet_deeper_node::Config for Test {
     type MaxIpLength = MaxIpLength;
     type WeightInfo = ();
     type VerifySignatureInterface = ();
+    type RegionOrigin = frame_system::EnsureRoot<Self::AccountId>;
+    type DeviceSignature = sp_runtime::testing::TestSignature;
+    type UnsignedPriority = frame_support::traits::ConstU64<700>;
+    type MaxCreditProofs = frame_support::traits::ConstU32<4>;
+    type ReputationDecay = ReputationDecay;
 }
 
 // Build genesis storage according to the mock runtime.
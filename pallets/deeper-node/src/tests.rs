@@ -13,11 +13,39 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-use crate::{mock::*, Error};
-use frame_support::{assert_err, assert_ok, dispatch::DispatchErrorWithPostInfo};
-use node_primitives::deeper_node::NodeInterface;
-use sp_core::H160;
-use std::str::FromStr;
+use crate::{mock::*, DeviceStats, DevicesOnboard, Error};
+use frame_support::{
+    assert_err, assert_ok, dispatch::DispatchErrorWithPostInfo, traits::Hooks, weights::Weight,
+};
+use node_primitives::deeper_node::{
+    DeviceStatus, Endpoint, EndpointRecord, IpAddress, NodeInterface, TunnelProtocol, MAX_RATING,
+};
+use sp_core::{ecdsa, Pair, H160};
+use sp_io::crypto::secp256k1_ecdsa_recover_compressed;
+use sp_runtime::{
+    testing::TestSignature,
+    traits::ValidateUnsigned,
+    transaction_validity::{InvalidTransaction, TransactionSource, TransactionValidity},
+    Perbill,
+};
+
+fn sorted(mut servers: Vec<u64>) -> Vec<u64> {
+    servers.sort();
+    servers
+}
+
+fn accounts(page: Vec<(u64, Option<EndpointRecord>)>) -> Vec<u64> {
+    page.into_iter().map(|(account, _)| account).collect()
+}
+
+/// Endpoint recorded from the `vec![1, 2, 3, 4]` ip passed to `register_device_deprecated`
+fn legacy_endpoint() -> EndpointRecord {
+    EndpointRecord::Plain(Endpoint {
+        addresses: vec![IpAddress::V4([1, 2, 3, 4])],
+        ports: vec![],
+        protocols: vec![],
+    })
+}
 
 #[test]
 fn unregister_device() {
@@ -102,6 +130,359 @@ fn unregister_server() {
     });
 }
 
+#[test]
+fn server_directory() {
+    new_test_ext().execute_with(|| {
+        DeeperNode::setup_region_map();
+        for account in 1..=3 {
+            assert_ok!(DeeperNode::register_device_deprecated(
+                RuntimeOrigin::signed(account),
+                vec![1, 2, 3, 4],
+                "US".as_bytes().to_vec()
+            ));
+        }
+        assert_ok!(DeeperNode::register_server(RuntimeOrigin::signed(1), 1));
+        assert_ok!(DeeperNode::register_server(RuntimeOrigin::signed(2), 1));
+        assert_ok!(DeeperNode::register_server(RuntimeOrigin::signed(3), 0));
+        assert_ok!(DeeperNode::im_online(RuntimeOrigin::signed(1)));
+        run_to_block(1);
+
+        // server 3 is past its expire block
+        let us = "US".as_bytes().to_vec();
+        assert_eq!(
+            sorted(accounts(DeeperNode::active_servers_by_country(
+                &us, None, 10
+            ))),
+            vec![1, 2]
+        );
+        // pages follow the storage order
+        let first = accounts(DeeperNode::active_servers_by_country(&us, None, 1));
+        assert_eq!(first.len(), 1);
+        let second = accounts(DeeperNode::active_servers_by_country(
+            &us,
+            Some(first[0]),
+            10,
+        ));
+        assert_eq!(second.len(), 1);
+        assert_eq!(sorted(vec![first[0], second[0]]), vec![1, 2]);
+        assert_eq!(
+            DeeperNode::active_servers_by_country(&us, Some(second[0]), 10),
+            Vec::new()
+        );
+        let amn = "AMN".as_bytes().to_vec();
+        assert_eq!(
+            sorted(accounts(DeeperNode::active_servers_by_region(
+                &amn, None, 10
+            ))),
+            vec![1, 2]
+        );
+        let first = accounts(DeeperNode::active_servers_by_region(&amn, None, 1));
+        assert_eq!(
+            DeeperNode::active_servers_by_region(&amn, Some(first[0]), 10).len(),
+            1
+        );
+        // directory pages carry the endpoints, here the legacy ips
+        assert_eq!(
+            DeeperNode::active_servers_by_country(&us, None, 10)[0].1,
+            Some(legacy_endpoint())
+        );
+
+        assert_eq!(
+            DeeperNode::device_status(&1),
+            Some(DeviceStatus {
+                account_id: 1,
+                country: us.clone(),
+                expire: BLOCKS_PER_ERA,
+                im_online: Some(0),
+                onboard_time: Some(0),
+                eras_offline: 0,
+                endpoint: Some(legacy_endpoint()),
+            })
+        );
+        assert_eq!(DeeperNode::device_status(&4), None);
+
+        let tree = DeeperNode::region_tree();
+        assert!(tree.contains(&(us, "AMN".as_bytes().to_vec())));
+        assert!(tree.contains(&("AMN".as_bytes().to_vec(), "AMER".as_bytes().to_vec())));
+    });
+}
+
+#[test]
+fn server_endpoint() {
+    new_test_ext().execute_with(|| {
+        DeeperNode::setup_region_map();
+        let endpoint = EndpointRecord::Plain(Endpoint {
+            addresses: vec![IpAddress::V4([1, 2, 3, 4]), IpAddress::V6([1; 16])],
+            ports: vec![443, 51820],
+            protocols: vec![TunnelProtocol::Trident, TunnelProtocol::WireGuard],
+        });
+        assert_err!(
+            DeeperNode::set_server_endpoint(RuntimeOrigin::signed(1), endpoint.clone()),
+            Error::<Test>::DeviceNotRegister
+        );
+
+        // ips which are not 4 or 16 bytes are not published
+        assert_ok!(DeeperNode::register_device_deprecated(
+            RuntimeOrigin::signed(1),
+            vec![1, 2, 3],
+            "US".as_bytes().to_vec()
+        ));
+        assert_eq!(DeeperNode::server_endpoint(&1), None);
+        assert_err!(
+            DeeperNode::register_device_deprecated(
+                RuntimeOrigin::signed(1),
+                vec![1; MaxIpLength::get() + 1],
+                "US".as_bytes().to_vec()
+            ),
+            Error::<Test>::InvalidIP
+        );
+
+        assert_ok!(DeeperNode::set_server_endpoint(
+            RuntimeOrigin::signed(1),
+            endpoint.clone()
+        ));
+        assert_eq!(DeeperNode::server_endpoint(&1), Some(endpoint.clone()));
+        // the legacy ip does not overwrite a published endpoint
+        assert_ok!(DeeperNode::register_device_deprecated(
+            RuntimeOrigin::signed(1),
+            vec![5, 6, 7, 8],
+            "US".as_bytes().to_vec()
+        ));
+        assert_eq!(DeeperNode::server_endpoint(&1), Some(endpoint));
+
+        let encrypted = EndpointRecord::Encrypted {
+            key: [7; 32],
+            ciphertext: vec![1; 64],
+        };
+        assert_ok!(DeeperNode::set_server_endpoint(
+            RuntimeOrigin::signed(1),
+            encrypted.clone()
+        ));
+        assert_eq!(
+            DeeperNode::device_status(&1).unwrap().endpoint,
+            Some(encrypted)
+        );
+
+        let invalid = |addresses: Vec<IpAddress>, ports: Vec<u16>| {
+            EndpointRecord::Plain(Endpoint {
+                addresses,
+                ports,
+                protocols: vec![TunnelProtocol::Trident],
+            })
+        };
+        assert_err!(
+            DeeperNode::set_server_endpoint(RuntimeOrigin::signed(1), invalid(vec![], vec![443])),
+            Error::<Test>::InvalidEndpoint
+        );
+        assert_err!(
+            DeeperNode::set_server_endpoint(
+                RuntimeOrigin::signed(1),
+                invalid(vec![IpAddress::V4([1, 2, 3, 4])], vec![0])
+            ),
+            Error::<Test>::InvalidEndpoint
+        );
+        assert_err!(
+            DeeperNode::set_server_endpoint(
+                RuntimeOrigin::signed(1),
+                invalid(vec![IpAddress::V4([0; 4])], vec![443])
+            ),
+            Error::<Test>::InvalidIP
+        );
+        assert_err!(
+            DeeperNode::set_server_endpoint(
+                RuntimeOrigin::signed(1),
+                EndpointRecord::Encrypted {
+                    key: [7; 32],
+                    ciphertext: vec![],
+                }
+            ),
+            Error::<Test>::InvalidEndpoint
+        );
+        assert_err!(
+            DeeperNode::set_server_endpoint(
+                RuntimeOrigin::signed(1),
+                EndpointRecord::Encrypted {
+                    key: [7; 32],
+                    ciphertext: vec![1; MaxIpLength::get()],
+                }
+            ),
+            Error::<Test>::EndpointTooLong
+        );
+
+        assert_ok!(DeeperNode::unregister_device(RuntimeOrigin::signed(1)));
+        assert_eq!(DeeperNode::server_endpoint(&1), None);
+    });
+}
+
+#[test]
+fn region_map_governance() {
+    new_test_ext().execute_with(|| {
+        DeeperNode::setup_region_map();
+        let code = |s: &str| s.as_bytes().to_vec();
+        assert_ok!(DeeperNode::register_device_deprecated(
+            RuntimeOrigin::signed(1),
+            vec![1, 2, 3, 4],
+            code("US")
+        ));
+        assert_ok!(DeeperNode::register_server(RuntimeOrigin::signed(1), 1));
+
+        // only the region origin can update the region map
+        assert!(
+            DeeperNode::insert_region(RuntimeOrigin::signed(1), code("QZ"), code("AMN")).is_err()
+        );
+        assert_err!(
+            DeeperNode::insert_region(RuntimeOrigin::root(), code("US"), code("AMN")),
+            Error::<Test>::RegionAlreadyExists
+        );
+        // a country can not be a parent
+        assert_err!(
+            DeeperNode::insert_region(RuntimeOrigin::root(), code("QZ"), code("US")),
+            Error::<Test>::InvalidCode
+        );
+        // an unknown parent does not create a new level 1 region
+        assert_err!(
+            DeeperNode::insert_region(RuntimeOrigin::root(), code("QZ"), code("XYZW")),
+            Error::<Test>::UnknownParent
+        );
+        assert_ok!(DeeperNode::insert_region(
+            RuntimeOrigin::root(),
+            code("QZ"),
+            code("AMN")
+        ));
+        assert_eq!(DeeperNode::region_children(code("AMN")), 6);
+        assert_eq!(DeeperNode::region_code(code("QZ")), code("AMN"));
+
+        // the witness must cover the listed servers
+        assert_err!(
+            DeeperNode::reparent_region(RuntimeOrigin::root(), code("US"), code("EUN"), 0),
+            Error::<Test>::ServerWitnessTooLow
+        );
+
+        // servers follow their country to the new regions
+        assert_ok!(DeeperNode::reparent_region(
+            RuntimeOrigin::root(),
+            code("US"),
+            code("EUN"),
+            1
+        ));
+        assert_eq!(DeeperNode::region_code(code("US")), code("EUN"));
+        assert!(DeeperNode::servers_by_region(code("AMN")).is_empty());
+        assert!(DeeperNode::servers_by_region(code("AMER")).is_empty());
+        assert_eq!(DeeperNode::servers_by_region(code("EUN")), vec![1]);
+        assert_eq!(DeeperNode::servers_by_region(code("EURO")), vec![1]);
+        assert_eq!(DeeperNode::server_count_by_region(code("AMN")), 0);
+        assert_eq!(DeeperNode::server_count_by_region(code("EURO")), 1);
+
+        // a level 2 region can only move to a known level 1 region
+        assert_err!(
+            DeeperNode::reparent_region(RuntimeOrigin::root(), code("EUN"), code("XYZW"), 1),
+            Error::<Test>::UnknownParent
+        );
+
+        // servers follow their level 2 region to the new level 1 region
+        assert_ok!(DeeperNode::reparent_region(
+            RuntimeOrigin::root(),
+            code("EUN"),
+            code("AMER"),
+            1
+        ));
+        assert!(DeeperNode::servers_by_region(code("EURO")).is_empty());
+        assert_eq!(DeeperNode::servers_by_region(code("AMER")), vec![1]);
+
+        // a region with countries under it can not be retired
+        assert_err!(
+            DeeperNode::retire_region(RuntimeOrigin::root(), code("EUN"), 0),
+            Error::<Test>::RegionInUse
+        );
+        // a country with registered devices can not be retired
+        assert_err!(
+            DeeperNode::retire_region(RuntimeOrigin::root(), code("US")),
+            Error::<Test>::CountryInUse
+        );
+        assert_ok!(DeeperNode::register_device_deprecated(
+            RuntimeOrigin::signed(1),
+            vec![1, 2, 3, 4],
+            code("CA")
+        ));
+        assert_eq!(DeeperNode::device_count_by_country(code("US")), 0);
+        assert_eq!(DeeperNode::device_count_by_country(code("CA")), 1);
+        assert!(DeeperNode::servers_by_region(code("EUN")).is_empty());
+        assert!(DeeperNode::servers_by_region(code("AMER")).is_empty());
+
+        assert_ok!(DeeperNode::retire_region(RuntimeOrigin::root(), code("US")));
+        assert!(!DeeperNode::region_tree().contains(&(code("US"), code("EUN"))));
+        assert_eq!(DeeperNode::region_children(code("AMN")), 5);
+
+        assert_ok!(DeeperNode::unregister_device(RuntimeOrigin::signed(1)));
+        assert_eq!(DeeperNode::device_count_by_country(code("CA")), 0);
+    });
+}
+
+#[test]
+fn expired_servers_swept_on_idle() {
+    new_test_ext().execute_with(|| {
+        DeeperNode::setup_region_map();
+        run_to_block(1);
+        for account in 1..=3 {
+            assert_ok!(DeeperNode::register_device_deprecated(
+                RuntimeOrigin::signed(account),
+                vec![1, 2, 3, 4],
+                "US".as_bytes().to_vec()
+            ));
+            assert_ok!(DeeperNode::register_server(
+                RuntimeOrigin::signed(account),
+                1
+            ));
+        }
+        // server 2 leaves the queue, server 3 extends its registration
+        assert_ok!(DeeperNode::unregister_server(RuntimeOrigin::signed(2)));
+        assert_ok!(DeeperNode::update_server(RuntimeOrigin::signed(3), 2));
+        assert_eq!(
+            DeeperNode::server_expiration(1 + BLOCKS_PER_ERA, 1),
+            Some(())
+        );
+        assert_eq!(DeeperNode::server_expiration(1 + BLOCKS_PER_ERA, 3), None);
+
+        // a server is listed through its expire block
+        run_to_block(1 + BLOCKS_PER_ERA);
+        DeeperNode::on_idle(System::block_number(), Weight::MAX);
+        assert_eq!(
+            sorted(DeeperNode::servers_by_country("US".as_bytes().to_vec())),
+            vec![1, 3]
+        );
+
+        run_to_block(2 + BLOCKS_PER_ERA);
+        DeeperNode::on_idle(System::block_number(), Weight::MAX);
+        assert_eq!(
+            DeeperNode::servers_by_country("US".as_bytes().to_vec()),
+            vec![3]
+        );
+        assert_eq!(
+            DeeperNode::servers_by_region("AMN".as_bytes().to_vec()),
+            vec![3]
+        );
+        assert_eq!(DeeperNode::server_expiration(1 + BLOCKS_PER_ERA, 1), None);
+        assert_eq!(
+            DeeperNode::next_server_expiry_to_sweep(),
+            2 + BLOCKS_PER_ERA
+        );
+        System::assert_last_event(RuntimeEvent::DeeperNode(crate::Event::ServerExpired(1)));
+
+        // no weight left, nothing is swept
+        run_to_block(2 + 2 * BLOCKS_PER_ERA);
+        assert_eq!(
+            DeeperNode::on_idle(System::block_number(), Weight::zero()),
+            Weight::zero()
+        );
+        assert_eq!(
+            DeeperNode::servers_by_country("US".as_bytes().to_vec()),
+            vec![3]
+        );
+        DeeperNode::on_idle(System::block_number(), Weight::MAX);
+        assert!(DeeperNode::servers_by_country("US".as_bytes().to_vec()).is_empty());
+    });
+}
+
 #[test]
 fn update_server() {
     new_test_ext().execute_with(|| {
@@ -144,18 +525,158 @@ fn im_online() {
         assert_ok!(DeeperNode::im_online(RuntimeOrigin::signed(1)));
         assert_eq!(DeeperNode::get_im_online(1), Some(0));
         assert_eq!(DeeperNode::onboard_time(1), Some(0));
-        assert_eq!(DeeperNode::devices_onboard(), vec![1]);
+        assert_eq!(DeeperNode::devices_onboard_count(), 1);
         run_to_block(1);
         assert_ok!(DeeperNode::im_online(RuntimeOrigin::signed(1)));
         assert_eq!(DeeperNode::get_im_online(1), Some(1));
         assert_eq!(DeeperNode::onboard_time(1), Some(0));
-        assert_eq!(DeeperNode::devices_onboard(), vec![1]);
+        assert_eq!(DeeperNode::devices_onboard_count(), 1);
         run_to_block(2);
         assert_ok!(DeeperNode::im_online(RuntimeOrigin::signed(2)));
         assert_eq!(DeeperNode::get_im_online(1), Some(1));
         assert_eq!(DeeperNode::get_im_online(2), Some(2));
         assert_eq!(DeeperNode::onboard_time(2), Some(2));
-        assert_eq!(DeeperNode::devices_onboard(), vec![1, 2]);
+        assert_eq!(DeeperNode::devices_onboard_count(), 2);
+        assert!(DevicesOnboard::<Test>::contains_key(2));
+    });
+}
+
+#[test]
+fn device_stats() {
+    new_test_ext().execute_with(|| {
+        DeeperNode::setup_region_map();
+        let us = "US".as_bytes().to_vec();
+        assert_ok!(DeeperNode::register_device_deprecated(
+            RuntimeOrigin::signed(1),
+            vec![1, 2, 3, 4],
+            us.clone()
+        ));
+        assert_ok!(DeeperNode::im_online(RuntimeOrigin::signed(1)));
+        // unregistered devices only count in the era totals
+        assert_ok!(DeeperNode::im_online(RuntimeOrigin::signed(2)));
+        run_to_block(1);
+        assert_ok!(DeeperNode::im_online(RuntimeOrigin::signed(1)));
+        assert_eq!(
+            DeeperNode::era_device_stats(0),
+            DeviceStats {
+                onboarded: 2,
+                active: 2
+            }
+        );
+        assert_eq!(
+            DeeperNode::country_era_device_stats(0, &us),
+            DeviceStats {
+                onboarded: 1,
+                active: 1
+            }
+        );
+
+        run_to_block(BLOCKS_PER_ERA);
+        assert_ok!(DeeperNode::im_online(RuntimeOrigin::signed(1)));
+        assert_eq!(
+            DeeperNode::era_device_stats(1),
+            DeviceStats {
+                onboarded: 0,
+                active: 1
+            }
+        );
+        assert_eq!(
+            DeeperNode::country_era_device_stats(1, &us),
+            DeviceStats {
+                onboarded: 0,
+                active: 1
+            }
+        );
+        assert_eq!(DeeperNode::devices_onboard_count(), 2);
+    });
+}
+
+fn heartbeat_call(account: u64, block: u64, signer: u64) -> crate::Call<Test> {
+    crate::Call::heartbeat {
+        account,
+        block,
+        signature: TestSignature(signer, DeeperNode::heartbeat_payload(&account, block)),
+    }
+}
+
+fn validate(call: &crate::Call<Test>) -> TransactionValidity {
+    DeeperNode::validate_unsigned(TransactionSource::External, call)
+}
+
+#[test]
+fn unsigned_heartbeat() {
+    new_test_ext().execute_with(|| {
+        DeeperNode::setup_region_map();
+        assert_ok!(DeeperNode::register_device_deprecated(
+            RuntimeOrigin::signed(1),
+            vec![1, 2, 3, 4],
+            "US".as_bytes().to_vec()
+        ));
+        run_to_block(1);
+
+        assert_eq!(
+            validate(&heartbeat_call(1, 1, 2)),
+            Err(InvalidTransaction::BadProof.into())
+        );
+        assert_eq!(
+            validate(&heartbeat_call(2, 1, 2)),
+            Err(InvalidTransaction::BadSigner.into())
+        );
+        assert_eq!(
+            validate(&heartbeat_call(1, 2, 1)),
+            Err(InvalidTransaction::Future.into())
+        );
+        // a device never seen online gets the full priority
+        assert_eq!(validate(&heartbeat_call(1, 1, 1)).unwrap().priority, 700);
+
+        assert!(DeeperNode::heartbeat(
+            RuntimeOrigin::signed(1),
+            1,
+            1,
+            TestSignature(1, DeeperNode::heartbeat_payload(&1, 1))
+        )
+        .is_err());
+        assert_ok!(DeeperNode::heartbeat(
+            RuntimeOrigin::none(),
+            1,
+            1,
+            TestSignature(1, DeeperNode::heartbeat_payload(&1, 1))
+        ));
+        assert_eq!(DeeperNode::get_im_online(1), Some(1));
+        assert_eq!(DeeperNode::onboard_time(1), Some(1));
+        assert_eq!(DeeperNode::devices_onboard_count(), 1);
+
+        // one heartbeat per era
+        assert_eq!(
+            validate(&heartbeat_call(1, 1, 1)),
+            Err(InvalidTransaction::Stale.into())
+        );
+        run_to_block(BLOCKS_PER_ERA);
+        assert_eq!(
+            validate(&heartbeat_call(1, 1, 1)),
+            Err(InvalidTransaction::Stale.into())
+        );
+        // the priority grows with the eras since the last heartbeat
+        assert_eq!(
+            validate(&heartbeat_call(1, BLOCKS_PER_ERA, 1))
+                .unwrap()
+                .priority,
+            100
+        );
+        run_to_block(3 * BLOCKS_PER_ERA);
+        assert_eq!(
+            validate(&heartbeat_call(1, 3 * BLOCKS_PER_ERA, 1))
+                .unwrap()
+                .priority,
+            300
+        );
+
+        // a signed im_online counts for the era too
+        assert_ok!(DeeperNode::im_online(RuntimeOrigin::signed(1)));
+        assert_eq!(
+            validate(&heartbeat_call(1, 3 * BLOCKS_PER_ERA, 1)),
+            Err(InvalidTransaction::Stale.into())
+        );
     });
 }
 
@@ -165,6 +686,7 @@ fn report_credit_proof() {
         assert_ok!(DeeperNode::report_credit_proof(
             RuntimeOrigin::signed(1),
             0,
+            100,
             Vec::new(),
             1655007560,
             1073741824000000,
@@ -174,36 +696,219 @@ fn report_credit_proof() {
             DeeperNode::device_credit_proof(1),
             (1655007560, 1073741824000000, 4294967295)
         );
+
+        // proofs are reported in order, once per day
+        assert_err!(
+            DeeperNode::report_credit_proof(
+                RuntimeOrigin::signed(1),
+                1,
+                100,
+                Vec::new(),
+                1655007560,
+                1,
+                1
+            ),
+            Error::<Test>::StaleCreditProof
+        );
+        assert_err!(
+            DeeperNode::report_credit_proof(
+                RuntimeOrigin::signed(1),
+                1,
+                100,
+                Vec::new(),
+                1655007560 + 3600,
+                1,
+                1
+            ),
+            Error::<Test>::CreditProofAlreadyReported
+        );
+        assert_ok!(DeeperNode::report_credit_proof(
+            RuntimeOrigin::signed(1),
+            1,
+            100,
+            Vec::new(),
+            1655007560 + 86_400,
+            1,
+            1
+        ));
     });
 }
 
+#[test]
+fn reliability_score() {
+    new_test_ext().execute_with(|| {
+        const DAY: u32 = 86_400;
+        let report = |account: u64, day: u32, traffic: u64, uptime: u32| {
+            assert_ok!(DeeperNode::report_credit_proof(
+                RuntimeOrigin::signed(account),
+                0,
+                100,
+                Vec::new(),
+                1655000000 + day * DAY,
+                traffic,
+                uptime
+            ));
+        };
+        assert_eq!(DeeperNode::get_reliability_score(&1), Perbill::zero());
+
+        // the history keeps the last 4 proofs
+        report(1, 0, 1, 0);
+        report(1, 1, 100, DAY);
+        report(1, 2, 100, DAY / 2);
+        report(1, 3, 100, DAY);
+        report(1, 4, 100, DAY);
+        assert_eq!(DeeperNode::credit_proof_history(1).len(), 4);
+        assert_eq!(DeeperNode::credit_proof_history(1)[0].0, 1655000000 + DAY);
+        assert_eq!(
+            DeeperNode::device_credit_proof(1),
+            (1655000000 + 4 * DAY, 100, DAY)
+        );
+        // up 87.5% of the days with a steady traffic
+        assert_eq!(
+            DeeperNode::get_reliability_score(&1),
+            Perbill::from_parts(912_500_000)
+        );
+
+        // the traffic deviates by half of its mean
+        report(2, 0, 100, DAY);
+        report(2, 1, 300, DAY);
+        assert_eq!(
+            DeeperNode::get_reliability_score(&2),
+            Perbill::from_percent(85)
+        );
+
+        // days without a proof count as down
+        report(3, 0, 100, DAY);
+        report(3, 3, 100, DAY);
+        assert_eq!(
+            DeeperNode::get_reliability_score(&3),
+            Perbill::from_percent(65)
+        );
+    });
+}
+
+/// Eth key seeded with `seed` and its eth address
+fn eth_pair(seed: u8) -> (ecdsa::Pair, H160) {
+    let pair = ecdsa::Pair::from_seed(&[seed; 32]);
+    let hash = [seed; 32];
+    let signature = pair.sign_prehashed(&hash).0;
+    // the recovered key is the pair's key, so the recovered address is the pair's address
+    assert_eq!(
+        secp256k1_ecdsa_recover_compressed(&signature, &hash),
+        Ok(pair.public().0)
+    );
+    (pair, DeeperNode::eth_recover(&signature, &hash).unwrap())
+}
+
+/// Signature of `pair` binding `account` at its current mapping nonce
+fn eth_sign(pair: &ecdsa::Pair, account: u64) -> [u8; 65] {
+    let nonce = DeeperNode::reward_mapping_nonce(&account);
+    pair.sign_prehashed(&DeeperNode::reward_mapping_hash(&account, nonce))
+        .0
+}
+
 #[test]
 fn reward_mapping() {
     new_test_ext().execute_with(|| {
-        let evm_address = H160::from_str("1000000000000000000000000000000000000001").unwrap();
+        let (pair, evm_address) = eth_pair(1);
         assert_ok!(DeeperNode::reward_mapping(
             RuntimeOrigin::signed(1),
             0,
+            100,
             Vec::new(),
-            evm_address
+            evm_address,
+            eth_sign(&pair, 1)
         ));
         assert_eq!(
             DeeperNode::rewards_accounts_deeper_evm(&1),
             Some(evm_address)
         );
+        assert_eq!(
+            DeeperNode::rewards_accounts_evm_deeper(evm_address),
+            Some(1)
+        );
+        assert_eq!(DeeperNode::reward_mapping_nonce(&1), 1);
+    });
+}
+
+#[test]
+fn reward_mapping_requires_eth_signature() {
+    new_test_ext().execute_with(|| {
+        let (pair, evm_address) = eth_pair(1);
+        let (other_pair, _) = eth_pair(2);
+        // signed by another key
+        assert_err!(
+            DeeperNode::reward_mapping(
+                RuntimeOrigin::signed(1),
+                0,
+                100,
+                Vec::new(),
+                evm_address,
+                eth_sign(&other_pair, 1)
+            ),
+            Error::<Test>::EthSignatureVerifyFailed
+        );
+        // signed for another deeper account
+        assert_err!(
+            DeeperNode::reward_mapping(
+                RuntimeOrigin::signed(2),
+                0,
+                100,
+                Vec::new(),
+                evm_address,
+                eth_sign(&pair, 1)
+            ),
+            Error::<Test>::EthSignatureVerifyFailed
+        );
+        assert_err!(
+            DeeperNode::reward_mapping(
+                RuntimeOrigin::signed(1),
+                0,
+                100,
+                Vec::new(),
+                evm_address,
+                [0u8; 65]
+            ),
+            Error::<Test>::EthSignatureVerifyFailed
+        );
+
+        // a signature can not be replayed once the nonce is used
+        let signature = eth_sign(&pair, 1);
+        assert_ok!(DeeperNode::reward_mapping(
+            RuntimeOrigin::signed(1),
+            0,
+            100,
+            Vec::new(),
+            evm_address,
+            signature
+        ));
+        assert_ok!(DeeperNode::unbind_reward_mapping(RuntimeOrigin::signed(1)));
+        assert_err!(
+            DeeperNode::reward_mapping(
+                RuntimeOrigin::signed(1),
+                0,
+                100,
+                Vec::new(),
+                evm_address,
+                signature
+            ),
+            Error::<Test>::EthSignatureVerifyFailed
+        );
     });
 }
 
 #[test]
 fn reward_mapping_switch_evm_address() {
     new_test_ext().execute_with(|| {
-        let evm_old_address = H160::from_str("1000000000000000000000000000000000000001").unwrap();
-        let evm_new_address = H160::from_str("1000000000000000000000000000000000000002").unwrap();
+        let (old_pair, evm_old_address) = eth_pair(1);
+        let (new_pair, evm_new_address) = eth_pair(2);
         assert_ok!(DeeperNode::reward_mapping(
             RuntimeOrigin::signed(1),
             0,
+            100,
             Vec::new(),
-            evm_old_address
+            evm_old_address,
+            eth_sign(&old_pair, 1)
         ));
         assert_eq!(
             DeeperNode::rewards_accounts_deeper_evm(1),
@@ -213,25 +918,50 @@ fn reward_mapping_switch_evm_address() {
         assert_ok!(DeeperNode::reward_mapping(
             RuntimeOrigin::signed(1),
             0,
+            100,
             Vec::new(),
-            evm_new_address
+            evm_new_address,
+            eth_sign(&new_pair, 1)
         ));
         assert_eq!(
             DeeperNode::rewards_accounts_deeper_evm(&1),
             Some(evm_new_address)
         );
+        assert_eq!(
+            DeeperNode::rewards_accounts_evm_deeper(evm_new_address),
+            Some(1)
+        );
+        // the old address is released and can be bound by another account
+        assert_eq!(
+            DeeperNode::rewards_accounts_evm_deeper(evm_old_address),
+            None
+        );
+        assert_ok!(DeeperNode::reward_mapping(
+            RuntimeOrigin::signed(2),
+            0,
+            100,
+            Vec::new(),
+            evm_old_address,
+            eth_sign(&old_pair, 2)
+        ));
+        assert_eq!(
+            DeeperNode::rewards_accounts_evm_deeper(evm_old_address),
+            Some(2)
+        );
     });
 }
 
 #[test]
 fn reward_mapping_with_already_mapped_evm_address() {
     new_test_ext().execute_with(|| {
-        let evm_address = H160::from_str("1000000000000000000000000000000000000001").unwrap();
+        let (pair, evm_address) = eth_pair(1);
         assert_ok!(DeeperNode::reward_mapping(
             RuntimeOrigin::signed(1),
             0,
+            100,
             Vec::new(),
-            evm_address
+            evm_address,
+            eth_sign(&pair, 1)
         ));
         assert_eq!(
             DeeperNode::rewards_accounts_deeper_evm(&1),
@@ -239,12 +969,46 @@ fn reward_mapping_with_already_mapped_evm_address() {
         );
 
         assert_err!(
-            DeeperNode::reward_mapping(RuntimeOrigin::signed(2), 0, Vec::new(), evm_address),
+            DeeperNode::reward_mapping(
+                RuntimeOrigin::signed(2),
+                0,
+                100,
+                Vec::new(),
+                evm_address,
+                eth_sign(&pair, 2)
+            ),
             Error::<Test>::EthAddressAlreadyMapped
         );
     });
 }
 
+#[test]
+fn unbind_reward_mapping() {
+    new_test_ext().execute_with(|| {
+        assert_err!(
+            DeeperNode::unbind_reward_mapping(RuntimeOrigin::signed(1)),
+            Error::<Test>::NotBound
+        );
+
+        let (pair, evm_address) = eth_pair(1);
+        assert_ok!(DeeperNode::reward_mapping(
+            RuntimeOrigin::signed(1),
+            0,
+            100,
+            Vec::new(),
+            evm_address,
+            eth_sign(&pair, 1)
+        ));
+        assert_ok!(DeeperNode::unbind_reward_mapping(RuntimeOrigin::signed(1)));
+        assert_eq!(DeeperNode::rewards_accounts_deeper_evm(&1), None);
+        assert_eq!(DeeperNode::rewards_accounts_evm_deeper(evm_address), None);
+        assert_err!(
+            DeeperNode::get_npow_reward(RuntimeOrigin::signed(1)),
+            Error::<Test>::NpowRewardAddressNotFound
+        );
+    });
+}
+
 #[test]
 fn get_onboard_time() {
     new_test_ext().execute_with(|| {
@@ -286,11 +1050,14 @@ fn get_npow_reward() {
             Error::<Test>::NpowRewardAddressNotFound
         );
 
+        let (pair, evm_address) = eth_pair(1);
         assert_ok!(DeeperNode::reward_mapping(
             RuntimeOrigin::signed(1),
             0,
+            100,
             Vec::new(),
-            H160::zero(),
+            evm_address,
+            eth_sign(&pair, 1),
         ));
         assert_ok!(DeeperNode::get_npow_reward(RuntimeOrigin::signed(1)));
         assert_eq!(
@@ -298,7 +1065,36 @@ fn get_npow_reward() {
                 .pop()
                 .expect("should contains events")
                 .event,
-            crate::tests::RuntimeEvent::from(crate::Event::GetNpowReward(1, H160::zero()))
+            crate::tests::RuntimeEvent::from(crate::Event::GetNpowReward(1, evm_address))
         );
     });
 }
+
+#[test]
+fn server_reputation() {
+    new_test_ext().execute_with(|| {
+        // unrated servers have the neutral prior
+        assert_eq!(DeeperNode::get_reputation(&1), Perbill::from_percent(50));
+
+        DeeperNode::rate_server(&1, MAX_RATING, 1);
+        assert_eq!(DeeperNode::get_reputation(&1), Perbill::from_percent(75));
+        DeeperNode::rate_server(&2, 1, 1);
+        assert_eq!(DeeperNode::get_reputation(&2), Perbill::from_percent(25));
+
+        // ratings count by their weight
+        DeeperNode::rate_server(&3, MAX_RATING, 1);
+        DeeperNode::rate_server(&3, 1, 3);
+        assert_eq!(DeeperNode::get_reputation(&3), Perbill::from_percent(30));
+
+        // old ratings fade back to the prior
+        run_to_block(10 * BLOCKS_PER_ERA);
+        let faded = DeeperNode::get_reputation(&1);
+        assert!(faded < Perbill::from_percent(75));
+        assert!(faded > Perbill::from_percent(50));
+
+        // a new rating outweighs the decayed ones
+        DeeperNode::rate_server(&1, 1, 1);
+        assert!(DeeperNode::get_reputation(&1) < Perbill::from_percent(50));
+        assert_eq!(DeeperNode::server_ratings(&1).era, 10);
+    });
+}
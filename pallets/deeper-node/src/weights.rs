@@ -43,6 +43,13 @@ pub trait WeightInfo {
 	fn report_credit_proof() -> Weight;
 	fn reward_mapping() -> Weight;
 	fn get_npow_reward() -> Weight;
+	fn insert_region() -> Weight;
+	fn reparent_region(s: u32, ) -> Weight;
+	fn retire_region() -> Weight;
+	fn expire_server() -> Weight;
+	fn heartbeat() -> Weight;
+	fn unbind_reward_mapping() -> Weight;
+	fn set_server_endpoint() -> Weight;
 }
 
 /// Weights for `pallet_deeper_node` using the Substrate node and recommended hardware.
@@ -56,14 +63,18 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	/// Proof: `DeeperNode::DeviceInfo` (`max_values`: None, `max_size`: None, mode: `Measured`)
 	/// Storage: `System::Account` (r:1 w:1)
 	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// Storage: `DeeperNode::ServerEndpoints` (r:1 w:1)
+	/// Proof: `DeeperNode::ServerEndpoints` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `DeeperNode::DeviceCountByCountry` (r:1 w:1)
+	/// Proof: `DeeperNode::DeviceCountByCountry` (`max_values`: None, `max_size`: None, mode: `Measured`)
 	fn register_device_deprecated() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `1234`
 		//  Estimated: `4699`
-		// Minimum execution time: 737_830_000 picoseconds.
-		Weight::from_parts(747_198_000, 4699)
-			.saturating_add(T::DbWeight::get().reads(4_u64))
-			.saturating_add(T::DbWeight::get().writes(2_u64))
+		// Minimum execution time: 768_412_000 picoseconds.
+		Weight::from_parts(779_305_000, 4699)
+			.saturating_add(T::DbWeight::get().reads(6_u64))
+			.saturating_add(T::DbWeight::get().writes(4_u64))
 	}
 	/// Storage: `DeeperNode::DeviceInfo` (r:1 w:1)
 	/// Proof: `DeeperNode::DeviceInfo` (`max_values`: None, `max_size`: None, mode: `Measured`)
@@ -75,14 +86,18 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	/// Proof: `DeeperNode::ServersByRegion` (`max_values`: None, `max_size`: None, mode: `Measured`)
 	/// Storage: `System::Account` (r:1 w:1)
 	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// Storage: `DeeperNode::ServerEndpoints` (r:0 w:1)
+	/// Proof: `DeeperNode::ServerEndpoints` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `DeeperNode::DeviceCountByCountry` (r:1 w:1)
+	/// Proof: `DeeperNode::DeviceCountByCountry` (`max_values`: None, `max_size`: None, mode: `Measured`)
 	fn unregister_device() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `1557`
 		//  Estimated: `7497`
-		// Minimum execution time: 994_991_000 picoseconds.
-		Weight::from_parts(1_003_989_000, 7497)
-			.saturating_add(T::DbWeight::get().reads(7_u64))
-			.saturating_add(T::DbWeight::get().writes(2_u64))
+		// Minimum execution time: 1_012_406_000 picoseconds.
+		Weight::from_parts(1_021_337_000, 7497)
+			.saturating_add(T::DbWeight::get().reads(8_u64))
+			.saturating_add(T::DbWeight::get().writes(4_u64))
 	}
 	/// Storage: `DeeperNode::DeviceInfo` (r:1 w:1)
 	/// Proof: `DeeperNode::DeviceInfo` (`max_values`: None, `max_size`: None, mode: `Measured`)
@@ -92,14 +107,20 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	/// Proof: `DeeperNode::ServersByCountry` (`max_values`: None, `max_size`: None, mode: `Measured`)
 	/// Storage: `DeeperNode::ServersByRegion` (r:2 w:2)
 	/// Proof: `DeeperNode::ServersByRegion` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `DeeperNode::ServerCountByCountry` (r:1 w:1)
+	/// Proof: `DeeperNode::ServerCountByCountry` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `DeeperNode::ServerCountByRegion` (r:2 w:2)
+	/// Proof: `DeeperNode::ServerCountByRegion` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `DeeperNode::ServerExpirations` (r:0 w:2)
+	/// Proof: `DeeperNode::ServerExpirations` (`max_values`: None, `max_size`: None, mode: `Measured`)
 	fn register_server() -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `1417`
-		//  Estimated: `7357`
-		// Minimum execution time: 905_100_000 picoseconds.
-		Weight::from_parts(921_932_000, 7357)
-			.saturating_add(T::DbWeight::get().reads(6_u64))
-			.saturating_add(T::DbWeight::get().writes(4_u64))
+		//  Measured:  `1609`
+		//  Estimated: `8521`
+		// Minimum execution time: 1_131_508_000 picoseconds.
+		Weight::from_parts(1_148_260_000, 8521)
+			.saturating_add(T::DbWeight::get().reads(9_u64))
+			.saturating_add(T::DbWeight::get().writes(9_u64))
 	}
 	/// Storage: `DeeperNode::DeviceInfo` (r:1 w:1)
 	/// Proof: `DeeperNode::DeviceInfo` (`max_values`: None, `max_size`: None, mode: `Measured`)
@@ -120,59 +141,81 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	/// Proof: `DeeperNode::ServersByCountry` (`max_values`: None, `max_size`: None, mode: `Measured`)
 	/// Storage: `DeeperNode::ServersByRegion` (r:2 w:2)
 	/// Proof: `DeeperNode::ServersByRegion` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `DeeperNode::ServerCountByCountry` (r:1 w:1)
+	/// Proof: `DeeperNode::ServerCountByCountry` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `DeeperNode::ServerCountByRegion` (r:2 w:2)
+	/// Proof: `DeeperNode::ServerCountByRegion` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `DeeperNode::ServerExpirations` (r:0 w:1)
+	/// Proof: `DeeperNode::ServerExpirations` (`max_values`: None, `max_size`: None, mode: `Measured`)
 	fn unregister_server() -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `1632`
-		//  Estimated: `7572`
-		// Minimum execution time: 996_966_000 picoseconds.
-		Weight::from_parts(1_006_954_000, 7572)
-			.saturating_add(T::DbWeight::get().reads(6_u64))
-			.saturating_add(T::DbWeight::get().writes(4_u64))
+		//  Measured:  `1824`
+		//  Estimated: `8736`
+		// Minimum execution time: 1_197_380_000 picoseconds.
+		Weight::from_parts(1_214_705_000, 8736)
+			.saturating_add(T::DbWeight::get().reads(9_u64))
+			.saturating_add(T::DbWeight::get().writes(8_u64))
 	}
+	/// Storage: `DeeperNode::DeviceInfo` (r:1 w:0)
+	/// Proof: `DeeperNode::DeviceInfo` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `DeeperNode::ImOnline` (r:1 w:1)
+	/// Proof: `DeeperNode::ImOnline` (`max_values`: None, `max_size`: None, mode: `Measured`)
 	/// Storage: `DeeperNode::OnboardTime` (r:1 w:1)
 	/// Proof: `DeeperNode::OnboardTime` (`max_values`: None, `max_size`: None, mode: `Measured`)
-	/// Storage: `DeeperNode::DevicesOnboard` (r:1 w:1)
-	/// Proof: `DeeperNode::DevicesOnboard` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
-	/// Storage: `DeeperNode::ImOnline` (r:0 w:1)
-	/// Proof: `DeeperNode::ImOnline` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `DeeperNode::CounterForDevicesOnboard` (r:1 w:1)
+	/// Proof: `DeeperNode::CounterForDevicesOnboard` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `DeeperNode::EraDeviceStats` (r:1 w:1)
+	/// Proof: `DeeperNode::EraDeviceStats` (`max_values`: None, `max_size`: Some(24), added: 2499, mode: `MaxEncodedLen`)
+	/// Storage: `DeeperNode::CountryEraDeviceStats` (r:1 w:1)
+	/// Proof: `DeeperNode::CountryEraDeviceStats` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `DeeperNode::DevicesOnboard` (r:0 w:1)
+	/// Proof: `DeeperNode::DevicesOnboard` (`max_values`: None, `max_size`: None, mode: `Measured`)
 	fn im_online() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `334`
 		//  Estimated: `3799`
-		// Minimum execution time: 366_190_000 picoseconds.
-		Weight::from_parts(371_360_000, 3799)
-			.saturating_add(T::DbWeight::get().reads(2_u64))
-			.saturating_add(T::DbWeight::get().writes(3_u64))
+		// Minimum execution time: 498_613_000 picoseconds.
+		Weight::from_parts(507_229_000, 3799)
+			.saturating_add(T::DbWeight::get().reads(6_u64))
+			.saturating_add(T::DbWeight::get().writes(7_u64))
 	}
-	/// Storage: `CreditAccumulation::AtmosAccountid` (r:1 w:0)
-	/// Proof: `CreditAccumulation::AtmosAccountid` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
-	/// Storage: `CreditAccumulation::TmpAtmosAccountid` (r:1 w:0)
-	/// Proof: `CreditAccumulation::TmpAtmosAccountid` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
-	/// Storage: `DeeperNode::DeviceCreditProof` (r:0 w:1)
+	/// Storage: `CreditAccumulation::AtmosSigners` (r:1 w:0)
+	/// Proof: `CreditAccumulation::AtmosSigners` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// Storage: `CreditAccumulation::AtmosNonceSince` (r:1 w:1)
+	/// Proof: `CreditAccumulation::AtmosNonceSince` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `CreditAccumulation::AtmosNonce` (r:1 w:1)
+	/// Proof: `CreditAccumulation::AtmosNonce` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `DeeperNode::CreditProofHistory` (r:1 w:1)
+	/// Proof: `DeeperNode::CreditProofHistory` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `DeeperNode::DeviceCreditProof` (r:1 w:1)
 	/// Proof: `DeeperNode::DeviceCreditProof` (`max_values`: None, `max_size`: None, mode: `Measured`)
 	fn report_credit_proof() -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `165`
-		//  Estimated: `1650`
-		// Minimum execution time: 399_182_000 picoseconds.
-		Weight::from_parts(405_224_000, 1650)
-			.saturating_add(T::DbWeight::get().reads(2_u64))
-			.saturating_add(T::DbWeight::get().writes(1_u64))
+		//  Measured:  `725`
+		//  Estimated: `4190`
+		// Minimum execution time: 451_903_000 picoseconds.
+		Weight::from_parts(460_218_000, 4190)
+			.saturating_add(T::DbWeight::get().reads(5_u64))
+			.saturating_add(T::DbWeight::get().writes(4_u64))
 	}
-	/// Storage: `CreditAccumulation::AtmosAccountid` (r:1 w:0)
-	/// Proof: `CreditAccumulation::AtmosAccountid` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
-	/// Storage: `DeeperNode::RewardsAccountsEVMtoDeeper` (r:1 w:1)
+	/// Storage: `CreditAccumulation::AtmosSigners` (r:1 w:0)
+	/// Proof: `CreditAccumulation::AtmosSigners` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// Storage: `CreditAccumulation::AtmosNonceSince` (r:1 w:0)
+	/// Proof: `CreditAccumulation::AtmosNonceSince` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `DeeperNode::RewardMappingNonce` (r:1 w:1)
+	/// Proof: `DeeperNode::RewardMappingNonce` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `DeeperNode::RewardsAccountsEVMtoDeeper` (r:1 w:2)
 	/// Proof: `DeeperNode::RewardsAccountsEVMtoDeeper` (`max_values`: None, `max_size`: None, mode: `Measured`)
 	/// Storage: `DeeperNode::RewardsAccountsDeepertoEVM` (r:1 w:1)
 	/// Proof: `DeeperNode::RewardsAccountsDeepertoEVM` (`max_values`: None, `max_size`: None, mode: `Measured`)
 	fn reward_mapping() -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `692`
-		//  Estimated: `4157`
-		// Minimum execution time: 599_666_000 picoseconds.
-		Weight::from_parts(609_685_000, 4157)
-			.saturating_add(T::DbWeight::get().reads(3_u64))
-			.saturating_add(T::DbWeight::get().writes(2_u64))
+		//  Measured:  `728`
+		//  Estimated: `4193`
+		// Minimum execution time: 671_204_000 picoseconds.
+		Weight::from_parts(683_517_000, 4193)
+			.saturating_add(T::DbWeight::get().reads(5_u64))
+			.saturating_add(T::DbWeight::get().writes(4_u64))
 	}
 	/// Storage: `DeeperNode::RewardsAccountsDeepertoEVM` (r:1 w:0)
 	/// Proof: `DeeperNode::RewardsAccountsDeepertoEVM` (`max_values`: None, `max_size`: None, mode: `Measured`)
@@ -184,6 +227,129 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 		Weight::from_parts(270_788_000, 3830)
 			.saturating_add(T::DbWeight::get().reads(1_u64))
 	}
+	/// Storage: `DeeperNode::RegionMap` (r:3 w:1)
+	/// Proof: `DeeperNode::RegionMap` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `DeeperNode::RegionChildren` (r:1 w:1)
+	/// Proof: `DeeperNode::RegionChildren` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	fn insert_region() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1143`
+		//  Estimated: `9408`
+		// Minimum execution time: 58_340_000 picoseconds.
+		Weight::from_parts(59_762_000, 9408)
+			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+	/// Storage: `DeeperNode::RegionMap` (r:3 w:1)
+	/// Proof: `DeeperNode::RegionMap` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `DeeperNode::ServerCountByCountry` (r:1 w:0)
+	/// Proof: `DeeperNode::ServerCountByCountry` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `DeeperNode::ServersByCountry` (r:1001 w:0)
+	/// Proof: `DeeperNode::ServersByCountry` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `DeeperNode::ServersByRegion` (r:2000 w:2000)
+	/// Proof: `DeeperNode::ServersByRegion` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `DeeperNode::ServerCountByRegion` (r:2000 w:2000)
+	/// Proof: `DeeperNode::ServerCountByRegion` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `DeeperNode::RegionChildren` (r:3 w:2)
+	/// Proof: `DeeperNode::RegionChildren` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// The range of component `s` is `[0, 1000]`.
+	fn reparent_region(s: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1052 + s * (96 ±0)`
+		//  Estimated: `11942 + s * (2571 ±0)`
+		// Minimum execution time: 421_516_000 picoseconds.
+		Weight::from_parts(433_105_000, 11942)
+			// Standard Error: 52_317
+			.saturating_add(Weight::from_parts(178_406_312, 0).saturating_mul(s.into()))
+			.saturating_add(T::DbWeight::get().reads(8_u64))
+			.saturating_add(T::DbWeight::get().reads((3_u64).saturating_mul(s.into())))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+			.saturating_add(T::DbWeight::get().writes((2_u64).saturating_mul(s.into())))
+			.saturating_add(Weight::from_parts(0, 2571).saturating_mul(s.into()))
+	}
+	/// Storage: `DeeperNode::RegionMap` (r:1 w:1)
+	/// Proof: `DeeperNode::RegionMap` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `DeeperNode::RegionChildren` (r:2 w:1)
+	/// Proof: `DeeperNode::RegionChildren` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `DeeperNode::DeviceCountByCountry` (r:1 w:0)
+	/// Proof: `DeeperNode::DeviceCountByCountry` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	fn retire_region() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1187`
+		//  Estimated: `7127`
+		// Minimum execution time: 61_915_000 picoseconds.
+		Weight::from_parts(63_208_000, 7127)
+			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+	/// Storage: `DeeperNode::DeviceInfo` (r:1 w:1)
+	/// Proof: `DeeperNode::DeviceInfo` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `DeeperNode::RegionMap` (r:2 w:0)
+	/// Proof: `DeeperNode::RegionMap` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `DeeperNode::ServersByCountry` (r:1 w:1)
+	/// Proof: `DeeperNode::ServersByCountry` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `DeeperNode::ServersByRegion` (r:2 w:2)
+	/// Proof: `DeeperNode::ServersByRegion` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `DeeperNode::ServerExpirations` (r:0 w:1)
+	/// Proof: `DeeperNode::ServerExpirations` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	fn expire_server() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `790`
+		//  Estimated: `6730`
+		// Minimum execution time: 351_204_000 picoseconds.
+		Weight::from_parts(358_962_000, 6730)
+			.saturating_add(T::DbWeight::get().reads(6_u64))
+			.saturating_add(T::DbWeight::get().writes(5_u64))
+	}
+	/// Storage: `DeeperNode::DeviceInfo` (r:1 w:0)
+	/// Proof: `DeeperNode::DeviceInfo` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `DeeperNode::ImOnline` (r:1 w:1)
+	/// Proof: `DeeperNode::ImOnline` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `DeeperNode::OnboardTime` (r:1 w:1)
+	/// Proof: `DeeperNode::OnboardTime` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `DeeperNode::CounterForDevicesOnboard` (r:1 w:1)
+	/// Proof: `DeeperNode::CounterForDevicesOnboard` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `DeeperNode::EraDeviceStats` (r:1 w:1)
+	/// Proof: `DeeperNode::EraDeviceStats` (`max_values`: None, `max_size`: Some(24), added: 2499, mode: `MaxEncodedLen`)
+	/// Storage: `DeeperNode::CountryEraDeviceStats` (r:1 w:1)
+	/// Proof: `DeeperNode::CountryEraDeviceStats` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `DeeperNode::DevicesOnboard` (r:0 w:1)
+	/// Proof: `DeeperNode::DevicesOnboard` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	fn heartbeat() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `334`
+		//  Estimated: `3799`
+		// Minimum execution time: 484_930_000 picoseconds.
+		Weight::from_parts(493_472_000, 3799)
+			.saturating_add(T::DbWeight::get().reads(6_u64))
+			.saturating_add(T::DbWeight::get().writes(7_u64))
+	}
+	/// Storage: `DeeperNode::RewardsAccountsDeepertoEVM` (r:1 w:1)
+	/// Proof: `DeeperNode::RewardsAccountsDeepertoEVM` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `DeeperNode::RewardsAccountsEVMtoDeeper` (r:0 w:1)
+	/// Proof: `DeeperNode::RewardsAccountsEVMtoDeeper` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	fn unbind_reward_mapping() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `401`
+		//  Estimated: `3866`
+		// Minimum execution time: 301_552_000 picoseconds.
+		Weight::from_parts(307_914_000, 3866)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+	/// Storage: `DeeperNode::DeviceInfo` (r:1 w:0)
+	/// Proof: `DeeperNode::DeviceInfo` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `DeeperNode::ServerEndpoints` (r:0 w:1)
+	/// Proof: `DeeperNode::ServerEndpoints` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	fn set_server_endpoint() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `412`
+		//  Estimated: `3877`
+		// Minimum execution time: 318_760_000 picoseconds.
+		Weight::from_parts(326_095_000, 3877)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
 }
 
 // For backwards compatibility and tests.
@@ -196,14 +362,18 @@ impl WeightInfo for () {
 	/// Proof: `DeeperNode::DeviceInfo` (`max_values`: None, `max_size`: None, mode: `Measured`)
 	/// Storage: `System::Account` (r:1 w:1)
 	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// Storage: `DeeperNode::ServerEndpoints` (r:1 w:1)
+	/// Proof: `DeeperNode::ServerEndpoints` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `DeeperNode::DeviceCountByCountry` (r:1 w:1)
+	/// Proof: `DeeperNode::DeviceCountByCountry` (`max_values`: None, `max_size`: None, mode: `Measured`)
 	fn register_device_deprecated() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `1234`
 		//  Estimated: `4699`
-		// Minimum execution time: 737_830_000 picoseconds.
-		Weight::from_parts(747_198_000, 4699)
-			.saturating_add(RocksDbWeight::get().reads(4_u64))
-			.saturating_add(RocksDbWeight::get().writes(2_u64))
+		// Minimum execution time: 768_412_000 picoseconds.
+		Weight::from_parts(779_305_000, 4699)
+			.saturating_add(RocksDbWeight::get().reads(6_u64))
+			.saturating_add(RocksDbWeight::get().writes(4_u64))
 	}
 	/// Storage: `DeeperNode::DeviceInfo` (r:1 w:1)
 	/// Proof: `DeeperNode::DeviceInfo` (`max_values`: None, `max_size`: None, mode: `Measured`)
@@ -215,14 +385,18 @@ impl WeightInfo for () {
 	/// Proof: `DeeperNode::ServersByRegion` (`max_values`: None, `max_size`: None, mode: `Measured`)
 	/// Storage: `System::Account` (r:1 w:1)
 	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// Storage: `DeeperNode::ServerEndpoints` (r:0 w:1)
+	/// Proof: `DeeperNode::ServerEndpoints` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `DeeperNode::DeviceCountByCountry` (r:1 w:1)
+	/// Proof: `DeeperNode::DeviceCountByCountry` (`max_values`: None, `max_size`: None, mode: `Measured`)
 	fn unregister_device() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `1557`
 		//  Estimated: `7497`
-		// Minimum execution time: 994_991_000 picoseconds.
-		Weight::from_parts(1_003_989_000, 7497)
-			.saturating_add(RocksDbWeight::get().reads(7_u64))
-			.saturating_add(RocksDbWeight::get().writes(2_u64))
+		// Minimum execution time: 1_012_406_000 picoseconds.
+		Weight::from_parts(1_021_337_000, 7497)
+			.saturating_add(RocksDbWeight::get().reads(8_u64))
+			.saturating_add(RocksDbWeight::get().writes(4_u64))
 	}
 	/// Storage: `DeeperNode::DeviceInfo` (r:1 w:1)
 	/// Proof: `DeeperNode::DeviceInfo` (`max_values`: None, `max_size`: None, mode: `Measured`)
@@ -232,14 +406,20 @@ impl WeightInfo for () {
 	/// Proof: `DeeperNode::ServersByCountry` (`max_values`: None, `max_size`: None, mode: `Measured`)
 	/// Storage: `DeeperNode::ServersByRegion` (r:2 w:2)
 	/// Proof: `DeeperNode::ServersByRegion` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `DeeperNode::ServerCountByCountry` (r:1 w:1)
+	/// Proof: `DeeperNode::ServerCountByCountry` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `DeeperNode::ServerCountByRegion` (r:2 w:2)
+	/// Proof: `DeeperNode::ServerCountByRegion` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `DeeperNode::ServerExpirations` (r:0 w:2)
+	/// Proof: `DeeperNode::ServerExpirations` (`max_values`: None, `max_size`: None, mode: `Measured`)
 	fn register_server() -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `1417`
-		//  Estimated: `7357`
-		// Minimum execution time: 905_100_000 picoseconds.
-		Weight::from_parts(921_932_000, 7357)
-			.saturating_add(RocksDbWeight::get().reads(6_u64))
-			.saturating_add(RocksDbWeight::get().writes(4_u64))
+		//  Measured:  `1609`
+		//  Estimated: `8521`
+		// Minimum execution time: 1_131_508_000 picoseconds.
+		Weight::from_parts(1_148_260_000, 8521)
+			.saturating_add(RocksDbWeight::get().reads(9_u64))
+			.saturating_add(RocksDbWeight::get().writes(9_u64))
 	}
 	/// Storage: `DeeperNode::DeviceInfo` (r:1 w:1)
 	/// Proof: `DeeperNode::DeviceInfo` (`max_values`: None, `max_size`: None, mode: `Measured`)
@@ -260,59 +440,81 @@ impl WeightInfo for () {
 	/// Proof: `DeeperNode::ServersByCountry` (`max_values`: None, `max_size`: None, mode: `Measured`)
 	/// Storage: `DeeperNode::ServersByRegion` (r:2 w:2)
 	/// Proof: `DeeperNode::ServersByRegion` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `DeeperNode::ServerCountByCountry` (r:1 w:1)
+	/// Proof: `DeeperNode::ServerCountByCountry` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `DeeperNode::ServerCountByRegion` (r:2 w:2)
+	/// Proof: `DeeperNode::ServerCountByRegion` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `DeeperNode::ServerExpirations` (r:0 w:1)
+	/// Proof: `DeeperNode::ServerExpirations` (`max_values`: None, `max_size`: None, mode: `Measured`)
 	fn unregister_server() -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `1632`
-		//  Estimated: `7572`
-		// Minimum execution time: 996_966_000 picoseconds.
-		Weight::from_parts(1_006_954_000, 7572)
-			.saturating_add(RocksDbWeight::get().reads(6_u64))
-			.saturating_add(RocksDbWeight::get().writes(4_u64))
+		//  Measured:  `1824`
+		//  Estimated: `8736`
+		// Minimum execution time: 1_197_380_000 picoseconds.
+		Weight::from_parts(1_214_705_000, 8736)
+			.saturating_add(RocksDbWeight::get().reads(9_u64))
+			.saturating_add(RocksDbWeight::get().writes(8_u64))
 	}
+	/// Storage: `DeeperNode::DeviceInfo` (r:1 w:0)
+	/// Proof: `DeeperNode::DeviceInfo` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `DeeperNode::ImOnline` (r:1 w:1)
+	/// Proof: `DeeperNode::ImOnline` (`max_values`: None, `max_size`: None, mode: `Measured`)
 	/// Storage: `DeeperNode::OnboardTime` (r:1 w:1)
 	/// Proof: `DeeperNode::OnboardTime` (`max_values`: None, `max_size`: None, mode: `Measured`)
-	/// Storage: `DeeperNode::DevicesOnboard` (r:1 w:1)
-	/// Proof: `DeeperNode::DevicesOnboard` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
-	/// Storage: `DeeperNode::ImOnline` (r:0 w:1)
-	/// Proof: `DeeperNode::ImOnline` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `DeeperNode::CounterForDevicesOnboard` (r:1 w:1)
+	/// Proof: `DeeperNode::CounterForDevicesOnboard` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `DeeperNode::EraDeviceStats` (r:1 w:1)
+	/// Proof: `DeeperNode::EraDeviceStats` (`max_values`: None, `max_size`: Some(24), added: 2499, mode: `MaxEncodedLen`)
+	/// Storage: `DeeperNode::CountryEraDeviceStats` (r:1 w:1)
+	/// Proof: `DeeperNode::CountryEraDeviceStats` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `DeeperNode::DevicesOnboard` (r:0 w:1)
+	/// Proof: `DeeperNode::DevicesOnboard` (`max_values`: None, `max_size`: None, mode: `Measured`)
 	fn im_online() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `334`
 		//  Estimated: `3799`
-		// Minimum execution time: 366_190_000 picoseconds.
-		Weight::from_parts(371_360_000, 3799)
-			.saturating_add(RocksDbWeight::get().reads(2_u64))
-			.saturating_add(RocksDbWeight::get().writes(3_u64))
+		// Minimum execution time: 498_613_000 picoseconds.
+		Weight::from_parts(507_229_000, 3799)
+			.saturating_add(RocksDbWeight::get().reads(6_u64))
+			.saturating_add(RocksDbWeight::get().writes(7_u64))
 	}
-	/// Storage: `CreditAccumulation::AtmosAccountid` (r:1 w:0)
-	/// Proof: `CreditAccumulation::AtmosAccountid` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
-	/// Storage: `CreditAccumulation::TmpAtmosAccountid` (r:1 w:0)
-	/// Proof: `CreditAccumulation::TmpAtmosAccountid` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
-	/// Storage: `DeeperNode::DeviceCreditProof` (r:0 w:1)
+	/// Storage: `CreditAccumulation::AtmosSigners` (r:1 w:0)
+	/// Proof: `CreditAccumulation::AtmosSigners` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// Storage: `CreditAccumulation::AtmosNonceSince` (r:1 w:1)
+	/// Proof: `CreditAccumulation::AtmosNonceSince` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `CreditAccumulation::AtmosNonce` (r:1 w:1)
+	/// Proof: `CreditAccumulation::AtmosNonce` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `DeeperNode::CreditProofHistory` (r:1 w:1)
+	/// Proof: `DeeperNode::CreditProofHistory` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `DeeperNode::DeviceCreditProof` (r:1 w:1)
 	/// Proof: `DeeperNode::DeviceCreditProof` (`max_values`: None, `max_size`: None, mode: `Measured`)
 	fn report_credit_proof() -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `165`
-		//  Estimated: `1650`
-		// Minimum execution time: 399_182_000 picoseconds.
-		Weight::from_parts(405_224_000, 1650)
-			.saturating_add(RocksDbWeight::get().reads(2_u64))
-			.saturating_add(RocksDbWeight::get().writes(1_u64))
+		//  Measured:  `725`
+		//  Estimated: `4190`
+		// Minimum execution time: 451_903_000 picoseconds.
+		Weight::from_parts(460_218_000, 4190)
+			.saturating_add(RocksDbWeight::get().reads(5_u64))
+			.saturating_add(RocksDbWeight::get().writes(4_u64))
 	}
-	/// Storage: `CreditAccumulation::AtmosAccountid` (r:1 w:0)
-	/// Proof: `CreditAccumulation::AtmosAccountid` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
-	/// Storage: `DeeperNode::RewardsAccountsEVMtoDeeper` (r:1 w:1)
+	/// Storage: `CreditAccumulation::AtmosSigners` (r:1 w:0)
+	/// Proof: `CreditAccumulation::AtmosSigners` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// Storage: `CreditAccumulation::AtmosNonceSince` (r:1 w:0)
+	/// Proof: `CreditAccumulation::AtmosNonceSince` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `DeeperNode::RewardMappingNonce` (r:1 w:1)
+	/// Proof: `DeeperNode::RewardMappingNonce` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `DeeperNode::RewardsAccountsEVMtoDeeper` (r:1 w:2)
 	/// Proof: `DeeperNode::RewardsAccountsEVMtoDeeper` (`max_values`: None, `max_size`: None, mode: `Measured`)
 	/// Storage: `DeeperNode::RewardsAccountsDeepertoEVM` (r:1 w:1)
 	/// Proof: `DeeperNode::RewardsAccountsDeepertoEVM` (`max_values`: None, `max_size`: None, mode: `Measured`)
 	fn reward_mapping() -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `692`
-		//  Estimated: `4157`
-		// Minimum execution time: 599_666_000 picoseconds.
-		Weight::from_parts(609_685_000, 4157)
-			.saturating_add(RocksDbWeight::get().reads(3_u64))
-			.saturating_add(RocksDbWeight::get().writes(2_u64))
+		//  Measured:  `728`
+		//  Estimated: `4193`
+		// Minimum execution time: 671_204_000 picoseconds.
+		Weight::from_parts(683_517_000, 4193)
+			.saturating_add(RocksDbWeight::get().reads(5_u64))
+			.saturating_add(RocksDbWeight::get().writes(4_u64))
 	}
 	/// Storage: `DeeperNode::RewardsAccountsDeepertoEVM` (r:1 w:0)
 	/// Proof: `DeeperNode::RewardsAccountsDeepertoEVM` (`max_values`: None, `max_size`: None, mode: `Measured`)
@@ -324,4 +526,127 @@ impl WeightInfo for () {
 		Weight::from_parts(270_788_000, 3830)
 			.saturating_add(RocksDbWeight::get().reads(1_u64))
 	}
+	/// Storage: `DeeperNode::RegionMap` (r:3 w:1)
+	/// Proof: `DeeperNode::RegionMap` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `DeeperNode::RegionChildren` (r:1 w:1)
+	/// Proof: `DeeperNode::RegionChildren` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	fn insert_region() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1143`
+		//  Estimated: `9408`
+		// Minimum execution time: 58_340_000 picoseconds.
+		Weight::from_parts(59_762_000, 9408)
+			.saturating_add(RocksDbWeight::get().reads(4_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+	/// Storage: `DeeperNode::RegionMap` (r:3 w:1)
+	/// Proof: `DeeperNode::RegionMap` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `DeeperNode::ServerCountByCountry` (r:1 w:0)
+	/// Proof: `DeeperNode::ServerCountByCountry` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `DeeperNode::ServersByCountry` (r:1001 w:0)
+	/// Proof: `DeeperNode::ServersByCountry` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `DeeperNode::ServersByRegion` (r:2000 w:2000)
+	/// Proof: `DeeperNode::ServersByRegion` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `DeeperNode::ServerCountByRegion` (r:2000 w:2000)
+	/// Proof: `DeeperNode::ServerCountByRegion` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `DeeperNode::RegionChildren` (r:3 w:2)
+	/// Proof: `DeeperNode::RegionChildren` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// The range of component `s` is `[0, 1000]`.
+	fn reparent_region(s: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1052 + s * (96 ±0)`
+		//  Estimated: `11942 + s * (2571 ±0)`
+		// Minimum execution time: 421_516_000 picoseconds.
+		Weight::from_parts(433_105_000, 11942)
+			// Standard Error: 52_317
+			.saturating_add(Weight::from_parts(178_406_312, 0).saturating_mul(s.into()))
+			.saturating_add(RocksDbWeight::get().reads(8_u64))
+			.saturating_add(RocksDbWeight::get().reads((3_u64).saturating_mul(s.into())))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
+			.saturating_add(RocksDbWeight::get().writes((2_u64).saturating_mul(s.into())))
+			.saturating_add(Weight::from_parts(0, 2571).saturating_mul(s.into()))
+	}
+	/// Storage: `DeeperNode::RegionMap` (r:1 w:1)
+	/// Proof: `DeeperNode::RegionMap` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `DeeperNode::RegionChildren` (r:2 w:1)
+	/// Proof: `DeeperNode::RegionChildren` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `DeeperNode::DeviceCountByCountry` (r:1 w:0)
+	/// Proof: `DeeperNode::DeviceCountByCountry` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	fn retire_region() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1187`
+		//  Estimated: `7127`
+		// Minimum execution time: 61_915_000 picoseconds.
+		Weight::from_parts(63_208_000, 7127)
+			.saturating_add(RocksDbWeight::get().reads(4_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+	/// Storage: `DeeperNode::DeviceInfo` (r:1 w:1)
+	/// Proof: `DeeperNode::DeviceInfo` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `DeeperNode::RegionMap` (r:2 w:0)
+	/// Proof: `DeeperNode::RegionMap` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `DeeperNode::ServersByCountry` (r:1 w:1)
+	/// Proof: `DeeperNode::ServersByCountry` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `DeeperNode::ServersByRegion` (r:2 w:2)
+	/// Proof: `DeeperNode::ServersByRegion` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `DeeperNode::ServerExpirations` (r:0 w:1)
+	/// Proof: `DeeperNode::ServerExpirations` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	fn expire_server() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `790`
+		//  Estimated: `6730`
+		// Minimum execution time: 351_204_000 picoseconds.
+		Weight::from_parts(358_962_000, 6730)
+			.saturating_add(RocksDbWeight::get().reads(6_u64))
+			.saturating_add(RocksDbWeight::get().writes(5_u64))
+	}
+	/// Storage: `DeeperNode::DeviceInfo` (r:1 w:0)
+	/// Proof: `DeeperNode::DeviceInfo` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `DeeperNode::ImOnline` (r:1 w:1)
+	/// Proof: `DeeperNode::ImOnline` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `DeeperNode::OnboardTime` (r:1 w:1)
+	/// Proof: `DeeperNode::OnboardTime` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `DeeperNode::CounterForDevicesOnboard` (r:1 w:1)
+	/// Proof: `DeeperNode::CounterForDevicesOnboard` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `DeeperNode::EraDeviceStats` (r:1 w:1)
+	/// Proof: `DeeperNode::EraDeviceStats` (`max_values`: None, `max_size`: Some(24), added: 2499, mode: `MaxEncodedLen`)
+	/// Storage: `DeeperNode::CountryEraDeviceStats` (r:1 w:1)
+	/// Proof: `DeeperNode::CountryEraDeviceStats` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `DeeperNode::DevicesOnboard` (r:0 w:1)
+	/// Proof: `DeeperNode::DevicesOnboard` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	fn heartbeat() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `334`
+		//  Estimated: `3799`
+		// Minimum execution time: 484_930_000 picoseconds.
+		Weight::from_parts(493_472_000, 3799)
+			.saturating_add(RocksDbWeight::get().reads(6_u64))
+			.saturating_add(RocksDbWeight::get().writes(7_u64))
+	}
+	/// Storage: `DeeperNode::RewardsAccountsDeepertoEVM` (r:1 w:1)
+	/// Proof: `DeeperNode::RewardsAccountsDeepertoEVM` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `DeeperNode::RewardsAccountsEVMtoDeeper` (r:0 w:1)
+	/// Proof: `DeeperNode::RewardsAccountsEVMtoDeeper` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	fn unbind_reward_mapping() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `401`
+		//  Estimated: `3866`
+		// Minimum execution time: 301_552_000 picoseconds.
+		Weight::from_parts(307_914_000, 3866)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+	/// Storage: `DeeperNode::DeviceInfo` (r:1 w:0)
+	/// Proof: `DeeperNode::DeviceInfo` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `DeeperNode::ServerEndpoints` (r:0 w:1)
+	/// Proof: `DeeperNode::ServerEndpoints` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	fn set_server_endpoint() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `412`
+		//  Estimated: `3877`
+		// Minimum execution time: 318_760_000 picoseconds.
+		Weight::from_parts(326_095_000, 3877)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
 }
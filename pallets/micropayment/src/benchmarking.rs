@@ -23,7 +23,7 @@ pub use frame_benchmarking::{
     account, benchmarks, impl_benchmark_test_suite, whitelist_account, whitelisted_caller,
 };
 use frame_support::traits::Currency;
-use frame_system::{Pallet as System, RawOrigin};
+use frame_system::{pallet_prelude::BlockNumberFor, Pallet as System, RawOrigin};
 //use hex_literal::hex;
 use codec::Encode;
 use node_primitives::AccountCreator;
@@ -199,6 +199,61 @@ benchmarks! {
         );
 
     }
+
+    withdraw_from_channel {
+        let client = create_funded_user::<T>("Alice", 100);
+        let server = create_funded_user::<T>("Bob", 100);
+        let amount = T::Currency::minimum_balance() * 30u32.into();
+
+        Micropayment::<T>::open_channel(RawOrigin::Signed(client.clone()).into(), server.clone(), amount, 3600)?;
+
+        let withdraw_nonce: u32 = 0;
+        let settled_session: u32 = 1;
+        let nonce: u64 = 0;
+        let paid = T::Currency::minimum_balance() * 10u32.into();
+        let withdraw_amount = T::Currency::minimum_balance() * 10u32.into();
+        let msg = Micropayment::<T>::construct_withdraw_hash(&client, nonce, withdraw_nonce, settled_session, paid, withdraw_amount);
+
+        let mut pk = [0u8; 32];
+        pk.copy_from_slice(&server.encode());
+        let pub_key = sr25519::Public::from_raw(pk);
+
+        let signature: [u8; 64] = sr25519_sign(0.into(), &pub_key, &msg).unwrap().into();
+    }: _(RawOrigin::Signed(client.clone()), server.clone(), withdraw_nonce, settled_session, paid, withdraw_amount, signature.into())
+    verify {
+        assert_eq!(
+            Micropayment::<T>::channel(&client, &server).balance,
+            T::Currency::minimum_balance() * 10u32.into()
+        );
+    }
+
+    rate_server {
+        let client = create_funded_user::<T>("Alice", 100);
+        let server = create_funded_user::<T>("Bob", 100);
+        let amount = T::Currency::minimum_balance() * 30u32.into();
+
+        Micropayment::<T>::open_channel(RawOrigin::Signed(client.clone()).into(), server.clone(), amount, 3600)?;
+        PaidAmount::<T>::insert((&client, &server), T::Currency::minimum_balance());
+        Micropayment::<T>::close_channel(RawOrigin::Signed(server.clone()).into(), client.clone())?;
+    }: _(RawOrigin::Signed(client.clone()), server.clone(), 0, 5)
+    verify {
+        assert_eq!(Micropayment::<T>::next_rating_nonce((&client, &server)), 1);
+    }
+
+    sweep_expired_channel {
+        let client = create_funded_user::<T>("Alice", 100);
+        let server = create_funded_user::<T>("Bob", 100);
+        let amount = T::Currency::minimum_balance() * 30u32.into();
+
+        Micropayment::<T>::open_channel(RawOrigin::Signed(client.clone()).into(), server.clone(), amount, 3600)?;
+        let now: BlockNumberFor<T> = 722u32.into();
+    }: {
+        Micropayment::<T>::close_expired_channel(&client, &server, now);
+    }
+    verify {
+        assert!(!Channel::<T>::contains_key(&client, &server));
+        assert!(!ChannelExpirations::<T>::contains_key(BlockNumberFor::<T>::from(720u32), (&client, &server)));
+    }
 }
 
 #[cfg(test)]
@@ -214,6 +269,8 @@ mod tests {
             assert_ok!(Pallet::<Test>::test_benchmark_close_channel());
             assert_ok!(Pallet::<Test>::test_benchmark_close_expired_channels());
             assert_ok!(Pallet::<Test>::test_benchmark_add_balance());
+            assert_ok!(Pallet::<Test>::test_benchmark_sweep_expired_channel());
+            assert_ok!(Pallet::<Test>::test_benchmark_rate_server());
             //assert_ok!(test_benchmark_claim_payment::<Test>()); // Move to test.rs
         });
     }
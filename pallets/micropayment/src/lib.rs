@@ -35,6 +35,8 @@ pub mod testing_utils;
 #[cfg(any(test, feature = "runtime-benchmarks"))]
 mod benchmarking;
 
+pub mod migration;
+
 #[cfg(any(feature = "runtime-benchmarks"))]
 use sp_std::prelude::*;
 
@@ -48,14 +50,22 @@ pub mod pallet {
         dispatch::DispatchResultWithPostInfo,
         pallet_prelude::*,
         traits::{
-            tokens::currency::Currency, ExistenceRequirement, Get, OnUnbalanced, WithdrawReasons,
+            tokens::currency::Currency, ExistenceRequirement, Get, OnUnbalanced,
+            ReservableCurrency, WithdrawReasons,
         },
     };
     use frame_system::pallet_prelude::*;
-    use node_primitives::{credit::CreditInterface, deeper_node::NodeInterface};
+    use node_primitives::{
+        credit::CreditInterface,
+        deeper_node::{NodeInterface, MAX_RATING},
+        sweep::sweep_expirations,
+    };
     use sp_core::{crypto::UncheckedFrom, sr25519};
     use sp_io::crypto::sr25519_verify;
-    use sp_runtime::Percent;
+    use sp_runtime::{
+        traits::{SaturatedConversion, Saturating, Zero},
+        Percent,
+    };
     use sp_std::prelude::Vec;
 
     #[cfg(feature = "runtime-benchmarks")]
@@ -66,7 +76,7 @@ pub mod pallet {
     pub trait Config: frame_system::Config {
         // Because this pallet emits events, it depends on the runtime's definition of an event.
         type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
-        type Currency: Currency<Self::AccountId>;
+        type Currency: ReservableCurrency<Self::AccountId>;
         type SecsPerBlock: Get<u32>;
         // CreditInterface of credit pallet
         type CreditInterface: CreditInterface<Self::AccountId, BalanceOf<Self>>;
@@ -81,6 +91,16 @@ pub mod pallet {
         type MicropaymentBurn: Get<Percent>;
 
         type Slash: OnUnbalanced<NegativeImbalanceOf<Self>>;
+
+        /// Deposit reserved from the client for every open channel, refunded on close.
+        /// It pays for the expiration index used to sweep expired channels.
+        #[pallet::constant]
+        type ChannelDeposit: Get<BalanceOf<Self>>;
+
+        /// Amount paid to the server in a closed channel per unit of weight of the client's
+        /// rating of the server.
+        #[pallet::constant]
+        type RatingWeightUnit: Get<BalanceOf<Self>>;
         #[cfg(feature = "runtime-benchmarks")]
         type AccountCreator: AccountCreator<Self::AccountId>;
     }
@@ -123,7 +143,11 @@ pub mod pallet {
         }
     }
 
+    /// The current storage version.
+    const STORAGE_VERSION: StorageVersion = StorageVersion::new(1);
+
     #[pallet::pallet]
+    #[pallet::storage_version(STORAGE_VERSION)]
     #[pallet::without_storage_info]
     pub struct Pallet<T>(_);
 
@@ -153,12 +177,61 @@ pub mod pallet {
     pub(super) type SessionId<T: Config> =
         StorageMap<_, Blake2_128Concat, (T::AccountId, T::AccountId), u32, OptionQuery>;
 
+    // next nonce of a withdraw voucher, withdrawals do not consume session ids
+    #[pallet::storage]
+    #[pallet::getter(fn withdraw_nonce)]
+    pub(super) type WithdrawNonce<T: Config> =
+        StorageMap<_, Blake2_128Concat, (T::AccountId, T::AccountId), u32, ValueQuery>;
+
     // record total micropayment channel balance of accountId
     #[pallet::storage]
     #[pallet::getter(fn total_micropayment_chanel_balance)]
     pub(super) type TotalMicropaymentChannelBalance<T: Config> =
         StorageMap<_, Blake2_128Concat, T::AccountId, BalanceOf<T>, OptionQuery>;
 
+    // amount claimed by the server from the current channel of an account pair
+    #[pallet::storage]
+    #[pallet::getter(fn paid_amount)]
+    pub(super) type PaidAmount<T: Config> =
+        StorageMap<_, Blake2_128Concat, (T::AccountId, T::AccountId), BalanceOf<T>, ValueQuery>;
+
+    // index of open channels by expiration block; the value is the deposit
+    // reserved from the client when the channel was opened
+    #[pallet::storage]
+    #[pallet::getter(fn channel_expiration)]
+    pub(super) type ChannelExpirations<T: Config> = StorageDoubleMap<
+        _,
+        Twox64Concat,
+        BlockNumberFor<T>,
+        Blake2_128Concat,
+        (T::AccountId, T::AccountId),
+        BalanceOf<T>,
+        OptionQuery,
+    >;
+
+    // the earliest expiration block which has not been swept yet
+    #[pallet::storage]
+    #[pallet::getter(fn next_expiration_to_sweep)]
+    pub(super) type NextExpirationToSweep<T: Config> =
+        StorageValue<_, BlockNumberFor<T>, ValueQuery>;
+
+    // lowest channel nonce of an account pair the client can still rate
+    #[pallet::storage]
+    #[pallet::getter(fn next_rating_nonce)]
+    pub(super) type NextRatingNonce<T: Config> =
+        StorageMap<_, Blake2_128Concat, (T::AccountId, T::AccountId), u64, ValueQuery>;
+
+    // nonce of the last closed channel of an account pair and the amount it paid the server
+    #[pallet::storage]
+    #[pallet::getter(fn closed_channel_paid)]
+    pub(super) type ClosedChannelPaid<T: Config> = StorageMap<
+        _,
+        Blake2_128Concat,
+        (T::AccountId, T::AccountId),
+        (u64, BalanceOf<T>),
+        OptionQuery,
+    >;
+
     // Pallets use events to inform users when important changes are made.
     // https://substrate.dev/docs/en/knowledgebase/runtime/events
     #[pallet::event]
@@ -176,6 +249,10 @@ pub mod pallet {
         ChannelClosed(T::AccountId, T::AccountId, BlockNumberFor<T>),
         ClaimPayment(T::AccountId, T::AccountId, BalanceOf<T>),
         BalanceAdded(T::AccountId, T::AccountId, BalanceOf<T>, BlockNumberFor<T>),
+        /// client, server, amount paid to the server so far, amount withdrawn by the client
+        BalanceWithdrawn(T::AccountId, T::AccountId, BalanceOf<T>, BalanceOf<T>),
+        /// client, server, nonce of the rated channel, rating
+        ServerRated(T::AccountId, T::AccountId, u64, u8),
     }
 
     #[pallet::error]
@@ -192,12 +269,28 @@ pub mod pallet {
         SameChannelEnds,
         // Session has already been consumed
         SessionError,
+        /// withdraw voucher nonce is not the next one
+        WithdrawNonceError,
         // Invalid signature
         InvalidSignature,
+        // Paid amount in the voucher is less than the amount already claimed by the server
+        PaidAmountTooLow,
+        // Rating is not between 1 and MAX_RATING
+        InvalidRating,
+        // Channel with this nonce is still open or was never opened
+        ChannelNotClosed,
+        // Channel with this nonce, or a later one, has already been rated
+        AlreadyRated,
+        // Channel with this nonce paid nothing to the server or is not the last closed one
+        NothingPaid,
     }
 
     #[pallet::hooks]
-    impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {}
+    impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
+        fn on_idle(now: BlockNumberFor<T>, remaining_weight: Weight) -> Weight {
+            Self::sweep_expired_channels(now, remaining_weight)
+        }
+    }
 
     // Dispatchable functions allows users to interact with the pallet and invoke state changes.
     // These functions materialize as "extrinsics", which are often compared to transactions.
@@ -237,7 +330,13 @@ pub mod pallet {
                 log::error!("Not enough free balance to open channel");
                 Err(Error::<T>::NotEnoughBalance)?
             }
+            let deposit = T::ChannelDeposit::get();
+            if T::Currency::reserve(&client, deposit).is_err() {
+                log::error!("Not enough free balance to reserve channel deposit");
+                Err(Error::<T>::NotEnoughBalance)?
+            }
             Channel::<T>::insert(&client, &server, chan);
+            ChannelExpirations::<T>::insert(expiration, (&client, &server), deposit);
             if TotalMicropaymentChannelBalance::<T>::contains_key(&client) {
                 TotalMicropaymentChannelBalance::<T>::mutate_exists(&client, |b| {
                     let total_balance = b.take().unwrap_or_default();
@@ -446,6 +545,7 @@ pub mod pallet {
 
             chan.balance -= amount;
             Channel::<T>::insert(&client, &server, chan);
+            PaidAmount::<T>::mutate((&client, &server), |p| *p += amount);
             TotalMicropaymentChannelBalance::<T>::mutate_exists(&client, |b| {
                 let total_balance = b.take().unwrap_or_default();
                 *b = if total_balance > amount {
@@ -460,6 +560,112 @@ pub mod pallet {
 
             Ok(().into())
         }
+
+        /// Client withdraws part of the channel balance with a voucher signed by the server.
+        /// The voucher states the amount the server has been paid so far, the last session
+        /// that amount settles and the amount the client withdraws; the unclaimed part of
+        /// `paid` is settled to the server first. The channel stays open with the same nonce,
+        /// the voucher consumes `withdraw_nonce` and leaves later sessions claimable.
+        #[pallet::call_index(5)]
+        #[pallet::weight(T::WeightInfo::withdraw_from_channel())]
+        pub fn withdraw_from_channel(
+            origin: OriginFor<T>,
+            server: T::AccountId,
+            withdraw_nonce: u32,
+            settled_session: u32,
+            paid: BalanceOf<T>,
+            amount: BalanceOf<T>,
+            signature: Vec<u8>,
+        ) -> DispatchResultWithPostInfo {
+            let client = ensure_signed(origin)?;
+            ensure!(
+                Channel::<T>::contains_key(&client, &server),
+                Error::<T>::ChannelNotExist
+            );
+            let mut chan = Channel::<T>::get(&client, &server);
+
+            ensure!(
+                withdraw_nonce == Self::withdraw_nonce((&client, &server)),
+                Error::<T>::WithdrawNonceError
+            );
+            Self::verify_withdraw_signature(
+                &client,
+                &server,
+                chan.nonce,
+                withdraw_nonce,
+                settled_session,
+                paid,
+                amount,
+                &signature,
+            )?;
+
+            let already_paid = PaidAmount::<T>::get((&client, &server));
+            ensure!(paid >= already_paid, Error::<T>::PaidAmountTooLow);
+            let unclaimed = paid - already_paid;
+            let total = unclaimed.saturating_add(amount);
+            ensure!(chan.balance >= total, Error::<T>::NotEnoughBalance);
+
+            WithdrawNonce::<T>::insert((&client, &server), withdraw_nonce.saturating_add(1));
+            // sessions settled by `paid` can not be claimed again
+            if settled_session > Self::session_id((&client, &server)).unwrap_or(0) {
+                SessionId::<T>::insert((&client, &server), settled_session);
+            }
+            chan.balance -= total;
+            Channel::<T>::insert(&client, &server, chan);
+            PaidAmount::<T>::insert((&client, &server), paid);
+            TotalMicropaymentChannelBalance::<T>::mutate_exists(&client, |b| {
+                let total_balance = b.take().unwrap_or_default();
+                *b = if total_balance > total {
+                    Some(total_balance - total)
+                } else {
+                    None
+                };
+            });
+            if !unclaimed.is_zero() {
+                Self::deposit_into_account(&server, unclaimed)?;
+            }
+            Self::deposit_into_account(&client, amount)?;
+            Self::deposit_event(Event::BalanceWithdrawn(client, server, paid, amount));
+
+            Ok(().into())
+        }
+
+        /// client rates the service of a server once per closed channel, the channel
+        /// nonce is the ticket. Only the last closed channel can be rated, if it paid the
+        /// server; the rating is weighted by the amount paid.
+        #[pallet::call_index(6)]
+        #[pallet::weight(T::WeightInfo::rate_server())]
+        pub fn rate_server(
+            origin: OriginFor<T>,
+            server: T::AccountId,
+            nonce: u64,
+            rating: u8,
+        ) -> DispatchResultWithPostInfo {
+            let client = ensure_signed(origin)?;
+            ensure!(
+                (1..=MAX_RATING).contains(&rating),
+                Error::<T>::InvalidRating
+            );
+            // the nonce is bumped when a channel closes
+            ensure!(
+                nonce < Nonce::<T>::get((&client, &server)),
+                Error::<T>::ChannelNotClosed
+            );
+            ensure!(
+                nonce >= NextRatingNonce::<T>::get((&client, &server)),
+                Error::<T>::AlreadyRated
+            );
+            let paid = match ClosedChannelPaid::<T>::get((&client, &server)) {
+                Some((closed, paid)) if closed == nonce && !paid.is_zero() => paid,
+                _ => Err(Error::<T>::NothingPaid)?,
+            };
+            let weight: u64 = (paid / T::RatingWeightUnit::get().max(1u32.into())).saturated_into();
+            NextRatingNonce::<T>::insert((&client, &server), nonce + 1);
+            ClosedChannelPaid::<T>::remove((&client, &server));
+            T::NodeInterface::rate_server(&server, rating, weight.max(1));
+            Self::deposit_event(Event::ServerRated(client, server, nonce, rating));
+            Ok(().into())
+        }
     }
 
     impl<T: Config> Pallet<T> {
@@ -467,12 +673,58 @@ pub mod pallet {
         fn _close_channel(client: &T::AccountId, server: &T::AccountId) {
             // remove all the session_ids of given channel
             SessionId::<T>::remove((client, server));
-            // remove the channel
-            Channel::<T>::remove(client, server);
+            WithdrawNonce::<T>::remove((client, server));
+            // remove the channel and its expiration index, refund the deposit
+            let chan = Channel::<T>::take(client, server);
+            // the amount paid weights the client's rating of the server
+            let paid = PaidAmount::<T>::take((client, server));
+            ClosedChannelPaid::<T>::insert((client, server), (chan.nonce, paid));
+            if let Some(deposit) = ChannelExpirations::<T>::take(chan.expiration, (client, server))
+            {
+                T::Currency::unreserve(client, deposit);
+            }
             // increment the nonce
             Nonce::<T>::mutate((client, server), |v| *v += 1);
         }
 
+        /// Refund the channels whose expiration block is before `now`, within `remaining_weight`.
+        /// Expiration blocks are visited in order starting from `NextExpirationToSweep`.
+        pub(crate) fn sweep_expired_channels(
+            now: BlockNumberFor<T>,
+            remaining_weight: Weight,
+        ) -> Weight {
+            sweep_expirations::<NextExpirationToSweep<T>, ChannelExpirations<T>, _, _, _>(
+                now,
+                remaining_weight,
+                T::DbWeight::get(),
+                T::WeightInfo::sweep_expired_channel(),
+                |_, (client, server)| Self::close_expired_channel(&client, &server, now),
+            )
+        }
+
+        /// Refund the remaining balance of an expired channel to the client and close it
+        pub(crate) fn close_expired_channel(
+            client: &T::AccountId,
+            server: &T::AccountId,
+            now: BlockNumberFor<T>,
+        ) {
+            let chan = Channel::<T>::get(client, server);
+            TotalMicropaymentChannelBalance::<T>::mutate_exists(client, |b| {
+                let total_balance = b.take().unwrap_or_default();
+                *b = if total_balance > chan.balance {
+                    Some(total_balance - chan.balance)
+                } else {
+                    None
+                };
+            });
+            // return the remaining balance in the channel to the client
+            if Self::deposit_into_account(client, chan.balance).is_err() {
+                log::error!("Failed to refund expired channel");
+            }
+            Self::_close_channel(client, server);
+            Self::deposit_event(Event::ChannelClosed(client.clone(), server.clone(), now));
+        }
+
         /// verify signature, signature is on hash of |server_addr|nonce|session_id|amount|
         /// during one session_id, a client can send multiple accumulated
         /// micropayments with the same session_id; the server can only claim one payment
@@ -503,6 +755,64 @@ pub mod pallet {
             Ok(().into())
         }
 
+        /// verify the server signature on a withdraw voucher, signature is on hash of
+        /// |"withdraw"|client_addr|nonce|withdraw_nonce|settled_session|paid|amount|
+        pub fn verify_withdraw_signature(
+            client: &T::AccountId,
+            server: &T::AccountId,
+            nonce: u64,
+            withdraw_nonce: u32,
+            settled_session: u32,
+            paid: BalanceOf<T>,
+            amount: BalanceOf<T>,
+            signature: &Vec<u8>,
+        ) -> DispatchResultWithPostInfo {
+            let mut pk = [0u8; 32];
+            pk.copy_from_slice(&server.encode());
+            let pub_key = sr25519::Public::from_raw(pk);
+
+            let sig = sr25519::Signature::from_slice(&signature);
+
+            let msg = Self::construct_withdraw_hash(
+                client,
+                nonce,
+                withdraw_nonce,
+                settled_session,
+                paid,
+                amount,
+            );
+
+            let verified = sr25519_verify(
+                &sig.unwrap_or(UncheckedFrom::unchecked_from([0; 64])),
+                &msg,
+                &pub_key,
+            );
+            ensure!(verified, Error::<T>::InvalidSignature);
+
+            Ok(().into())
+        }
+
+        // construct data from
+        // |"withdraw"|client_addr|nonce|withdraw_nonce|settled_session|paid|amount| and hash it
+        pub fn construct_withdraw_hash(
+            address: &T::AccountId,
+            nonce: u64,
+            withdraw_nonce: u32,
+            settled_session: u32,
+            paid: BalanceOf<T>,
+            amount: BalanceOf<T>,
+        ) -> [u8; 32] {
+            let mut data = Vec::new();
+            data.extend_from_slice(b"withdraw");
+            data.extend_from_slice(&address.encode());
+            data.extend_from_slice(&nonce.to_be_bytes());
+            data.extend_from_slice(&withdraw_nonce.to_be_bytes());
+            data.extend_from_slice(&settled_session.to_be_bytes());
+            data.extend_from_slice(&paid.encode());
+            data.extend_from_slice(&amount.encode());
+            sp_io::hashing::blake2_256(&data)
+        }
+
         // construct data from |server_addr|session_id|amount| and hash it
         pub fn construct_byte_array_and_hash(
             address: &T::AccountId,
@@ -0,0 +1,50 @@
+use super::*;
+pub mod v1 {
+    use frame_support::{pallet_prelude::*, weights::Weight};
+    use sp_runtime::traits::Zero;
+
+    use super::*;
+    use frame_support::traits::{Currency, OnRuntimeUpgrade};
+    use frame_system::pallet_prelude::BlockNumberFor;
+
+    /// Index the channels opened before the upgrade by their expiration block.
+    /// No deposit was reserved for them, so they are indexed with a zero deposit.
+    pub struct MigrateToV1<T>(sp_std::marker::PhantomData<T>);
+    impl<T: Config> OnRuntimeUpgrade for MigrateToV1<T> {
+        fn on_runtime_upgrade() -> Weight {
+            let current_version = Pallet::<T>::current_storage_version();
+            let onchain_version = Pallet::<T>::on_chain_storage_version();
+            if onchain_version == 0 && current_version == 1 {
+                let mut indexed = 0u64;
+                let mut earliest = <frame_system::Pallet<T>>::block_number();
+                for (client, server, chan) in Channel::<T>::iter() {
+                    indexed += 1;
+                    if chan.expiration < earliest {
+                        earliest = chan.expiration;
+                    }
+                    ChannelExpirations::<T>::insert(
+                        chan.expiration,
+                        (client, server),
+                        <T::Currency as Currency<T::AccountId>>::Balance::zero(),
+                    );
+                }
+                NextExpirationToSweep::<T>::put(earliest);
+
+                current_version.put::<Pallet<T>>();
+                log::info!(
+                    target: "runtime::micropayment",
+                    "Indexed {} channels, storage to version {:?}",
+                    indexed,
+                    current_version
+                );
+                T::DbWeight::get().reads_writes(indexed + 2, indexed + 2)
+            } else {
+                log::info!(
+                    target: "runtime::micropayment",
+                    "Migration did not execute. This probably should be removed"
+                );
+                T::DbWeight::get().reads(1)
+            }
+        }
+    }
+}
@@ -182,6 +182,7 @@ parameter_types! {
     pub const MicropaymentToCreditFactor: u128 = 1_000_000_000_000_000;
     pub const BlocksPerEra: BlockNumber =  BLOCKS_PER_ERA;
     pub const MaxBurnCreditPerAddress: u32 = 50;
+    pub const AnomalyDeviations: u32 = 3;
 }
 
 impl pallet_credit::Config for Test {
@@ -198,6 +199,7 @@ impl pallet_credit::Config for Test {
     type BurnedTo = ();
     type UserPrivilegeInterface = ();
     type MaxBurnCreditPerAddress = MaxBurnCreditPerAddress;
+    type AnomalyDeviations = AnomalyDeviations;
 }
 
 parameter_types! {
@@ -215,6 +217,7 @@ parameter_types! {
     pub const MinLockAmt: u32 = 100;
     pub const MaxDurationEras: u8 = 7;
     pub const MaxIpLength: usize = 256;
+    pub const ReputationDecay: sp_runtime::Perbill = sp_runtime::Perbill::from_percent(90);
 }
 impl pallet_deeper_node::Config for Test {
     type RuntimeEvent = RuntimeEvent;
@@ -225,12 +228,18 @@ impl pallet_deeper_node::Config for Test {
     type MaxIpLength = MaxIpLength;
     type WeightInfo = ();
     type VerifySignatureInterface = ();
+    type RegionOrigin = frame_system::EnsureRoot<Self::AccountId>;
+    type DeviceSignature = sp_runtime::testing::TestSignature;
+    type UnsignedPriority = frame_support::traits::ConstU64<700>;
+    type MaxCreditProofs = frame_support::traits::ConstU32<4>;
+    type ReputationDecay = ReputationDecay;
 }
 
 parameter_types! {
     pub const SecsPerBlock: u32 = 5u32;
     pub const DataPerDPR: u64 = 1024 * 1024 * 1024 * 1024;
     pub const MicropaymentBurn: Percent = Percent::from_percent(10);
+    pub static ChannelDeposit: Balance = 0;
 }
 
 pub struct DefaultAccountCreator;
@@ -255,6 +264,8 @@ impl pallet_micropayment::Config for Test {
     type NodeInterface = DeeperNode;
     type MicropaymentBurn = MicropaymentBurn;
     type Slash = Treasury;
+    type ChannelDeposit = ChannelDeposit;
+    type RatingWeightUnit = ConstU128<100>;
     #[cfg(feature = "runtime-benchmarks")]
     type AccountCreator = DefaultAccountCreator;
 }
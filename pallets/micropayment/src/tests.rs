@@ -14,15 +14,19 @@
 // limitations under the License.
 
 use super::Chan;
-use crate::{mock::*, testing_utils::*, Error};
-use frame_support::{assert_ok, dispatch::DispatchErrorWithPostInfo};
+use crate::weights::WeightInfo;
+use crate::{mock::*, testing_utils::*, Channel, Error};
+use frame_support::{
+    assert_err, assert_ok, dispatch::DispatchErrorWithPostInfo, traits::Hooks, weights::Weight,
+};
 use hex_literal::hex;
 use sp_core::{
     crypto::UncheckedFrom,
-    sr25519::{Public, Signature},
+    sr25519::{self, Public, Signature},
+    Pair,
 };
 use sp_io::crypto::sr25519_verify;
-use sp_runtime::{DispatchError, ModuleError};
+use sp_runtime::{DispatchError, ModuleError, Perbill};
 
 #[test]
 fn open_channel() {
@@ -216,6 +220,93 @@ fn close_expired_channels() {
     });
 }
 
+#[test]
+fn expired_channels_swept_on_idle() {
+    new_test_ext().execute_with(|| {
+        ChannelDeposit::set(10);
+        assert_ok!(Micropayment::open_channel(
+            RuntimeOrigin::signed(alice()),
+            bob(),
+            100,
+            3600
+        ));
+        assert_ok!(Micropayment::open_channel(
+            RuntimeOrigin::signed(alice()),
+            charlie(),
+            100,
+            3600
+        ));
+        assert_eq!(Balances::reserved_balance(alice()), 20);
+        assert_eq!(
+            Micropayment::channel_expiration(720, (alice(), bob())),
+            Some(10)
+        );
+
+        // channel is still live at its expiration block
+        Micropayment::on_idle(720, Weight::MAX);
+        assert!(Channel::<Test>::contains_key(alice(), bob()));
+        assert_eq!(Micropayment::next_expiration_to_sweep(), 720);
+
+        // only one channel fits in the remaining weight
+        Micropayment::on_idle(721, <() as WeightInfo>::sweep_expired_channel());
+        assert_eq!(Micropayment::next_expiration_to_sweep(), 720);
+        assert_eq!(Balances::reserved_balance(alice()), 10);
+        assert_eq!(
+            Micropayment::total_micropayment_chanel_balance(alice()),
+            Some(100)
+        );
+
+        Micropayment::on_idle(721, Weight::MAX);
+        assert!(!Channel::<Test>::contains_key(alice(), bob()));
+        assert!(!Channel::<Test>::contains_key(alice(), charlie()));
+        assert_eq!(
+            Micropayment::channel_expiration(720, (alice(), bob())),
+            None
+        );
+        assert_eq!(Micropayment::next_expiration_to_sweep(), 721);
+        assert_eq!(
+            Micropayment::total_micropayment_chanel_balance(alice()),
+            None
+        );
+        assert_eq!(Micropayment::nonce((alice(), bob())), 1);
+        assert_eq!(Balances::reserved_balance(alice()), 0);
+        // 10% of the refunded channel balance is burned
+        assert_eq!(Balances::free_balance(alice()), 490);
+    });
+}
+
+#[test]
+fn channel_deposit_refunded_on_close() {
+    new_test_ext().execute_with(|| {
+        ChannelDeposit::set(10);
+        assert_ok!(Micropayment::open_channel(
+            RuntimeOrigin::signed(alice()),
+            bob(),
+            300,
+            3600
+        ));
+        assert_eq!(Balances::reserved_balance(alice()), 10);
+        assert_eq!(Balances::free_balance(alice()), 190);
+        assert_ok!(Micropayment::close_channel(
+            RuntimeOrigin::signed(bob()),
+            alice()
+        ));
+        assert_eq!(Balances::reserved_balance(alice()), 0);
+        assert_eq!(
+            Micropayment::channel_expiration(720, (alice(), bob())),
+            None
+        );
+
+        // the deposit must be reserved on top of the channel balance
+        assert_eq!(
+            Micropayment::open_channel(RuntimeOrigin::signed(charlie()), dave(), 395, 3600),
+            Err(DispatchErrorWithPostInfo::from(
+                Error::<Test>::NotEnoughBalance
+            ))
+        );
+    });
+}
+
 #[test]
 fn add_balance() {
     new_test_ext().execute_with(|| {
@@ -294,6 +385,268 @@ fn claim_payment() {
     });
 }
 
+#[test]
+fn withdraw_from_channel() {
+    new_test_ext().execute_with(|| {
+        let alice_pair = sr25519::Pair::from_string("//Alice", None).unwrap();
+        let bob_pair = sr25519::Pair::from_string("//Bob", None).unwrap();
+        assert_ok!(Micropayment::open_channel(
+            RuntimeOrigin::signed(alice()),
+            bob(),
+            300,
+            3600
+        ));
+        let msg = Micropayment::construct_byte_array_and_hash(&bob(), 0, 1, 30);
+        let signature: [u8; 64] = alice_pair.sign(&msg).into();
+        assert_ok!(Micropayment::claim_payment(
+            RuntimeOrigin::signed(bob()),
+            alice(),
+            1,
+            30,
+            signature.into()
+        ));
+        assert_eq!(Micropayment::paid_amount((alice(), bob())), 30);
+
+        // server has been paid 50 so far up to session 2, 20 of which is not claimed yet
+        let msg = Micropayment::construct_withdraw_hash(&alice(), 0, 0, 2, 50, 100);
+        let voucher: [u8; 64] = bob_pair.sign(&msg).into();
+        assert_ok!(Micropayment::withdraw_from_channel(
+            RuntimeOrigin::signed(alice()),
+            bob(),
+            0,
+            2,
+            50,
+            100,
+            voucher.into()
+        ));
+        let chan = Micropayment::channel(&alice(), &bob());
+        assert_eq!(chan.balance, 150);
+        assert_eq!(chan.nonce, 0);
+        assert_eq!(Micropayment::nonce((alice(), bob())), 0);
+        assert_eq!(Micropayment::withdraw_nonce((alice(), bob())), 1);
+        assert_eq!(Micropayment::session_id((alice(), bob())), Some(2));
+        assert_eq!(Micropayment::paid_amount((alice(), bob())), 50);
+        assert_eq!(
+            Micropayment::total_micropayment_chanel_balance(alice()),
+            Some(150)
+        );
+        assert_eq!(Balances::free_balance(alice()), 290);
+        assert_eq!(Balances::free_balance(bob()), 545);
+
+        // the voucher can not be replayed
+        assert_eq!(
+            Micropayment::withdraw_from_channel(
+                RuntimeOrigin::signed(alice()),
+                bob(),
+                0,
+                2,
+                50,
+                100,
+                voucher.into()
+            ),
+            Err(DispatchErrorWithPostInfo::from(
+                Error::<Test>::WithdrawNonceError
+            ))
+        );
+        // session 2 was settled by the voucher and can not be claimed again
+        let msg = Micropayment::construct_byte_array_and_hash(&bob(), 0, 2, 20);
+        let signature: [u8; 64] = alice_pair.sign(&msg).into();
+        assert_eq!(
+            Micropayment::claim_payment(
+                RuntimeOrigin::signed(bob()),
+                alice(),
+                2,
+                20,
+                signature.into()
+            ),
+            Err(DispatchErrorWithPostInfo::from(Error::<Test>::SessionError))
+        );
+
+        // the voucher must be signed by the server
+        let msg = Micropayment::construct_withdraw_hash(&alice(), 0, 1, 2, 50, 10);
+        let voucher: [u8; 64] = alice_pair.sign(&msg).into();
+        assert_eq!(
+            Micropayment::withdraw_from_channel(
+                RuntimeOrigin::signed(alice()),
+                bob(),
+                1,
+                2,
+                50,
+                10,
+                voucher.into()
+            ),
+            Err(DispatchErrorWithPostInfo::from(
+                Error::<Test>::InvalidSignature
+            ))
+        );
+
+        let msg = Micropayment::construct_withdraw_hash(&alice(), 0, 1, 2, 40, 10);
+        let voucher: [u8; 64] = bob_pair.sign(&msg).into();
+        assert_eq!(
+            Micropayment::withdraw_from_channel(
+                RuntimeOrigin::signed(alice()),
+                bob(),
+                1,
+                2,
+                40,
+                10,
+                voucher.into()
+            ),
+            Err(DispatchErrorWithPostInfo::from(
+                Error::<Test>::PaidAmountTooLow
+            ))
+        );
+
+        let msg = Micropayment::construct_withdraw_hash(&alice(), 0, 1, 2, 60, 150);
+        let voucher: [u8; 64] = bob_pair.sign(&msg).into();
+        assert_eq!(
+            Micropayment::withdraw_from_channel(
+                RuntimeOrigin::signed(alice()),
+                bob(),
+                1,
+                2,
+                60,
+                150,
+                voucher.into()
+            ),
+            Err(DispatchErrorWithPostInfo::from(
+                Error::<Test>::NotEnoughBalance
+            ))
+        );
+    });
+}
+
+#[test]
+fn withdraw_keeps_pending_claim() {
+    new_test_ext().execute_with(|| {
+        let alice_pair = sr25519::Pair::from_string("//Alice", None).unwrap();
+        let bob_pair = sr25519::Pair::from_string("//Bob", None).unwrap();
+        assert_ok!(Micropayment::open_channel(
+            RuntimeOrigin::signed(alice()),
+            bob(),
+            300,
+            3600
+        ));
+        // the server holds a payment for session 1 and has not claimed it yet
+        let msg = Micropayment::construct_byte_array_and_hash(&bob(), 0, 1, 30);
+        let payment: [u8; 64] = alice_pair.sign(&msg).into();
+
+        // a withdrawal racing the claim settles nothing
+        let msg = Micropayment::construct_withdraw_hash(&alice(), 0, 0, 0, 0, 100);
+        let voucher: [u8; 64] = bob_pair.sign(&msg).into();
+        assert_ok!(Micropayment::withdraw_from_channel(
+            RuntimeOrigin::signed(alice()),
+            bob(),
+            0,
+            0,
+            0,
+            100,
+            voucher.into()
+        ));
+        assert_eq!(Micropayment::session_id((alice(), bob())), None);
+
+        assert_ok!(Micropayment::claim_payment(
+            RuntimeOrigin::signed(bob()),
+            alice(),
+            1,
+            30,
+            payment.into()
+        ));
+        assert_eq!(Micropayment::channel(&alice(), &bob()).balance, 170);
+        assert_eq!(Micropayment::paid_amount((alice(), bob())), 30);
+    });
+}
+
+#[test]
+fn rate_server() {
+    new_test_ext().execute_with(|| {
+        let alice_pair = sr25519::Pair::from_string("//Alice", None).unwrap();
+        assert_ok!(Micropayment::open_channel(
+            RuntimeOrigin::signed(alice()),
+            bob(),
+            300,
+            3600
+        ));
+        // an open channel can not be rated
+        assert_err!(
+            Micropayment::rate_server(RuntimeOrigin::signed(alice()), bob(), 0, 5),
+            Error::<Test>::ChannelNotClosed
+        );
+        assert_ok!(Micropayment::close_channel(
+            RuntimeOrigin::signed(bob()),
+            alice()
+        ));
+        // a channel which paid the server nothing can not be rated
+        assert_err!(
+            Micropayment::rate_server(RuntimeOrigin::signed(alice()), bob(), 0, 5),
+            Error::<Test>::NothingPaid
+        );
+
+        assert_ok!(Micropayment::open_channel(
+            RuntimeOrigin::signed(alice()),
+            bob(),
+            300,
+            3600
+        ));
+        let msg = Micropayment::construct_byte_array_and_hash(&bob(), 1, 1, 200);
+        let signature: [u8; 64] = alice_pair.sign(&msg).into();
+        assert_ok!(Micropayment::claim_payment(
+            RuntimeOrigin::signed(bob()),
+            alice(),
+            1,
+            200,
+            signature.into()
+        ));
+        assert_ok!(Micropayment::close_channel(
+            RuntimeOrigin::signed(bob()),
+            alice()
+        ));
+        assert_eq!(
+            Micropayment::closed_channel_paid((alice(), bob())),
+            Some((1, 200))
+        );
+
+        assert_err!(
+            Micropayment::rate_server(RuntimeOrigin::signed(alice()), bob(), 1, 0),
+            Error::<Test>::InvalidRating
+        );
+        assert_err!(
+            Micropayment::rate_server(RuntimeOrigin::signed(alice()), bob(), 1, 6),
+            Error::<Test>::InvalidRating
+        );
+        // only the client of the channel holds the ticket
+        assert_err!(
+            Micropayment::rate_server(RuntimeOrigin::signed(bob()), alice(), 1, 1),
+            Error::<Test>::ChannelNotClosed
+        );
+        // only the last closed channel can be rated
+        assert_err!(
+            Micropayment::rate_server(RuntimeOrigin::signed(alice()), bob(), 0, 5),
+            Error::<Test>::NothingPaid
+        );
+        assert_ok!(Micropayment::rate_server(
+            RuntimeOrigin::signed(alice()),
+            bob(),
+            1,
+            5
+        ));
+        assert_eq!(Micropayment::next_rating_nonce((alice(), bob())), 2);
+        // 200 paid is a rating of weight 2 next to the neutral prior of weight 1
+        assert_eq!(
+            DeeperNode::reputation(&bob()),
+            Perbill::from_rational(5u32, 6u32)
+        );
+        assert_err!(
+            Micropayment::rate_server(RuntimeOrigin::signed(alice()), bob(), 1, 5),
+            Error::<Test>::AlreadyRated
+        );
+        assert_err!(
+            Micropayment::rate_server(RuntimeOrigin::signed(alice()), bob(), 2, 5),
+            Error::<Test>::ChannelNotClosed
+        );
+    });
+}
+
 #[test]
 fn test_blake2_hash() {
     let bob: [u8; 32] = [
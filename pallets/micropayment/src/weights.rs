@@ -39,6 +39,9 @@ pub trait WeightInfo {
 	fn close_expired_channels() -> Weight;
 	fn add_balance() -> Weight;
 	fn claim_payment() -> Weight;
+	fn sweep_expired_channel() -> Weight;
+	fn withdraw_from_channel() -> Weight;
+	fn rate_server() -> Weight;
 }
 
 /// Weights for `pallet_micropayment` using the Substrate node and recommended hardware.
@@ -71,14 +74,18 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	/// Proof: `Micropayment::Nonce` (`max_values`: None, `max_size`: None, mode: `Measured`)
 	/// Storage: `Micropayment::SessionId` (r:0 w:1)
 	/// Proof: `Micropayment::SessionId` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Micropayment::PaidAmount` (r:1 w:1)
+	/// Proof: `Micropayment::PaidAmount` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Micropayment::ClosedChannelPaid` (r:0 w:1)
+	/// Proof: `Micropayment::ClosedChannelPaid` (`max_values`: None, `max_size`: None, mode: `Measured`)
 	fn close_channel() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `622`
 		//  Estimated: `6196`
 		// Minimum execution time: 1_319_969_000 picoseconds.
 		Weight::from_parts(1_343_381_000, 6196)
-			.saturating_add(T::DbWeight::get().reads(5_u64))
-			.saturating_add(T::DbWeight::get().writes(6_u64))
+			.saturating_add(T::DbWeight::get().reads(6_u64))
+			.saturating_add(T::DbWeight::get().writes(8_u64))
 	}
 	/// Storage: `Micropayment::Channel` (r:2 w:1)
 	/// Proof: `Micropayment::Channel` (`max_values`: None, `max_size`: None, mode: `Measured`)
@@ -90,14 +97,18 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	/// Proof: `Micropayment::Nonce` (`max_values`: None, `max_size`: None, mode: `Measured`)
 	/// Storage: `Micropayment::SessionId` (r:0 w:1)
 	/// Proof: `Micropayment::SessionId` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Micropayment::PaidAmount` (r:1 w:1)
+	/// Proof: `Micropayment::PaidAmount` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Micropayment::ClosedChannelPaid` (r:0 w:1)
+	/// Proof: `Micropayment::ClosedChannelPaid` (`max_values`: None, `max_size`: None, mode: `Measured`)
 	fn close_expired_channels() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `730`
 		//  Estimated: `6670`
 		// Minimum execution time: 1_423_017_000 picoseconds.
 		Weight::from_parts(1_428_569_000, 6670)
-			.saturating_add(T::DbWeight::get().reads(6_u64))
-			.saturating_add(T::DbWeight::get().writes(6_u64))
+			.saturating_add(T::DbWeight::get().reads(7_u64))
+			.saturating_add(T::DbWeight::get().writes(8_u64))
 	}
 	/// Storage: `Micropayment::Channel` (r:1 w:1)
 	/// Proof: `Micropayment::Channel` (`max_values`: None, `max_size`: None, mode: `Measured`)
@@ -131,6 +142,71 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 			.saturating_add(T::DbWeight::get().reads(5_u64))
 			.saturating_add(T::DbWeight::get().writes(5_u64))
 	}
+	/// Storage: `Micropayment::Channel` (r:1 w:1)
+	/// Proof: `Micropayment::Channel` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Micropayment::TotalMicropaymentChannelBalance` (r:1 w:1)
+	/// Proof: `Micropayment::TotalMicropaymentChannelBalance` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// Storage: `Micropayment::ChannelExpirations` (r:1 w:1)
+	/// Proof: `Micropayment::ChannelExpirations` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Micropayment::Nonce` (r:1 w:1)
+	/// Proof: `Micropayment::Nonce` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Micropayment::SessionId` (r:0 w:1)
+	/// Proof: `Micropayment::SessionId` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Micropayment::PaidAmount` (r:1 w:1)
+	/// Proof: `Micropayment::PaidAmount` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Micropayment::ClosedChannelPaid` (r:0 w:1)
+	/// Proof: `Micropayment::ClosedChannelPaid` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	fn sweep_expired_channel() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `698`
+		//  Estimated: `6638`
+		// Minimum execution time: 1_187_342_000 picoseconds.
+		Weight::from_parts(1_203_516_000, 6638)
+			.saturating_add(T::DbWeight::get().reads(6_u64))
+			.saturating_add(T::DbWeight::get().writes(8_u64))
+	}
+	/// Storage: `Micropayment::Channel` (r:1 w:1)
+	/// Proof: `Micropayment::Channel` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Micropayment::WithdrawNonce` (r:1 w:1)
+	/// Proof: `Micropayment::WithdrawNonce` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Micropayment::SessionId` (r:1 w:1)
+	/// Proof: `Micropayment::SessionId` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Micropayment::PaidAmount` (r:1 w:1)
+	/// Proof: `Micropayment::PaidAmount` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Micropayment::TotalMicropaymentChannelBalance` (r:1 w:1)
+	/// Proof: `Micropayment::TotalMicropaymentChannelBalance` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `System::Account` (r:2 w:2)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	fn withdraw_from_channel() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `618`
+		//  Estimated: `6196`
+		// Minimum execution time: 1_517_903_000 picoseconds.
+		Weight::from_parts(1_562_241_000, 6196)
+			.saturating_add(T::DbWeight::get().reads(7_u64))
+			.saturating_add(T::DbWeight::get().writes(7_u64))
+	}
+	/// Storage: `Micropayment::Nonce` (r:1 w:0)
+	/// Proof: `Micropayment::Nonce` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Micropayment::NextRatingNonce` (r:1 w:1)
+	/// Proof: `Micropayment::NextRatingNonce` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Micropayment::ClosedChannelPaid` (r:1 w:1)
+	/// Proof: `Micropayment::ClosedChannelPaid` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `System::Number` (r:1 w:0)
+	/// Proof: `System::Number` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `DeeperNode::ServerRatings` (r:1 w:1)
+	/// Proof: `DeeperNode::ServerRatings` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	fn rate_server() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `437`
+		//  Estimated: `3902`
+		// Minimum execution time: 427_815_000 picoseconds.
+		Weight::from_parts(436_204_000, 3902)
+			.saturating_add(T::DbWeight::get().reads(5_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+	}
 }
 
 // For backwards compatibility and tests.
@@ -162,14 +238,18 @@ impl WeightInfo for () {
 	/// Proof: `Micropayment::Nonce` (`max_values`: None, `max_size`: None, mode: `Measured`)
 	/// Storage: `Micropayment::SessionId` (r:0 w:1)
 	/// Proof: `Micropayment::SessionId` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Micropayment::PaidAmount` (r:1 w:1)
+	/// Proof: `Micropayment::PaidAmount` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Micropayment::ClosedChannelPaid` (r:0 w:1)
+	/// Proof: `Micropayment::ClosedChannelPaid` (`max_values`: None, `max_size`: None, mode: `Measured`)
 	fn close_channel() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `622`
 		//  Estimated: `6196`
 		// Minimum execution time: 1_319_969_000 picoseconds.
 		Weight::from_parts(1_343_381_000, 6196)
-			.saturating_add(RocksDbWeight::get().reads(5_u64))
-			.saturating_add(RocksDbWeight::get().writes(6_u64))
+			.saturating_add(RocksDbWeight::get().reads(6_u64))
+			.saturating_add(RocksDbWeight::get().writes(8_u64))
 	}
 	/// Storage: `Micropayment::Channel` (r:2 w:1)
 	/// Proof: `Micropayment::Channel` (`max_values`: None, `max_size`: None, mode: `Measured`)
@@ -181,14 +261,18 @@ impl WeightInfo for () {
 	/// Proof: `Micropayment::Nonce` (`max_values`: None, `max_size`: None, mode: `Measured`)
 	/// Storage: `Micropayment::SessionId` (r:0 w:1)
 	/// Proof: `Micropayment::SessionId` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Micropayment::PaidAmount` (r:1 w:1)
+	/// Proof: `Micropayment::PaidAmount` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Micropayment::ClosedChannelPaid` (r:0 w:1)
+	/// Proof: `Micropayment::ClosedChannelPaid` (`max_values`: None, `max_size`: None, mode: `Measured`)
 	fn close_expired_channels() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `730`
 		//  Estimated: `6670`
 		// Minimum execution time: 1_423_017_000 picoseconds.
 		Weight::from_parts(1_428_569_000, 6670)
-			.saturating_add(RocksDbWeight::get().reads(6_u64))
-			.saturating_add(RocksDbWeight::get().writes(6_u64))
+			.saturating_add(RocksDbWeight::get().reads(7_u64))
+			.saturating_add(RocksDbWeight::get().writes(8_u64))
 	}
 	/// Storage: `Micropayment::Channel` (r:1 w:1)
 	/// Proof: `Micropayment::Channel` (`max_values`: None, `max_size`: None, mode: `Measured`)
@@ -222,4 +306,69 @@ impl WeightInfo for () {
 			.saturating_add(RocksDbWeight::get().reads(5_u64))
 			.saturating_add(RocksDbWeight::get().writes(5_u64))
 	}
+	/// Storage: `Micropayment::Channel` (r:1 w:1)
+	/// Proof: `Micropayment::Channel` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Micropayment::TotalMicropaymentChannelBalance` (r:1 w:1)
+	/// Proof: `Micropayment::TotalMicropaymentChannelBalance` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// Storage: `Micropayment::ChannelExpirations` (r:1 w:1)
+	/// Proof: `Micropayment::ChannelExpirations` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Micropayment::Nonce` (r:1 w:1)
+	/// Proof: `Micropayment::Nonce` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Micropayment::SessionId` (r:0 w:1)
+	/// Proof: `Micropayment::SessionId` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Micropayment::PaidAmount` (r:1 w:1)
+	/// Proof: `Micropayment::PaidAmount` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Micropayment::ClosedChannelPaid` (r:0 w:1)
+	/// Proof: `Micropayment::ClosedChannelPaid` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	fn sweep_expired_channel() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `698`
+		//  Estimated: `6638`
+		// Minimum execution time: 1_187_342_000 picoseconds.
+		Weight::from_parts(1_203_516_000, 6638)
+			.saturating_add(RocksDbWeight::get().reads(6_u64))
+			.saturating_add(RocksDbWeight::get().writes(8_u64))
+	}
+	/// Storage: `Micropayment::Channel` (r:1 w:1)
+	/// Proof: `Micropayment::Channel` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Micropayment::WithdrawNonce` (r:1 w:1)
+	/// Proof: `Micropayment::WithdrawNonce` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Micropayment::SessionId` (r:1 w:1)
+	/// Proof: `Micropayment::SessionId` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Micropayment::PaidAmount` (r:1 w:1)
+	/// Proof: `Micropayment::PaidAmount` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Micropayment::TotalMicropaymentChannelBalance` (r:1 w:1)
+	/// Proof: `Micropayment::TotalMicropaymentChannelBalance` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `System::Account` (r:2 w:2)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	fn withdraw_from_channel() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `618`
+		//  Estimated: `6196`
+		// Minimum execution time: 1_517_903_000 picoseconds.
+		Weight::from_parts(1_562_241_000, 6196)
+			.saturating_add(RocksDbWeight::get().reads(7_u64))
+			.saturating_add(RocksDbWeight::get().writes(7_u64))
+	}
+	/// Storage: `Micropayment::Nonce` (r:1 w:0)
+	/// Proof: `Micropayment::Nonce` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Micropayment::NextRatingNonce` (r:1 w:1)
+	/// Proof: `Micropayment::NextRatingNonce` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Micropayment::ClosedChannelPaid` (r:1 w:1)
+	/// Proof: `Micropayment::ClosedChannelPaid` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `System::Number` (r:1 w:0)
+	/// Proof: `System::Number` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `DeeperNode::ServerRatings` (r:1 w:1)
+	/// Proof: `DeeperNode::ServerRatings` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	fn rate_server() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `437`
+		//  Estimated: `3902`
+		// Minimum execution time: 427_815_000 picoseconds.
+		Weight::from_parts(436_204_000, 3902)
+			.saturating_add(RocksDbWeight::get().reads(5_u64))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
+	}
 }
@@ -21,13 +21,12 @@
 
 use super::*;
 use frame_benchmarking::{account, benchmarks};
-use frame_support::traits::{Currency, Get, LockableCurrency, WithdrawReasons};
+use frame_support::traits::{Currency, Get, LockableCurrency, ReservableCurrency, WithdrawReasons};
 use frame_system::RawOrigin;
 use sp_runtime::traits::{Saturating, StaticLookup};
 
 use node_primitives::{credit::CreditInterface, user_privileges::Privilege};
 use pallet_user_privileges::Pallet as UserPrivileges;
-use scale_info::prelude::string::ToString;
 
 const SEED: u32 = 0;
 const USER_SEED: u32 = 999666;
@@ -105,7 +104,7 @@ benchmarks! {
         assert_eq!(<T as pallet::Config>::Currency::free_balance(&user),existential_deposit);
     }
 
-    bridge_deeper_to_other {
+    bridge_other_to_deeper {
         let user1: T::AccountId = account("b", 1, USER_SEED);
         let user2: T::AccountId = account("b", 2, USER_SEED);
         let account_lookup: <T::Lookup as StaticLookup>::Source = T::Lookup::unlookup(user1.clone());
@@ -115,11 +114,13 @@ benchmarks! {
         let _ = <T as pallet::Config>::Currency::make_free_balance_be(&user1, existential_deposit*2u32.into());
         let _ = <T as pallet::Config>::Currency::make_free_balance_be(&user2, existential_deposit*2u32.into());
 
-    }: bridge_deeper_to_other(RawOrigin::Signed(user1.clone()), H160::zero(),user2,existential_deposit,"test".to_string())
+        BridgeChains::<T>::insert(1, ChainName::truncate_from(b"SOL".to_vec()));
+        let source = SourceTx { tx_hash: H256::repeat_byte(1), log_index: 0 };
+    }: bridge_other_to_deeper(RawOrigin::Signed(user1.clone()), user2,ForeignAddress::truncate_from(H160::zero().as_bytes().to_vec()),existential_deposit,1,source)
     verify {
     }
 
-    bridge_other_to_deeper {
+    apply_for_bridge_transfer {
         let user1: T::AccountId = account("b", 1, USER_SEED);
         let user2: T::AccountId = account("b", 2, USER_SEED);
         let account_lookup: <T::Lookup as StaticLookup>::Source = T::Lookup::unlookup(user1.clone());
@@ -129,22 +130,88 @@ benchmarks! {
         let _ = <T as pallet::Config>::Currency::make_free_balance_be(&user1, existential_deposit*2u32.into());
         let _ = <T as pallet::Config>::Currency::make_free_balance_be(&user2, existential_deposit*2u32.into());
 
-    }: bridge_other_to_deeper(RawOrigin::Signed(user1.clone()), user2,H160::zero(),existential_deposit,"test".to_string())
+        BridgeChains::<T>::insert(1, ChainName::truncate_from(b"SOL".to_vec()));
+    }: apply_for_bridge_transfer(RawOrigin::Signed(user1.clone()), ForeignAddress::truncate_from(b"3NwgW4G8pVz7acktRwdMAKfTfw8pFw9h4if5H6P8k9sY".to_vec()),existential_deposit,1)
     verify {
+        assert!(BridgeApplications::<T>::contains_key(0));
     }
 
-    apply_for_bridge_transfer {
+    complete_bridge_application {
+        let user1: T::AccountId = account("b", 1, USER_SEED);
+        let user2: T::AccountId = account("b", 2, USER_SEED);
+        let account_lookup: <T::Lookup as StaticLookup>::Source = T::Lookup::unlookup(user1.clone());
+        let _ = UserPrivileges::<T>::set_user_privilege(RawOrigin::Root.into(),account_lookup,Privilege::BridgeAdmin);
+        BridgeFundAddreess::<T>::put(user1.clone());
+        let existential_deposit = <T as pallet::Config>::Currency::minimum_balance();
+        let _ = <T as pallet::Config>::Currency::make_free_balance_be(&user1, existential_deposit*2u32.into());
+        let _ = <T as pallet::Config>::Currency::make_free_balance_be(&user2, existential_deposit*2u32.into());
+
+        BridgeChains::<T>::insert(1, ChainName::truncate_from(b"SOL".to_vec()));
+        let _ = Pallet::<T>::apply_for_bridge_transfer(RawOrigin::Signed(user2.clone()).into(), ForeignAddress::truncate_from(b"3NwgW4G8pVz7acktRwdMAKfTfw8pFw9h4if5H6P8k9sY".to_vec()),existential_deposit,1);
+    }: complete_bridge_application(RawOrigin::Signed(user1.clone()), 0)
+    verify {
+        assert!(!BridgeApplications::<T>::contains_key(0));
+        assert_eq!(OutboundNonce::<T>::get(1), 1);
+    }
+
+    reject_bridge_application {
         let user1: T::AccountId = account("b", 1, USER_SEED);
         let user2: T::AccountId = account("b", 2, USER_SEED);
         let account_lookup: <T::Lookup as StaticLookup>::Source = T::Lookup::unlookup(user1.clone());
         let _ = UserPrivileges::<T>::set_user_privilege(RawOrigin::Root.into(),account_lookup,Privilege::BridgeAdmin);
+        let existential_deposit = <T as pallet::Config>::Currency::minimum_balance();
+        let _ = <T as pallet::Config>::Currency::make_free_balance_be(&user2, existential_deposit*2u32.into());
+
+        BridgeChains::<T>::insert(1, ChainName::truncate_from(b"SOL".to_vec()));
+        let _ = Pallet::<T>::apply_for_bridge_transfer(RawOrigin::Signed(user2.clone()).into(), ForeignAddress::truncate_from(b"3NwgW4G8pVz7acktRwdMAKfTfw8pFw9h4if5H6P8k9sY".to_vec()),existential_deposit,1);
+    }: reject_bridge_application(RawOrigin::Signed(user1.clone()), 0)
+    verify {
+        assert!(!BridgeApplications::<T>::contains_key(0));
+        assert_eq!(<T as pallet::Config>::Currency::reserved_balance(&user2), 0u32.into());
+    }
+
+    refund_bridge_application {
+        let user2: T::AccountId = account("b", 2, USER_SEED);
+        let existential_deposit = <T as pallet::Config>::Currency::minimum_balance();
+        let _ = <T as pallet::Config>::Currency::make_free_balance_be(&user2, existential_deposit*2u32.into());
+
+        BridgeChains::<T>::insert(1, ChainName::truncate_from(b"SOL".to_vec()));
+        let _ = Pallet::<T>::apply_for_bridge_transfer(RawOrigin::Signed(user2.clone()).into(), ForeignAddress::truncate_from(b"3NwgW4G8pVz7acktRwdMAKfTfw8pFw9h4if5H6P8k9sY".to_vec()),existential_deposit,1);
+        let expiry = BridgeApplications::<T>::get(0).map(|application| application.expiry).unwrap_or_default();
+    }: {
+        Pallet::<T>::refund_bridge_application(expiry, 0);
+    }
+    verify {
+        assert!(!BridgeApplications::<T>::contains_key(0));
+        assert_eq!(<T as pallet::Config>::Currency::reserved_balance(&user2), 0u32.into());
+    }
+
+    attest_inbound {
+        let r in 0 .. T::MaxRelayers::get() - 1;
+        let user1: T::AccountId = account("b", 1, USER_SEED);
+        let user2: T::AccountId = account("b", 2, USER_SEED);
         BridgeFundAddreess::<T>::put(user1.clone());
         let existential_deposit = <T as pallet::Config>::Currency::minimum_balance();
         let _ = <T as pallet::Config>::Currency::make_free_balance_be(&user1, existential_deposit*2u32.into());
         let _ = <T as pallet::Config>::Currency::make_free_balance_be(&user2, existential_deposit*2u32.into());
 
-    }: apply_for_bridge_transfer(RawOrigin::Signed(user1.clone()), "3NwgW4G8pVz7acktRwdMAKfTfw8pFw9h4if5H6P8k9sY".to_string(),existential_deposit,"SOL".to_string())
+        BridgeChains::<T>::insert(1, ChainName::truncate_from(b"SOL".to_vec()));
+        let source = SourceTx { tx_hash: H256::repeat_byte(1), log_index: 0 };
+        let from = ForeignAddress::truncate_from(H160::zero().as_bytes().to_vec());
+        let relayer: T::AccountId = account("relayer", 0, USER_SEED);
+        let mut relayers = Vec::new();
+        relayers.push(relayer.clone());
+        let mut attestations = Vec::new();
+        for i in 1 ..= r {
+            let other: T::AccountId = account("relayer", i, USER_SEED);
+            relayers.push(other.clone());
+            attestations.push((other, InboundTransfer { to: user2.clone(), from: from.clone(), amount: existential_deposit * 2u32.into() }));
+        }
+        BridgeRelayers::<T>::insert(1, (BoundedVec::truncate_from(relayers), 1));
+        InboundAttestations::<T>::insert(1, source, BoundedVec::truncate_from(attestations));
+    }: attest_inbound(RawOrigin::Signed(relayer), user2,from,existential_deposit,1,source)
     verify {
+        assert!(ProcessedInbound::<T>::contains_key(1, source));
     }
 
     impl_benchmark_test_suite!(Operation, crate::tests::new_test_ext(), crate::tests::Test);
@@ -36,22 +36,23 @@ pub mod pallet {
         ensure,
         pallet_prelude::*,
         traits::{
-            Currency, ExistenceRequirement, Get, Imbalance, LockIdentifier, LockableCurrency,
-            OnUnbalanced, ReservableCurrency, WithdrawReasons,
+            BalanceStatus, Currency, ExistenceRequirement, Get, Imbalance, LockIdentifier,
+            LockableCurrency, OnUnbalanced, PalletInfoAccess, ReservableCurrency, WithdrawReasons,
         },
         transactional,
     };
     use frame_system::{self, ensure_signed, pallet_prelude::*};
     use node_primitives::{
         credit::CreditInterface,
-        user_privileges::{Privilege, UserPrivilegeInterface},
+        sweep::sweep_expirations,
+        user_privileges::{Privilege, QuotaResource, UserPrivilegeInterface},
         OperationInterface, DPR,
     };
     use scale_info::prelude::string::{String, ToString};
-    pub use sp_core::H160;
+    pub use sp_core::{H160, H256};
     use sp_runtime::{
         traits::{Saturating, StaticLookup, UniqueSaturatedFrom, UniqueSaturatedInto, Zero},
-        RuntimeDebug,
+        Perbill, RuntimeDebug,
     };
 
     type BalanceOf<T> = <<T as pallet::Config>::Currency as Currency<
@@ -63,6 +64,95 @@ pub mod pallet {
 
     pub const MILLISECS_PER_DAY: u64 = 1000 * 3600 * 24;
 
+    /// Id of a chain the bridge is connected to
+    pub type ChainId = u32;
+    pub type ChainName = BoundedVec<u8, ConstU32<32>>;
+    /// Account on the other side of the bridge, in the encoding of its chain
+    pub type ForeignAddress = BoundedVec<u8, ConstU32<64>>;
+
+    /// Transfer on the source chain which an inbound bridge transfer credits
+    #[derive(Encode, Decode, Clone, Copy, PartialEq, Eq, RuntimeDebug, MaxEncodedLen, TypeInfo)]
+    pub struct SourceTx {
+        pub tx_hash: H256,
+        pub log_index: u32,
+    }
+
+    /// Inbound transfer as attested by a relayer
+    #[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+    pub struct InboundTransfer<AccountId, Balance> {
+        pub to: AccountId,
+        pub from: ForeignAddress,
+        pub amount: Balance,
+    }
+
+    pub type InboundTransferOf<T> =
+        InboundTransfer<<T as frame_system::Config>::AccountId, BalanceOf<T>>;
+
+    /// Outbound transfer applied for by a user, its amount stays reserved until the bridge
+    /// admin completes or rejects it, or it expires
+    #[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+    pub struct BridgeApplication<AccountId, Balance, BlockNumber> {
+        pub from: AccountId,
+        pub to: ForeignAddress,
+        pub amount: Balance,
+        pub chain: ChainId,
+        pub expiry: BlockNumber,
+    }
+
+    pub type BridgeApplicationOf<T> =
+        BridgeApplication<<T as frame_system::Config>::AccountId, BalanceOf<T>, BlockNumberFor<T>>;
+
+    /// Flow of funds watched by the circuit breaker
+    #[derive(Encode, Decode, Clone, Copy, PartialEq, Eq, RuntimeDebug, MaxEncodedLen, TypeInfo)]
+    pub enum BreakerFlow {
+        DeeperToOther(ChainId),
+        OtherToDeeper(ChainId),
+        /// staking balance released in `on_finalize`
+        Release,
+    }
+
+    impl BreakerFlow {
+        /// Calls paused when the breaker of the flow trips
+        pub fn calls(&self) -> &'static [&'static [u8]] {
+            match self {
+                BreakerFlow::DeeperToOther(_) => &[b"complete_bridge_application"],
+                BreakerFlow::OtherToDeeper(_) => &[b"bridge_other_to_deeper", b"attest_inbound"],
+                // releases can still be set up, they are paid once the breaker is reset
+                BreakerFlow::Release => &[b"release_staking_balance"],
+            }
+        }
+    }
+
+    /// Bridge transfer refused by the circuit breaker, to retry once it is reset
+    #[derive(Encode, Decode, Clone, Copy, PartialEq, Eq, RuntimeDebug, MaxEncodedLen, TypeInfo)]
+    pub enum RefusedTransfer {
+        /// outbound bridge application, completed again with `complete_bridge_application`
+        Application(u64),
+        /// inbound transfer of the source transfer, attested again with `attest_inbound`
+        Inbound(SourceTx),
+    }
+
+    /// Volume a flow may reach within `window` blocks
+    #[derive(Encode, Decode, Clone, Copy, PartialEq, Eq, RuntimeDebug, MaxEncodedLen, TypeInfo)]
+    pub struct BreakerThreshold<Balance, BlockNumber> {
+        pub limit: Balance,
+        pub window: BlockNumber,
+    }
+
+    pub type BreakerThresholdOf<T> = BreakerThreshold<BalanceOf<T>, BlockNumberFor<T>>;
+
+    /// Volume of a flow in the window starting at `start` and in the window before it
+    #[derive(
+        Encode, Decode, Clone, Copy, PartialEq, Eq, Default, RuntimeDebug, MaxEncodedLen, TypeInfo,
+    )]
+    pub struct BreakerWindow<Balance, BlockNumber> {
+        pub start: BlockNumber,
+        pub previous: Balance,
+        pub current: Balance,
+    }
+
+    pub type BreakerWindowOf<T> = BreakerWindow<BalanceOf<T>, BlockNumberFor<T>>;
+
     /// Configure the pallet by specifying the parameters and types on which it depends.
     #[pallet::config]
     pub trait Config: frame_system::Config + pallet_timestamp::Config {
@@ -74,6 +164,12 @@ pub mod pallet {
         type MinimumBurnedDPR: Get<BalanceOf<Self>>;
         type CreditInterface: CreditInterface<Self::AccountId, BalanceOf<Self>>;
         type UserPrivilegeInterface: UserPrivilegeInterface<Self::AccountId>;
+        /// Max relayers attesting inbound transfers of a chain
+        type MaxRelayers: Get<u32>;
+        /// Blocks after which an outbound bridge application not handled is refunded
+        type BridgeApplicationTimeout: Get<BlockNumberFor<Self>>;
+        /// Origin setting circuit breaker thresholds and resetting tripped breakers
+        type CircuitBreakerOrigin: EnsureOrigin<Self::RuntimeOrigin>;
     }
 
     #[pallet::pallet]
@@ -92,9 +188,82 @@ pub mod pallet {
         BurnForEZC(T::AccountId, BalanceOf<T>, H160),
         UnstakingResult(T::AccountId, String),
         GetNpowReward(T::AccountId, H160),
-        BridgeDeeperToOther(String, T::AccountId, BalanceOf<T>, String, String),
-        BridgeOtherToDeeper(T::AccountId, String, BalanceOf<T>, String, String),
-        ApplyForBridgeTransfer(T::AccountId, String, BalanceOf<T>, String),
+        BridgeDeeperToOther {
+            from: T::AccountId,
+            to: ForeignAddress,
+            amount: BalanceOf<T>,
+            chain: ChainId,
+            nonce: u64,
+        },
+        BridgeOtherToDeeper {
+            to: T::AccountId,
+            from: ForeignAddress,
+            amount: BalanceOf<T>,
+            chain: ChainId,
+            source: SourceTx,
+        },
+        ApplyForBridgeTransfer {
+            id: u64,
+            from: T::AccountId,
+            to: ForeignAddress,
+            amount: BalanceOf<T>,
+            chain: ChainId,
+        },
+        BridgeChainRegistered {
+            chain: ChainId,
+            name: ChainName,
+        },
+        BridgeChainRemoved {
+            chain: ChainId,
+        },
+        BridgeRelayersSet {
+            chain: ChainId,
+            relayers: Vec<T::AccountId>,
+            threshold: u32,
+        },
+        InboundAttested {
+            chain: ChainId,
+            source: SourceTx,
+            relayer: T::AccountId,
+        },
+        /// relayer attested a transfer other than the one credited for the source transfer
+        RelayerDisagreed {
+            chain: ChainId,
+            source: SourceTx,
+            relayer: T::AccountId,
+        },
+        /// application was bridged as the outbound transfer with `nonce`
+        BridgeApplicationCompleted {
+            id: u64,
+            nonce: u64,
+        },
+        BridgeApplicationRejected {
+            id: u64,
+        },
+        /// application was not handled in time and its amount was unreserved
+        BridgeApplicationRefunded {
+            id: u64,
+        },
+        CircuitBreakerSet {
+            flow: BreakerFlow,
+            threshold: Option<BreakerThresholdOf<T>>,
+        },
+        /// rolling volume of the flow went above its limit, the calls of the flow are paused
+        CircuitBreakerTripped {
+            flow: BreakerFlow,
+            volume: BalanceOf<T>,
+            limit: BalanceOf<T>,
+        },
+        CircuitBreakerReset {
+            flow: BreakerFlow,
+        },
+        /// a bridge transfer was refused without failing the call, so that the tripped
+        /// breaker stays paused
+        BridgeTransferRefused {
+            flow: BreakerFlow,
+            transfer: RefusedTransfer,
+            amount: BalanceOf<T>,
+        },
         DPRPrice(BalanceOf<T>, H160),
         /// Paused transaction
         Paused(String, String, T::AccountId),
@@ -119,11 +288,35 @@ pub mod pallet {
         NotAllow,
         /// invalid pallet or funciton name
         InvalidName,
+        /// chain is not registered with the bridge
+        UnknownChain,
+        /// chain id is already registered
+        ChainAlreadyRegistered,
+        /// source transfer has already been credited
+        InboundAlreadyProcessed,
+        /// account is not a relayer of the chain
+        NotRelayer,
+        /// relayer has already attested the source transfer
+        AlreadyAttested,
+        /// threshold is zero or above the number of relayers
+        InvalidThreshold,
+        TooManyRelayers,
+        /// inbound transfers of the chain are credited by relayer attestations
+        AttestationRequired,
+        BridgeApplicationNotFound,
+        BridgeAmountZero,
+        /// reserved balance of the application has been slashed
+        InsufficientReserved,
+        /// call is paused by the circuit breaker
+        CallPaused,
+        /// circuit breaker window is zero
+        InvalidBreakerWindow,
     }
 
     #[derive(Encode, Decode, Clone, Copy, PartialEq, Eq, RuntimeDebug, MaxEncodedLen, TypeInfo)]
     pub enum Releases {
         V1_0_0,
+        V2_0_0,
     }
 
     #[derive(Encode, Decode, Clone, PartialEq, Eq, MaxEncodedLen, TypeInfo)]
@@ -207,15 +400,99 @@ pub mod pallet {
     pub type AccountsReleaseInfo<T: Config> =
         StorageMap<_, Twox64Concat, T::AccountId, CurrentRelease<T>, OptionQuery>;
 
+    /// total bridged from deeper to a chain, replaces `DeeperToOtherAmount` keyed by name
     #[pallet::storage]
     #[pallet::getter(fn deeper_to_other_amount)]
-    pub type DeeperToOtherAmount<T: Config> =
-        StorageMap<_, Twox64Concat, String, BalanceOf<T>, ValueQuery>;
+    pub type DeeperToOtherByChain<T: Config> =
+        StorageMap<_, Twox64Concat, ChainId, BalanceOf<T>, ValueQuery>;
 
+    /// total bridged from a chain to deeper, replaces `OtherToDeeperAmount` keyed by name
     #[pallet::storage]
     #[pallet::getter(fn other_to_deeper_amount)]
-    pub type OtherToDeeperAmount<T: Config> =
-        StorageMap<_, Twox64Concat, String, BalanceOf<T>, ValueQuery>;
+    pub type OtherToDeeperByChain<T: Config> =
+        StorageMap<_, Twox64Concat, ChainId, BalanceOf<T>, ValueQuery>;
+
+    #[pallet::storage]
+    #[pallet::getter(fn bridge_chain)]
+    pub type BridgeChains<T: Config> = StorageMap<_, Twox64Concat, ChainId, ChainName, OptionQuery>;
+
+    /// nonce of the next outbound transfer to a chain
+    #[pallet::storage]
+    #[pallet::getter(fn outbound_nonce)]
+    pub type OutboundNonce<T: Config> = StorageMap<_, Twox64Concat, ChainId, u64, ValueQuery>;
+
+    /// source transfers already credited, with the block they were credited at
+    #[pallet::storage]
+    #[pallet::getter(fn processed_inbound)]
+    pub type ProcessedInbound<T: Config> = StorageDoubleMap<
+        _,
+        Twox64Concat,
+        ChainId,
+        Blake2_128Concat,
+        SourceTx,
+        BlockNumberFor<T>,
+        OptionQuery,
+    >;
+
+    /// relayers attesting inbound transfers of a chain, and the number of matching
+    /// attestations needed to credit one
+    #[pallet::storage]
+    #[pallet::getter(fn bridge_relayers)]
+    pub type BridgeRelayers<T: Config> = StorageMap<
+        _,
+        Twox64Concat,
+        ChainId,
+        (BoundedVec<T::AccountId, T::MaxRelayers>, u32),
+        OptionQuery,
+    >;
+
+    /// attestations of inbound transfers not credited yet, attestations of relayers removed
+    /// from the set are dropped by the next attestation of the transfer
+    #[pallet::storage]
+    #[pallet::getter(fn inbound_attestations)]
+    pub type InboundAttestations<T: Config> = StorageDoubleMap<
+        _,
+        Twox64Concat,
+        ChainId,
+        Blake2_128Concat,
+        SourceTx,
+        BoundedVec<(T::AccountId, InboundTransferOf<T>), T::MaxRelayers>,
+        ValueQuery,
+    >;
+
+    /// number of times a relayer attested a transfer other than the one credited
+    #[pallet::storage]
+    #[pallet::getter(fn relayer_disagreements)]
+    pub type RelayerDisagreements<T: Config> =
+        StorageDoubleMap<_, Twox64Concat, ChainId, Blake2_128Concat, T::AccountId, u32, ValueQuery>;
+
+    #[pallet::storage]
+    #[pallet::getter(fn next_bridge_application_id)]
+    pub type NextBridgeApplicationId<T: Config> = StorageValue<_, u64, ValueQuery>;
+
+    /// outbound transfers applied for and not handled yet
+    #[pallet::storage]
+    #[pallet::getter(fn bridge_application)]
+    pub type BridgeApplications<T: Config> =
+        StorageMap<_, Twox64Concat, u64, BridgeApplicationOf<T>, OptionQuery>;
+
+    /// pending applications by the block they expire at
+    #[pallet::storage]
+    pub(super) type BridgeApplicationExpirations<T: Config> =
+        StorageDoubleMap<_, Twox64Concat, BlockNumberFor<T>, Twox64Concat, u64, (), OptionQuery>;
+
+    // the earliest application expiry block which has not been swept yet
+    #[pallet::storage]
+    #[pallet::getter(fn next_application_expiry_to_sweep)]
+    pub(super) type NextApplicationExpiryToSweep<T: Config> =
+        StorageValue<_, BlockNumberFor<T>, ValueQuery>;
+
+    /// deeper to other and other to deeper amounts recorded under a chain name before chain
+    /// ids, moved to the chain registered with that name
+    #[pallet::storage]
+    #[pallet::getter(fn legacy_bridge_amounts)]
+    pub type LegacyBridgeAmounts<T: Config> =
+        StorageMap<_, Blake2_128Concat, Vec<u8>, (BalanceOf<T>, BalanceOf<T>), ValueQuery>;
 
     /// delegators last key
     #[pallet::storage]
@@ -237,13 +514,40 @@ pub mod pallet {
     #[pallet::storage]
     pub(super) type StorageVersion<T> = StorageValue<_, Releases>;
 
+    /// calls paused by the circuit breaker, keyed by pallet and call name, with the flow which
+    /// tripped it
     #[pallet::storage]
     #[pallet::getter(fn paused_calls)]
     pub type PausedCalls<T: Config> =
-        StorageMap<_, Twox64Concat, (Vec<u8>, Vec<u8>), T::AccountId, OptionQuery>;
+        StorageMap<_, Twox64Concat, (Vec<u8>, Vec<u8>), BreakerFlow, OptionQuery>;
+
+    #[pallet::storage]
+    #[pallet::getter(fn circuit_breaker)]
+    pub type CircuitBreakers<T: Config> =
+        StorageMap<_, Twox64Concat, BreakerFlow, BreakerThresholdOf<T>, OptionQuery>;
+
+    #[pallet::storage]
+    #[pallet::getter(fn breaker_volume)]
+    pub type BreakerVolumes<T: Config> =
+        StorageMap<_, Twox64Concat, BreakerFlow, BreakerWindowOf<T>, ValueQuery>;
 
     #[pallet::hooks]
     impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
+        fn on_runtime_upgrade() -> Weight {
+            if StorageVersion::<T>::get() == Some(Releases::V2_0_0) {
+                return T::DbWeight::get().reads(1);
+            }
+            let migrated = Self::migrate_legacy_bridge_amounts();
+            // no application expires before the upgrade, start sweeping from here
+            NextApplicationExpiryToSweep::<T>::put(<frame_system::Pallet<T>>::block_number());
+            StorageVersion::<T>::put(Releases::V2_0_0);
+            T::DbWeight::get().reads_writes(1 + migrated, 2 + 2 * migrated)
+        }
+
+        fn on_idle(now: BlockNumberFor<T>, remaining_weight: Weight) -> Weight {
+            Self::refund_expired_bridge_applications(now, remaining_weight)
+        }
+
         fn on_finalize(_: BlockNumberFor<T>) {
             let saved_day = Self::saved_day();
             let cur_time: u64 = <pallet_timestamp::Pallet<T>>::get().unique_saturated_into();
@@ -316,8 +620,10 @@ pub mod pallet {
             basic_info: ReleaseInfo<T>,
         ) -> DispatchResult {
             let setter = ensure_signed(origin)?;
+            Self::ensure_not_paused(b"unstaking_release")?;
 
-            if !Self::is_payment_address(&setter) {
+            if !T::UserPrivilegeInterface::has_approved_privilege(&setter, Privilege::ReleaseSetter)
+            {
                 Self::deposit_event(Event::UnstakingResult(
                     setter,
                     "not release owner".to_string(),
@@ -406,98 +712,102 @@ pub mod pallet {
             Ok(().into())
         }
 
+        /// Set the account bridged amounts are paid into and inbound transfers are paid from
         #[pallet::call_index(6)]
-        #[pallet::weight(Weight::from_all(10_000u64) + T::DbWeight::get().reads_writes(1,1))]
+        #[pallet::weight(Weight::from_all(10_000u64) + T::DbWeight::get().reads_writes(0,1))]
         pub fn set_fund_pool_address(
             origin: OriginFor<T>,
             funder: T::AccountId,
         ) -> DispatchResultWithPostInfo {
-            let who = ensure_signed(origin)?;
-            ensure!(
-                T::UserPrivilegeInterface::has_privilege(&who, Privilege::BridgeAdmin),
-                Error::<T>::UnauthorizedAccounts
-            );
+            ensure_root(origin)?;
             BridgeFundAddreess::<T>::put(funder);
             Ok(().into())
         }
 
-        #[pallet::call_index(7)]
-        #[pallet::weight(T::OPWeightInfo::bridge_deeper_to_other())]
-        pub fn bridge_deeper_to_other(
-            origin: OriginFor<T>,
-            to: String,
-            from: T::AccountId,
-            amount: BalanceOf<T>,
-            chain: String,
-            data: String,
-        ) -> DispatchResultWithPostInfo {
-            let who = ensure_signed(origin)?;
-            ensure!(
-                T::UserPrivilegeInterface::has_privilege(&who, Privilege::BridgeAdmin),
-                Error::<T>::UnauthorizedAccounts
-            );
-            let funder = Self::bridge_fund_address();
-            ensure!(funder.is_some(), Error::<T>::FundPoolNotSet);
-
-            T::Currency::transfer(
-                &from,
-                &funder.unwrap(),
-                amount,
-                ExistenceRequirement::KeepAlive,
-            )?;
-            DeeperToOtherAmount::<T>::mutate(&chain, |balance| {
-                *balance = balance.saturating_add(amount);
-            });
-            Self::deposit_event(Event::<T>::BridgeDeeperToOther(
-                to, from, amount, chain, data,
-            ));
-            Ok(().into())
-        }
-
         #[pallet::call_index(8)]
         #[pallet::weight(T::OPWeightInfo::bridge_other_to_deeper())]
         pub fn bridge_other_to_deeper(
             origin: OriginFor<T>,
             to: T::AccountId,
-            from: String,
+            from: ForeignAddress,
             amount: BalanceOf<T>,
-            chain: String,
-            data: String,
+            chain: ChainId,
+            source: SourceTx,
         ) -> DispatchResultWithPostInfo {
             let who = ensure_signed(origin)?;
+            Self::ensure_not_paused(b"bridge_other_to_deeper")?;
             ensure!(
-                T::UserPrivilegeInterface::has_privilege(&who, Privilege::BridgeAdmin),
+                T::UserPrivilegeInterface::has_approved_privilege(&who, Privilege::BridgeAdmin),
                 Error::<T>::UnauthorizedAccounts
             );
-            let funder = Self::bridge_fund_address();
-            ensure!(funder.is_some(), Error::<T>::FundPoolNotSet);
-
-            T::Currency::transfer(
-                &funder.unwrap(),
-                &to,
-                amount,
-                ExistenceRequirement::KeepAlive,
-            )?;
-            OtherToDeeperAmount::<T>::mutate(&chain, |balance| {
-                *balance = balance.saturating_add(amount);
-            });
-            Self::deposit_event(Event::<T>::BridgeOtherToDeeper(
-                to, from, amount, chain, data,
-            ));
+            ensure!(
+                Self::bridge_fund_address().is_some(),
+                Error::<T>::FundPoolNotSet
+            );
+            ensure!(
+                BridgeChains::<T>::contains_key(chain),
+                Error::<T>::UnknownChain
+            );
+            ensure!(
+                !BridgeRelayers::<T>::contains_key(chain),
+                Error::<T>::AttestationRequired
+            );
+            ensure!(
+                !ProcessedInbound::<T>::contains_key(chain, source),
+                Error::<T>::InboundAlreadyProcessed
+            );
+            if Self::credit_inbound(chain, source, InboundTransfer { to, from, amount })? {
+                T::UserPrivilegeInterface::consume_quota(
+                    &who,
+                    Privilege::BridgeAdmin,
+                    QuotaResource::BridgeTransfer,
+                    amount.unique_saturated_into(),
+                )?;
+            }
             Ok(().into())
         }
 
+        /// Apply for an outbound transfer, the amount is reserved until the bridge admin
+        /// completes or rejects the application, and refunded if neither happens before
+        /// `BridgeApplicationTimeout`
         #[pallet::call_index(9)]
-        #[pallet::weight(T::OPWeightInfo::bridge_deeper_to_other())]
+        #[pallet::weight(T::OPWeightInfo::apply_for_bridge_transfer())]
         pub fn apply_for_bridge_transfer(
             origin: OriginFor<T>,
-            to: String,
+            to: ForeignAddress,
             amount: BalanceOf<T>,
-            chain: String,
+            chain: ChainId,
         ) -> DispatchResultWithPostInfo {
             let from = ensure_signed(origin)?;
+            ensure!(!amount.is_zero(), Error::<T>::BridgeAmountZero);
+            ensure!(
+                BridgeChains::<T>::contains_key(chain),
+                Error::<T>::UnknownChain
+            );
 
-            Self::deposit_event(Event::<T>::ApplyForBridgeTransfer(from, to, amount, chain));
+            T::Currency::reserve(&from, amount)?;
+            let id = Self::next_bridge_application_id();
+            NextBridgeApplicationId::<T>::put(id.wrapping_add(1));
+            let expiry = <frame_system::Pallet<T>>::block_number()
+                .saturating_add(T::BridgeApplicationTimeout::get());
+            BridgeApplications::<T>::insert(
+                id,
+                BridgeApplication {
+                    from: from.clone(),
+                    to: to.clone(),
+                    amount,
+                    chain,
+                    expiry,
+                },
+            );
+            BridgeApplicationExpirations::<T>::insert(expiry, id, ());
+            Self::deposit_event(Event::<T>::ApplyForBridgeTransfer {
+                id,
+                from,
+                to,
+                amount,
+                chain,
+            });
             Ok(().into())
         }
 
@@ -521,9 +831,461 @@ pub mod pallet {
             Self::deposit_event(Event::UnLocked(from));
             Ok(().into())
         }
+
+        /// Register a chain with the bridge, amounts bridged before chain ids under the same
+        /// name are carried over
+        #[pallet::call_index(12)]
+        #[pallet::weight(Weight::from_all(10_000u64) + T::DbWeight::get().reads_writes(4,4))]
+        pub fn register_bridge_chain(
+            origin: OriginFor<T>,
+            chain: ChainId,
+            name: ChainName,
+        ) -> DispatchResultWithPostInfo {
+            ensure_root(origin)?;
+            ensure!(
+                !BridgeChains::<T>::contains_key(chain),
+                Error::<T>::ChainAlreadyRegistered
+            );
+            let (deeper_to_other, other_to_deeper) = LegacyBridgeAmounts::<T>::take(name.to_vec());
+            DeeperToOtherByChain::<T>::mutate(chain, |balance| {
+                *balance = balance.saturating_add(deeper_to_other);
+            });
+            OtherToDeeperByChain::<T>::mutate(chain, |balance| {
+                *balance = balance.saturating_add(other_to_deeper);
+            });
+            BridgeChains::<T>::insert(chain, name.clone());
+            Self::deposit_event(Event::BridgeChainRegistered { chain, name });
+            Ok(().into())
+        }
+
+        /// Stop bridging with a chain, its amounts and processed transfers are kept
+        #[pallet::call_index(13)]
+        #[pallet::weight(Weight::from_all(10_000u64) + T::DbWeight::get().reads_writes(1,1))]
+        pub fn remove_bridge_chain(
+            origin: OriginFor<T>,
+            chain: ChainId,
+        ) -> DispatchResultWithPostInfo {
+            ensure_root(origin)?;
+            ensure!(
+                BridgeChains::<T>::take(chain).is_some(),
+                Error::<T>::UnknownChain
+            );
+            Self::deposit_event(Event::BridgeChainRemoved { chain });
+            Ok(().into())
+        }
+
+        /// Set the relayers of a chain and the number of matching attestations needed to credit
+        /// an inbound transfer, an empty set lets `BridgeAdmin` credit transfers again
+        #[pallet::call_index(14)]
+        #[pallet::weight(Weight::from_all(10_000u64) + T::DbWeight::get().reads_writes(1,1))]
+        pub fn set_bridge_relayers(
+            origin: OriginFor<T>,
+            chain: ChainId,
+            mut relayers: Vec<T::AccountId>,
+            threshold: u32,
+        ) -> DispatchResultWithPostInfo {
+            ensure_root(origin)?;
+            ensure!(
+                BridgeChains::<T>::contains_key(chain),
+                Error::<T>::UnknownChain
+            );
+            relayers.sort();
+            relayers.dedup();
+            if relayers.is_empty() {
+                BridgeRelayers::<T>::remove(chain);
+            } else {
+                ensure!(
+                    threshold > 0 && threshold as usize <= relayers.len(),
+                    Error::<T>::InvalidThreshold
+                );
+                let bounded =
+                    BoundedVec::<T::AccountId, T::MaxRelayers>::try_from(relayers.clone())
+                        .map_err(|_| Error::<T>::TooManyRelayers)?;
+                BridgeRelayers::<T>::insert(chain, (bounded, threshold));
+            }
+            Self::deposit_event(Event::BridgeRelayersSet {
+                chain,
+                relayers,
+                threshold,
+            });
+            Ok(().into())
+        }
+
+        /// Attest an inbound transfer as a relayer of the chain, the transfer is credited once
+        /// enough relayers attested the same transfer, and relayers which attested a different
+        /// one are recorded in `RelayerDisagreements`
+        #[pallet::call_index(15)]
+        #[pallet::weight(T::OPWeightInfo::attest_inbound(T::MaxRelayers::get()))]
+        pub fn attest_inbound(
+            origin: OriginFor<T>,
+            to: T::AccountId,
+            from: ForeignAddress,
+            amount: BalanceOf<T>,
+            chain: ChainId,
+            source: SourceTx,
+        ) -> DispatchResultWithPostInfo {
+            let relayer = ensure_signed(origin)?;
+            Self::ensure_not_paused(b"attest_inbound")?;
+            ensure!(
+                BridgeChains::<T>::contains_key(chain),
+                Error::<T>::UnknownChain
+            );
+            let (relayers, threshold) =
+                Self::bridge_relayers(chain).ok_or(Error::<T>::NotRelayer)?;
+            ensure!(relayers.contains(&relayer), Error::<T>::NotRelayer);
+            ensure!(
+                !ProcessedInbound::<T>::contains_key(chain, source),
+                Error::<T>::InboundAlreadyProcessed
+            );
+            let mut attestations = Self::inbound_attestations(chain, source);
+            // attestations of relayers removed from the set no longer count
+            attestations.retain(|(who, _)| relayers.contains(who));
+            let transfer = InboundTransfer { to, from, amount };
+            let retry = attestations.iter().any(|(who, _)| *who == relayer);
+            if !retry {
+                attestations
+                    .try_push((relayer.clone(), transfer.clone()))
+                    .map_err(|_| Error::<T>::TooManyRelayers)?;
+                Self::deposit_event(Event::InboundAttested {
+                    chain,
+                    source,
+                    relayer: relayer.clone(),
+                });
+            }
+            let matching = attestations
+                .iter()
+                .filter(|(_, attested)| *attested == transfer)
+                .count();
+            // attesting again only retries a transfer the circuit breaker refused
+            ensure!(
+                !retry
+                    || (matching >= threshold as usize
+                        && attestations.contains(&(relayer, transfer.clone()))),
+                Error::<T>::AlreadyAttested
+            );
+            if matching < threshold as usize {
+                InboundAttestations::<T>::insert(chain, source, attestations);
+                return Ok(().into());
+            }
+            if !Self::credit_inbound(chain, source, transfer.clone())? {
+                InboundAttestations::<T>::insert(chain, source, attestations);
+                return Ok(().into());
+            }
+
+            InboundAttestations::<T>::remove(chain, source);
+            let mut disagreements = 0u32;
+            for (who, attested) in attestations {
+                if attested != transfer {
+                    RelayerDisagreements::<T>::mutate(chain, &who, |count| {
+                        *count = count.saturating_add(1)
+                    });
+                    Self::deposit_event(Event::RelayerDisagreed {
+                        chain,
+                        source,
+                        relayer: who,
+                    });
+                    disagreements += 1;
+                }
+            }
+            Ok(Some(T::OPWeightInfo::attest_inbound(disagreements)).into())
+        }
+
+        /// Bridge an application, its reserved amount is moved to the fund pool
+        #[pallet::call_index(16)]
+        #[pallet::weight(T::OPWeightInfo::complete_bridge_application())]
+        pub fn complete_bridge_application(
+            origin: OriginFor<T>,
+            id: u64,
+        ) -> DispatchResultWithPostInfo {
+            let who = ensure_signed(origin)?;
+            Self::ensure_not_paused(b"complete_bridge_application")?;
+            ensure!(
+                T::UserPrivilegeInterface::has_approved_privilege(&who, Privilege::BridgeAdmin),
+                Error::<T>::UnauthorizedAccounts
+            );
+            let funder = Self::bridge_fund_address().ok_or(Error::<T>::FundPoolNotSet)?;
+            let application =
+                Self::bridge_application(id).ok_or(Error::<T>::BridgeApplicationNotFound)?;
+            ensure!(
+                BridgeChains::<T>::contains_key(application.chain),
+                Error::<T>::UnknownChain
+            );
+            // the application stays pending, it can be completed once the breaker is reset
+            let flow = BreakerFlow::DeeperToOther(application.chain);
+            if !Self::admit_flow_volume(flow, application.amount) {
+                Self::deposit_event(Event::BridgeTransferRefused {
+                    flow,
+                    transfer: RefusedTransfer::Application(id),
+                    amount: application.amount,
+                });
+                return Ok(().into());
+            }
+
+            Self::remove_bridge_application(id, application.expiry);
+            let missing = T::Currency::repatriate_reserved(
+                &application.from,
+                &funder,
+                application.amount,
+                BalanceStatus::Free,
+            )?;
+            ensure!(missing.is_zero(), Error::<T>::InsufficientReserved);
+            let nonce = Self::record_outbound(
+                application.from,
+                application.to,
+                application.amount,
+                application.chain,
+            );
+            Self::deposit_event(Event::BridgeApplicationCompleted { id, nonce });
+            Ok(().into())
+        }
+
+        /// Reject an application and unreserve its amount
+        #[pallet::call_index(17)]
+        #[pallet::weight(T::OPWeightInfo::reject_bridge_application())]
+        pub fn reject_bridge_application(
+            origin: OriginFor<T>,
+            id: u64,
+        ) -> DispatchResultWithPostInfo {
+            let who = ensure_signed(origin)?;
+            ensure!(
+                T::UserPrivilegeInterface::has_privilege(&who, Privilege::BridgeAdmin),
+                Error::<T>::UnauthorizedAccounts
+            );
+            let application =
+                Self::bridge_application(id).ok_or(Error::<T>::BridgeApplicationNotFound)?;
+
+            Self::remove_bridge_application(id, application.expiry);
+            T::Currency::unreserve(&application.from, application.amount);
+            Self::deposit_event(Event::BridgeApplicationRejected { id });
+            Ok(().into())
+        }
+
+        /// Set the volume a flow may reach within a rolling window before its calls are
+        /// paused, `None` stops watching the flow
+        #[pallet::call_index(18)]
+        #[pallet::weight(Weight::from_all(10_000u64) + T::DbWeight::get().reads_writes(0,2))]
+        pub fn set_circuit_breaker(
+            origin: OriginFor<T>,
+            flow: BreakerFlow,
+            threshold: Option<BreakerThresholdOf<T>>,
+        ) -> DispatchResultWithPostInfo {
+            T::CircuitBreakerOrigin::ensure_origin(origin)?;
+            match threshold {
+                Some(threshold) => {
+                    ensure!(
+                        !threshold.window.is_zero(),
+                        Error::<T>::InvalidBreakerWindow
+                    );
+                    CircuitBreakers::<T>::insert(flow, threshold);
+                }
+                None => CircuitBreakers::<T>::remove(flow),
+            }
+            // the volume was measured against the previous window
+            BreakerVolumes::<T>::remove(flow);
+            Self::deposit_event(Event::CircuitBreakerSet { flow, threshold });
+            Ok(().into())
+        }
+
+        /// Unpause the calls of a tripped flow and clear its volume
+        #[pallet::call_index(19)]
+        #[pallet::weight(Weight::from_all(10_000u64) + T::DbWeight::get().reads_writes(0,3))]
+        pub fn reset_circuit_breaker(
+            origin: OriginFor<T>,
+            flow: BreakerFlow,
+        ) -> DispatchResultWithPostInfo {
+            T::CircuitBreakerOrigin::ensure_origin(origin)?;
+            let pallet = Self::pallet_name();
+            for call in flow.calls() {
+                if PausedCalls::<T>::take((pallet.clone(), call.to_vec())).is_some() {
+                    Self::deposit_event(Event::Unpaused(
+                        String::from_utf8_lossy(&pallet).into_owned(),
+                        String::from_utf8_lossy(call).into_owned(),
+                    ));
+                }
+            }
+            BreakerVolumes::<T>::remove(flow);
+            Self::deposit_event(Event::CircuitBreakerReset { flow });
+            Ok(().into())
+        }
     }
 
     impl<T: Config> Pallet<T> {
+        /// Move the amounts keyed by chain name to `LegacyBridgeAmounts`, returns the number of
+        /// entries moved
+        fn migrate_legacy_bridge_amounts() -> u64 {
+            use frame_support::storage::migration::storage_key_iter;
+
+            let pallet = <Pallet<T> as PalletInfoAccess>::name().as_bytes();
+            let mut migrated = 0u64;
+            for (chain, amount) in storage_key_iter::<String, BalanceOf<T>, Twox64Concat>(
+                pallet,
+                b"DeeperToOtherAmount",
+            )
+            .drain()
+            {
+                LegacyBridgeAmounts::<T>::mutate(chain.into_bytes(), |amounts| amounts.0 = amount);
+                migrated += 1;
+            }
+            for (chain, amount) in storage_key_iter::<String, BalanceOf<T>, Twox64Concat>(
+                pallet,
+                b"OtherToDeeperAmount",
+            )
+            .drain()
+            {
+                LegacyBridgeAmounts::<T>::mutate(chain.into_bytes(), |amounts| amounts.1 = amount);
+                migrated += 1;
+            }
+            migrated
+        }
+
+        /// Account an outbound transfer whose amount is already in the fund pool and admitted by
+        /// the circuit breaker, returns its nonce
+        fn record_outbound(
+            from: T::AccountId,
+            to: ForeignAddress,
+            amount: BalanceOf<T>,
+            chain: ChainId,
+        ) -> u64 {
+            DeeperToOtherByChain::<T>::mutate(chain, |balance| {
+                *balance = balance.saturating_add(amount);
+            });
+            let nonce = Self::outbound_nonce(chain);
+            OutboundNonce::<T>::insert(chain, nonce.wrapping_add(1));
+            Self::deposit_event(Event::<T>::BridgeDeeperToOther {
+                from,
+                to,
+                amount,
+                chain,
+                nonce,
+            });
+            nonce
+        }
+
+        fn pallet_name() -> Vec<u8> {
+            <Pallet<T> as PalletInfoAccess>::name().as_bytes().to_vec()
+        }
+
+        fn is_paused(call: &[u8]) -> bool {
+            PausedCalls::<T>::contains_key((Self::pallet_name(), call.to_vec()))
+        }
+
+        fn ensure_not_paused(call: &[u8]) -> DispatchResult {
+            ensure!(!Self::is_paused(call), Error::<T>::CallPaused);
+            Ok(())
+        }
+
+        /// Add `amount` to the rolling volume of `flow` before the funds move, returns whether it
+        /// was admitted. When the volume would go above the limit nothing is added and the
+        /// breaker trips instead. The rolling volume is the volume of the current window plus
+        /// the part of the previous window still covered by the rolling one.
+        pub(crate) fn admit_flow_volume(flow: BreakerFlow, amount: BalanceOf<T>) -> bool {
+            let threshold = match Self::circuit_breaker(flow) {
+                Some(threshold) => threshold,
+                None => return true,
+            };
+            let now = <frame_system::Pallet<T>>::block_number();
+            let window_start = now.saturating_sub(now % threshold.window);
+            let mut volume = Self::breaker_volume(flow);
+            if volume.start != window_start {
+                volume.previous = if volume.start.saturating_add(threshold.window) == window_start {
+                    volume.current
+                } else {
+                    Zero::zero()
+                };
+                volume.current = Zero::zero();
+                volume.start = window_start;
+            }
+            volume.current = volume.current.saturating_add(amount);
+
+            let window: u64 = threshold.window.unique_saturated_into();
+            let elapsed: u64 = (now - window_start).unique_saturated_into();
+            let rolling = (Perbill::from_rational(window - elapsed, window) * volume.previous)
+                .saturating_add(volume.current);
+            if rolling <= threshold.limit {
+                BreakerVolumes::<T>::insert(flow, volume);
+                return true;
+            }
+            let pallet = Self::pallet_name();
+            for call in flow.calls() {
+                PausedCalls::<T>::insert((pallet.clone(), call.to_vec()), flow);
+            }
+            Self::deposit_event(Event::CircuitBreakerTripped {
+                flow,
+                volume: rolling,
+                limit: threshold.limit,
+            });
+            false
+        }
+
+        fn remove_bridge_application(id: u64, expiry: BlockNumberFor<T>) {
+            BridgeApplications::<T>::remove(id);
+            BridgeApplicationExpirations::<T>::remove(expiry, id);
+        }
+
+        /// Refund the applications whose expiry block is not after `now`, within
+        /// `remaining_weight`. Expiry blocks are visited in order starting from
+        /// `NextApplicationExpiryToSweep`.
+        pub(crate) fn refund_expired_bridge_applications(
+            now: BlockNumberFor<T>,
+            remaining_weight: Weight,
+        ) -> Weight {
+            sweep_expirations::<
+                NextApplicationExpiryToSweep<T>,
+                BridgeApplicationExpirations<T>,
+                _,
+                _,
+                _,
+            >(
+                now.saturating_add(1u32.into()),
+                remaining_weight,
+                T::DbWeight::get(),
+                T::OPWeightInfo::refund_bridge_application(),
+                Self::refund_bridge_application,
+            )
+        }
+
+        /// Unreserve an application which expired at `block`
+        pub(crate) fn refund_bridge_application(block: BlockNumberFor<T>, id: u64) {
+            BridgeApplicationExpirations::<T>::remove(block, id);
+            if let Some(application) = BridgeApplications::<T>::take(id) {
+                T::Currency::unreserve(&application.from, application.amount);
+                Self::deposit_event(Event::BridgeApplicationRefunded { id });
+            }
+        }
+
+        /// Pay an inbound transfer from the fund pool and mark its source transfer processed,
+        /// returns false without paying when the circuit breaker refuses the transfer
+        fn credit_inbound(
+            chain: ChainId,
+            source: SourceTx,
+            transfer: InboundTransferOf<T>,
+        ) -> Result<bool, DispatchError> {
+            let funder = Self::bridge_fund_address().ok_or(Error::<T>::FundPoolNotSet)?;
+            let InboundTransfer { to, from, amount } = transfer;
+            let flow = BreakerFlow::OtherToDeeper(chain);
+            if !Self::admit_flow_volume(flow, amount) {
+                Self::deposit_event(Event::BridgeTransferRefused {
+                    flow,
+                    transfer: RefusedTransfer::Inbound(source),
+                    amount,
+                });
+                return Ok(false);
+            }
+            T::Currency::transfer(&funder, &to, amount, ExistenceRequirement::KeepAlive)?;
+            OtherToDeeperByChain::<T>::mutate(chain, |balance| {
+                *balance = balance.saturating_add(amount);
+            });
+            ProcessedInbound::<T>::insert(chain, source, <frame_system::Pallet<T>>::block_number());
+            Self::deposit_event(Event::<T>::BridgeOtherToDeeper {
+                to,
+                from,
+                amount,
+                chain,
+                source,
+            });
+            Ok(true)
+        }
+
         fn get_account_release_prefix_hash() -> Vec<u8> {
             use frame_support::storage::generator::StorageMap;
             AccountsReleaseInfo::<T>::prefix_hash()
@@ -538,7 +1300,7 @@ pub mod pallet {
         }
 
         fn release_staking_balance(cur_day: u32) {
-            if Self::day_release_end() {
+            if Self::day_release_end() || Self::is_paused(b"release_staking_balance") {
                 return;
             }
             let prefix = Self::get_account_release_prefix_hash();
@@ -573,6 +1335,10 @@ pub mod pallet {
                         break;
                     }
 
+                    // the account is released first once the breaker is reset
+                    if !Self::admit_flow_volume(BreakerFlow::Release, released_balance) {
+                        break;
+                    }
                     let imbalance =
                         T::Currency::deposit_creating(&data.basic_info.account, released_balance);
                     total_release += released_balance;
@@ -20,19 +20,19 @@
 use sp_core::H256;
 use sp_runtime::{
     traits::{BlakeTwo256, IdentityLookup},
-    BuildStorage, Perbill,
+    BuildStorage, DispatchResult, Perbill,
 };
 
 use frame_support::{
-    assert_ok, parameter_types,
-    traits::{ConstU32, OnFinalize, OnInitialize},
+    assert_noop, assert_ok, parameter_types,
+    traits::{ConstU32, ConstU64, OnFinalize, OnIdle, OnInitialize, OnRuntimeUpgrade},
     weights::Weight,
 };
 
 use super::*;
 use crate::{self as pallet_operation};
 use node_primitives::{
-    user_privileges::{Privilege, UserPrivilegeInterface},
+    user_privileges::{Privilege, QuotaResource, UserPrivilegeInterface},
     BlockNumber, Moment,
 };
 type Block = frame_system::mocking::MockBlock<Test>;
@@ -128,6 +128,9 @@ impl Config for Test {
     type MinimumBurnedDPR = MinimumBurnedDPR;
     type CreditInterface = ();
     type UserPrivilegeInterface = U128FakeUserPrivilege;
+    type MaxRelayers = ConstU32<16>;
+    type BridgeApplicationTimeout = ConstU64<10>;
+    type CircuitBreakerOrigin = frame_system::EnsureRoot<u128>;
 }
 
 pub fn new_test_ext() -> sp_io::TestExternalities {
@@ -251,6 +254,19 @@ impl UserPrivilegeInterface<u128> for U128FakeUserPrivilege {
     fn has_evm_privilege(_user: &H160, _p: Privilege) -> bool {
         true
     }
+
+    fn has_approved_privilege(user: &u128, p: Privilege) -> bool {
+        Self::has_privilege(user, p)
+    }
+
+    fn consume_quota(
+        _user: &u128,
+        _p: Privilege,
+        _resource: QuotaResource,
+        _amount: u128,
+    ) -> DispatchResult {
+        Ok(())
+    }
 }
 
 #[test]
@@ -258,126 +274,760 @@ fn bridge_test() {
     new_test_ext().execute_with(|| {
         run_to_block(1);
         assert_ok!(Balances::force_set_balance(RuntimeOrigin::root(), 2, 1_000));
-        assert_ok!(Operation::set_fund_pool_address(
-            RuntimeOrigin::signed(1),
-            2
-        ));
+        // bridge admins may not redirect the fund pool
+        assert_noop!(
+            Operation::set_fund_pool_address(RuntimeOrigin::signed(1), 3),
+            sp_runtime::traits::BadOrigin
+        );
+        assert_ok!(Operation::set_fund_pool_address(RuntimeOrigin::root(), 2));
+        let sol =
+            ForeignAddress::truncate_from(b"3NwgW4G8pVz7acktRwdMAKfTfw8pFw9h4if5H6P8k9sY".to_vec());
+        let source = |log_index| SourceTx {
+            tx_hash: H256::repeat_byte(7),
+            log_index,
+        };
 
         assert_eq!(Balances::free_balance(&3), 0);
 
+        assert_noop!(
+            Operation::apply_for_bridge_transfer(RuntimeOrigin::signed(1), sol.clone(), 200, 1),
+            Error::<Test>::UnknownChain
+        );
+        assert_noop!(
+            Operation::register_bridge_chain(
+                RuntimeOrigin::signed(1),
+                1,
+                ChainName::truncate_from(b"SOL".to_vec())
+            ),
+            sp_runtime::traits::BadOrigin
+        );
+        assert_ok!(Operation::register_bridge_chain(
+            RuntimeOrigin::root(),
+            1,
+            ChainName::truncate_from(b"SOL".to_vec())
+        ));
+        assert_noop!(
+            Operation::register_bridge_chain(
+                RuntimeOrigin::root(),
+                1,
+                ChainName::truncate_from(b"ETH".to_vec())
+            ),
+            Error::<Test>::ChainAlreadyRegistered
+        );
+
         assert_ok!(Operation::apply_for_bridge_transfer(
             RuntimeOrigin::signed(1),
-            "3NwgW4G8pVz7acktRwdMAKfTfw8pFw9h4if5H6P8k9sY".to_string(),
-            200,
-            "SOL".to_string()
+            sol.clone(),
+            50,
+            1
         ));
         assert_eq!(
             <frame_system::Pallet<Test>>::events()
                 .pop()
                 .expect("should contains events")
                 .event,
-            crate::tests::RuntimeEvent::from(crate::Event::ApplyForBridgeTransfer(
-                1,
-                "3NwgW4G8pVz7acktRwdMAKfTfw8pFw9h4if5H6P8k9sY".to_string(),
-                200,
-                "SOL".to_string()
-            ))
+            crate::tests::RuntimeEvent::from(crate::Event::ApplyForBridgeTransfer {
+                id: 0,
+                from: 1,
+                to: sol.clone(),
+                amount: 50,
+                chain: 1
+            })
         );
+        assert_eq!(Balances::reserved_balance(&1), 50);
 
         assert_ok!(Operation::bridge_other_to_deeper(
             RuntimeOrigin::signed(1),
             3,
-            "3NwgW4G8pVz7acktRwdMAKfTfw8pFw9h4if5H6P8k9sY".to_string(),
+            sol.clone(),
             200,
-            "SOL".to_string(),
-            "1".to_string()
+            1,
+            source(0)
         ));
-        assert_eq!(Operation::other_to_deeper_amount("SOL".to_string()), 200);
+        assert_eq!(Operation::other_to_deeper_amount(1), 200);
         assert_eq!(
             <frame_system::Pallet<Test>>::events()
                 .pop()
                 .expect("should contains events")
                 .event,
-            crate::tests::RuntimeEvent::from(crate::Event::BridgeOtherToDeeper(
-                3,
-                "3NwgW4G8pVz7acktRwdMAKfTfw8pFw9h4if5H6P8k9sY".to_string(),
-                200,
-                "SOL".to_string(),
-                "1".to_string()
-            ))
+            crate::tests::RuntimeEvent::from(crate::Event::BridgeOtherToDeeper {
+                to: 3,
+                from: sol.clone(),
+                amount: 200,
+                chain: 1,
+                source: source(0)
+            })
         );
         assert_eq!(Balances::free_balance(&3), 200);
+        assert_eq!(Operation::processed_inbound(1, source(0)), Some(1));
 
+        // the same source transfer is credited only once
+        assert_noop!(
+            Operation::bridge_other_to_deeper(
+                RuntimeOrigin::signed(1),
+                3,
+                sol.clone(),
+                200,
+                1,
+                source(0)
+            ),
+            Error::<Test>::InboundAlreadyProcessed
+        );
+        assert_noop!(
+            Operation::bridge_other_to_deeper(
+                RuntimeOrigin::signed(1),
+                3,
+                sol.clone(),
+                300,
+                2,
+                source(1)
+            ),
+            Error::<Test>::UnknownChain
+        );
         assert_ok!(Operation::bridge_other_to_deeper(
             RuntimeOrigin::signed(1),
             3,
-            "3NwgW4G8pVz7acktRwdMAKfTfw8pFw9h4if5H6P8k9sY".to_string(),
+            sol.clone(),
             300,
-            "SOL".to_string(),
-            "1".to_string()
+            1,
+            source(1)
         ));
-        assert_eq!(Operation::other_to_deeper_amount("SOL".to_string()), 500);
+        assert_eq!(Operation::other_to_deeper_amount(1), 500);
+        assert_eq!(Balances::free_balance(&3), 500);
+
+        // outbound transfers only leave through a completed application
+        assert_ok!(Operation::apply_for_bridge_transfer(
+            RuntimeOrigin::signed(3),
+            sol.clone(),
+            100,
+            1
+        ));
+        assert_ok!(Operation::complete_bridge_application(
+            RuntimeOrigin::signed(1),
+            0
+        ));
+        assert_eq!(Operation::deeper_to_other_amount(1), 100);
+        assert!(<frame_system::Pallet<Test>>::events().iter().any(|record| {
+            record.event
+                == RuntimeEvent::from(crate::Event::BridgeDeeperToOther {
+                    from: 3,
+                    to: sol.clone(),
+                    amount: 100,
+                    chain: 1,
+                    nonce: 0,
+                })
+        }));
+        assert_eq!(Balances::free_balance(&3), 400);
+
+        assert_ok!(Operation::apply_for_bridge_transfer(
+            RuntimeOrigin::signed(3),
+            sol.clone(),
+            250,
+            1
+        ));
+        assert_ok!(Operation::complete_bridge_application(
+            RuntimeOrigin::signed(1),
+            1
+        ));
+        assert_eq!(Operation::deeper_to_other_amount(1), 350);
+        assert_eq!(Operation::outbound_nonce(1), 2);
+        assert_eq!(Balances::free_balance(&3), 150);
+
+        assert_ok!(Operation::remove_bridge_chain(RuntimeOrigin::root(), 1));
+        assert_noop!(
+            Operation::apply_for_bridge_transfer(RuntimeOrigin::signed(3), sol, 50, 1),
+            Error::<Test>::UnknownChain
+        );
+        assert_eq!(Operation::deeper_to_other_amount(1), 350);
+    });
+}
+
+#[test]
+fn legacy_bridge_amounts_move_to_registered_chain() {
+    new_test_ext().execute_with(|| {
+        use frame_support::{storage::migration::put_storage_value, Hashable};
+
+        let key = "SOL".to_string().twox_64_concat();
+        put_storage_value(b"Operation", b"DeeperToOtherAmount", &key, 350u64);
+        put_storage_value(b"Operation", b"OtherToDeeperAmount", &key, 500u64);
+
+        Operation::on_runtime_upgrade();
         assert_eq!(
-            <frame_system::Pallet<Test>>::events()
-                .pop()
-                .expect("should contains events")
-                .event,
-            crate::tests::RuntimeEvent::from(crate::Event::BridgeOtherToDeeper(
+            Operation::legacy_bridge_amounts(b"SOL".to_vec()),
+            (350, 500)
+        );
+
+        assert_ok!(Operation::register_bridge_chain(
+            RuntimeOrigin::root(),
+            1,
+            ChainName::truncate_from(b"SOL".to_vec())
+        ));
+        assert_eq!(Operation::deeper_to_other_amount(1), 350);
+        assert_eq!(Operation::other_to_deeper_amount(1), 500);
+        assert_eq!(Operation::legacy_bridge_amounts(b"SOL".to_vec()), (0, 0));
+    });
+}
+
+#[test]
+fn bridge_relayer_attestation_test() {
+    new_test_ext().execute_with(|| {
+        run_to_block(1);
+        assert_ok!(Balances::force_set_balance(RuntimeOrigin::root(), 2, 1_000));
+        assert_ok!(Operation::set_fund_pool_address(RuntimeOrigin::root(), 2));
+        assert_ok!(Operation::register_bridge_chain(
+            RuntimeOrigin::root(),
+            1,
+            ChainName::truncate_from(b"SOL".to_vec())
+        ));
+        let sol = ForeignAddress::truncate_from(b"sol".to_vec());
+        let source = SourceTx {
+            tx_hash: H256::repeat_byte(7),
+            log_index: 0,
+        };
+
+        assert_noop!(
+            Operation::set_bridge_relayers(RuntimeOrigin::root(), 1, vec![10, 11], 3),
+            Error::<Test>::InvalidThreshold
+        );
+        assert_noop!(
+            Operation::set_bridge_relayers(RuntimeOrigin::root(), 1, vec![10, 11], 0),
+            Error::<Test>::InvalidThreshold
+        );
+        assert_noop!(
+            Operation::set_bridge_relayers(RuntimeOrigin::root(), 2, vec![10, 11], 1),
+            Error::<Test>::UnknownChain
+        );
+        assert_ok!(Operation::set_bridge_relayers(
+            RuntimeOrigin::root(),
+            1,
+            vec![12, 10, 11, 10],
+            2
+        ));
+        assert_eq!(
+            Operation::bridge_relayers(1)
+                .map(|(relayers, threshold)| (relayers.to_vec(), threshold)),
+            Some((vec![10, 11, 12], 2))
+        );
+
+        // a single bridge admin can no longer credit the chain
+        assert_noop!(
+            Operation::bridge_other_to_deeper(
+                RuntimeOrigin::signed(1),
                 3,
-                "3NwgW4G8pVz7acktRwdMAKfTfw8pFw9h4if5H6P8k9sY".to_string(),
-                300,
-                "SOL".to_string(),
-                "1".to_string()
-            ))
+                sol.clone(),
+                200,
+                1,
+                source
+            ),
+            Error::<Test>::AttestationRequired
         );
-        assert_eq!(Balances::free_balance(&3), 500);
+        assert_noop!(
+            Operation::attest_inbound(RuntimeOrigin::signed(1), 3, sol.clone(), 200, 1, source),
+            Error::<Test>::NotRelayer
+        );
+
+        assert_ok!(Operation::attest_inbound(
+            RuntimeOrigin::signed(10),
+            3,
+            sol.clone(),
+            200,
+            1,
+            source
+        ));
+        assert_noop!(
+            Operation::attest_inbound(RuntimeOrigin::signed(10), 3, sol.clone(), 200, 1, source),
+            Error::<Test>::AlreadyAttested
+        );
+        assert_ok!(Operation::attest_inbound(
+            RuntimeOrigin::signed(11),
+            4,
+            sol.clone(),
+            900,
+            1,
+            source
+        ));
+        assert_eq!(Balances::free_balance(&3), 0);
+        assert_eq!(Balances::free_balance(&4), 0);
+        assert_eq!(Operation::inbound_attestations(1, source).len(), 2);
 
-        assert_ok!(Operation::bridge_deeper_to_other(
+        assert_ok!(Operation::attest_inbound(
+            RuntimeOrigin::signed(12),
+            3,
+            sol.clone(),
+            200,
+            1,
+            source
+        ));
+        assert_eq!(Balances::free_balance(&3), 200);
+        assert_eq!(Balances::free_balance(&4), 0);
+        assert_eq!(Operation::other_to_deeper_amount(1), 200);
+        assert_eq!(Operation::processed_inbound(1, source), Some(1));
+        assert!(Operation::inbound_attestations(1, source).is_empty());
+        assert_eq!(Operation::relayer_disagreements(1, 11), 1);
+        assert_eq!(Operation::relayer_disagreements(1, 10), 0);
+        assert!(<frame_system::Pallet<Test>>::events().iter().any(|record| {
+            record.event
+                == RuntimeEvent::from(crate::Event::RelayerDisagreed {
+                    chain: 1,
+                    source,
+                    relayer: 11,
+                })
+        }));
+
+        assert_noop!(
+            Operation::attest_inbound(RuntimeOrigin::signed(11), 3, sol.clone(), 200, 1, source),
+            Error::<Test>::InboundAlreadyProcessed
+        );
+
+        // clearing the relayers lets bridge admins credit the chain again
+        assert_ok!(Operation::set_bridge_relayers(
+            RuntimeOrigin::root(),
+            1,
+            vec![],
+            0
+        ));
+        assert_ok!(Operation::bridge_other_to_deeper(
             RuntimeOrigin::signed(1),
-            "3NwgW4G8pVz7acktRwdMAKfTfw8pFw9h4if5H6P8k9sY".to_string(),
             3,
+            sol,
             100,
-            "SOL".to_string(),
-            "0".to_string()
+            1,
+            SourceTx {
+                tx_hash: H256::repeat_byte(7),
+                log_index: 1,
+            }
+        ));
+        assert_eq!(Balances::free_balance(&3), 300);
+    });
+}
+
+#[test]
+fn removed_relayer_attestations_are_dropped() {
+    new_test_ext().execute_with(|| {
+        run_to_block(1);
+        assert_ok!(Balances::force_set_balance(RuntimeOrigin::root(), 2, 1_000));
+        assert_ok!(Operation::set_fund_pool_address(RuntimeOrigin::root(), 2));
+        assert_ok!(Operation::register_bridge_chain(
+            RuntimeOrigin::root(),
+            1,
+            ChainName::truncate_from(b"SOL".to_vec())
+        ));
+        let sol = ForeignAddress::truncate_from(b"sol".to_vec());
+        let source = SourceTx {
+            tx_hash: H256::repeat_byte(7),
+            log_index: 0,
+        };
+        assert_ok!(Operation::set_bridge_relayers(
+            RuntimeOrigin::root(),
+            1,
+            vec![10, 11, 12],
+            2
+        ));
+        assert_ok!(Operation::attest_inbound(
+            RuntimeOrigin::signed(10),
+            3,
+            sol.clone(),
+            200,
+            1,
+            source
+        ));
+
+        // 10 leaves the set, its attestation is dropped instead of counted
+        assert_ok!(Operation::set_bridge_relayers(
+            RuntimeOrigin::root(),
+            1,
+            vec![11, 12, 13],
+            2
+        ));
+        assert_ok!(Operation::attest_inbound(
+            RuntimeOrigin::signed(11),
+            3,
+            sol.clone(),
+            200,
+            1,
+            source
         ));
-        assert_eq!(Operation::deeper_to_other_amount("SOL".to_string()), 100);
         assert_eq!(
-            <frame_system::Pallet<Test>>::events()
-                .pop()
-                .expect("should contains events")
-                .event,
-            crate::tests::RuntimeEvent::from(crate::Event::BridgeDeeperToOther(
-                "3NwgW4G8pVz7acktRwdMAKfTfw8pFw9h4if5H6P8k9sY".to_string(),
-                3,
-                100,
-                "SOL".to_string(),
-                "0".to_string()
-            ))
+            Operation::inbound_attestations(1, source)
+                .iter()
+                .map(|(who, _)| *who)
+                .collect::<Vec<_>>(),
+            vec![11]
+        );
+        assert_eq!(Balances::free_balance(&3), 0);
+
+        assert_ok!(Operation::attest_inbound(
+            RuntimeOrigin::signed(13),
+            3,
+            sol,
+            200,
+            1,
+            source
+        ));
+        assert_eq!(Balances::free_balance(&3), 200);
+        assert!(Operation::inbound_attestations(1, source).is_empty());
+    });
+}
+
+#[test]
+fn bridge_application_test() {
+    new_test_ext().execute_with(|| {
+        run_to_block(1);
+        assert_ok!(Balances::force_set_balance(RuntimeOrigin::root(), 3, 1_000));
+        assert_ok!(Operation::set_fund_pool_address(RuntimeOrigin::root(), 2));
+        assert_ok!(Operation::register_bridge_chain(
+            RuntimeOrigin::root(),
+            1,
+            ChainName::truncate_from(b"SOL".to_vec())
+        ));
+        let sol = ForeignAddress::truncate_from(b"sol".to_vec());
+
+        assert_noop!(
+            Operation::apply_for_bridge_transfer(RuntimeOrigin::signed(3), sol.clone(), 0, 1),
+            Error::<Test>::BridgeAmountZero
         );
+        assert_noop!(
+            Operation::apply_for_bridge_transfer(RuntimeOrigin::signed(3), sol.clone(), 2_000, 1),
+            pallet_balances::Error::<Test>::InsufficientBalance
+        );
+        for amount in [100, 200, 300] {
+            assert_ok!(Operation::apply_for_bridge_transfer(
+                RuntimeOrigin::signed(3),
+                sol.clone(),
+                amount,
+                1
+            ));
+        }
+        assert_eq!(Operation::next_bridge_application_id(), 3);
+        assert_eq!(Balances::reserved_balance(&3), 600);
         assert_eq!(Balances::free_balance(&3), 400);
 
-        assert_ok!(Operation::bridge_deeper_to_other(
+        // completing moves the reserved amount to the fund pool
+        assert_noop!(
+            Operation::complete_bridge_application(RuntimeOrigin::signed(3), 0),
+            Error::<Test>::UnauthorizedAccounts
+        );
+        assert_ok!(Operation::complete_bridge_application(
             RuntimeOrigin::signed(1),
-            "3NwgW4G8pVz7acktRwdMAKfTfw8pFw9h4if5H6P8k9sY".to_string(),
-            3,
-            250,
-            "SOL".to_string(),
-            "0".to_string()
+            0
         ));
-        assert_eq!(Operation::deeper_to_other_amount("SOL".to_string()), 350);
         assert_eq!(
             <frame_system::Pallet<Test>>::events()
                 .pop()
                 .expect("should contains events")
                 .event,
-            crate::tests::RuntimeEvent::from(crate::Event::BridgeDeeperToOther(
-                "3NwgW4G8pVz7acktRwdMAKfTfw8pFw9h4if5H6P8k9sY".to_string(),
+            RuntimeEvent::from(crate::Event::BridgeApplicationCompleted { id: 0, nonce: 0 })
+        );
+        assert_eq!(Operation::bridge_application(0), None);
+        assert_eq!(Balances::reserved_balance(&3), 500);
+        assert_eq!(Balances::free_balance(&2), 101);
+        assert_eq!(Operation::deeper_to_other_amount(1), 100);
+        assert_eq!(Operation::outbound_nonce(1), 1);
+        assert_noop!(
+            Operation::complete_bridge_application(RuntimeOrigin::signed(1), 0),
+            Error::<Test>::BridgeApplicationNotFound
+        );
+
+        // rejecting gives the amount back
+        assert_ok!(Operation::reject_bridge_application(
+            RuntimeOrigin::signed(1),
+            1
+        ));
+        assert_eq!(Balances::reserved_balance(&3), 300);
+        assert_eq!(Balances::free_balance(&3), 600);
+        assert_noop!(
+            Operation::complete_bridge_application(RuntimeOrigin::signed(1), 1),
+            Error::<Test>::BridgeApplicationNotFound
+        );
+
+        // the last one is refunded once it expires
+        run_to_block(10);
+        Operation::on_idle(10, Weight::MAX);
+        assert_eq!(Balances::reserved_balance(&3), 300);
+        run_to_block(11);
+        Operation::on_idle(11, Weight::MAX);
+        assert_eq!(Operation::bridge_application(2), None);
+        assert_eq!(Balances::reserved_balance(&3), 0);
+        assert_eq!(Balances::free_balance(&3), 900);
+        assert_eq!(Operation::next_application_expiry_to_sweep(), 12);
+        assert!(<frame_system::Pallet<Test>>::events().iter().any(|record| {
+            record.event == RuntimeEvent::from(crate::Event::BridgeApplicationRefunded { id: 2 })
+        }));
+        assert_eq!(Operation::deeper_to_other_amount(1), 100);
+    });
+}
+
+#[test]
+fn bridge_circuit_breaker_test() {
+    new_test_ext().execute_with(|| {
+        run_to_block(1);
+        assert_ok!(Balances::force_set_balance(RuntimeOrigin::root(), 3, 1_000));
+        assert_ok!(Operation::set_fund_pool_address(RuntimeOrigin::root(), 2));
+        assert_ok!(Operation::register_bridge_chain(
+            RuntimeOrigin::root(),
+            1,
+            ChainName::truncate_from(b"SOL".to_vec())
+        ));
+        let sol = ForeignAddress::truncate_from(b"sol".to_vec());
+        let flow = BreakerFlow::DeeperToOther(1);
+        let threshold = BreakerThreshold {
+            limit: 300,
+            window: 10,
+        };
+
+        assert_noop!(
+            Operation::set_circuit_breaker(RuntimeOrigin::signed(1), flow, Some(threshold)),
+            sp_runtime::traits::BadOrigin
+        );
+        assert_noop!(
+            Operation::set_circuit_breaker(
+                RuntimeOrigin::root(),
+                flow,
+                Some(BreakerThreshold {
+                    limit: 300,
+                    window: 0
+                })
+            ),
+            Error::<Test>::InvalidBreakerWindow
+        );
+        assert_ok!(Operation::set_circuit_breaker(
+            RuntimeOrigin::root(),
+            flow,
+            Some(threshold)
+        ));
+
+        let bridge_out = |id, amount| {
+            assert_ok!(Operation::apply_for_bridge_transfer(
+                RuntimeOrigin::signed(3),
+                sol.clone(),
+                amount,
+                1
+            ));
+            Operation::complete_bridge_application(RuntimeOrigin::signed(1), id)
+        };
+        assert_ok!(bridge_out(0, 200));
+        // 80% of the previous window is still in the rolling one, 160 + 100
+        run_to_block(12);
+        assert_ok!(bridge_out(1, 100));
+        assert_eq!(
+            Operation::paused_calls((
+                b"Operation".to_vec(),
+                b"complete_bridge_application".to_vec()
+            )),
+            None
+        );
+
+        // 160 + 150 would go above the limit, the transfer is refused and trips the breaker
+        assert_ok!(bridge_out(2, 50));
+        assert!(<frame_system::Pallet<Test>>::events().iter().any(|record| {
+            record.event
+                == RuntimeEvent::from(crate::Event::CircuitBreakerTripped {
+                    flow,
+                    volume: 310,
+                    limit: 300,
+                })
+        }));
+        assert!(<frame_system::Pallet<Test>>::events().iter().any(|record| {
+            record.event
+                == RuntimeEvent::from(crate::Event::BridgeTransferRefused {
+                    flow,
+                    transfer: RefusedTransfer::Application(2),
+                    amount: 50,
+                })
+        }));
+        assert!(Operation::bridge_application(2).is_some());
+        assert_eq!(Balances::reserved_balance(&3), 50);
+        assert_eq!(Operation::deeper_to_other_amount(1), 300);
+        assert_eq!(Operation::outbound_nonce(1), 2);
+        assert_eq!(
+            Operation::paused_calls((
+                b"Operation".to_vec(),
+                b"complete_bridge_application".to_vec()
+            )),
+            Some(flow)
+        );
+        assert_eq!(Balances::free_balance(&3), 650);
+        assert_noop!(
+            Operation::complete_bridge_application(RuntimeOrigin::signed(1), 2),
+            Error::<Test>::CallPaused
+        );
+
+        // resetting needs the governance origin
+        assert_noop!(
+            Operation::reset_circuit_breaker(RuntimeOrigin::signed(1), flow),
+            sp_runtime::traits::BadOrigin
+        );
+        assert_ok!(Operation::reset_circuit_breaker(
+            RuntimeOrigin::root(),
+            flow
+        ));
+        assert!(<frame_system::Pallet<Test>>::events().iter().any(|record| {
+            record.event
+                == RuntimeEvent::from(crate::Event::Unpaused(
+                    "Operation".to_string(),
+                    "complete_bridge_application".to_string(),
+                ))
+        }));
+        assert_eq!(Operation::breaker_volume(flow), Default::default());
+        assert_ok!(Operation::complete_bridge_application(
+            RuntimeOrigin::signed(1),
+            2
+        ));
+        assert_eq!(Operation::deeper_to_other_amount(1), 350);
+        assert_eq!(Balances::reserved_balance(&3), 0);
+    });
+}
+
+#[test]
+fn inbound_transfer_refused_by_breaker_is_retried() {
+    new_test_ext().execute_with(|| {
+        run_to_block(1);
+        assert_ok!(Balances::force_set_balance(RuntimeOrigin::root(), 2, 1_000));
+        assert_ok!(Operation::set_fund_pool_address(RuntimeOrigin::root(), 2));
+        assert_ok!(Operation::register_bridge_chain(
+            RuntimeOrigin::root(),
+            1,
+            ChainName::truncate_from(b"SOL".to_vec())
+        ));
+        assert_ok!(Operation::set_bridge_relayers(
+            RuntimeOrigin::root(),
+            1,
+            vec![10, 11],
+            2
+        ));
+        let flow = BreakerFlow::OtherToDeeper(1);
+        assert_ok!(Operation::set_circuit_breaker(
+            RuntimeOrigin::root(),
+            flow,
+            Some(BreakerThreshold {
+                limit: 300,
+                window: 10,
+            })
+        ));
+        let sol = ForeignAddress::truncate_from(b"sol".to_vec());
+        let source = SourceTx {
+            tx_hash: H256::repeat_byte(7),
+            log_index: 0,
+        };
+
+        // a single transfer above the limit is refused before any funds move
+        for relayer in [10, 11] {
+            assert_ok!(Operation::attest_inbound(
+                RuntimeOrigin::signed(relayer),
                 3,
-                250,
-                "SOL".to_string(),
-                "0".to_string()
-            ))
+                sol.clone(),
+                400,
+                1,
+                source
+            ));
+        }
+        assert_eq!(Balances::free_balance(&3), 0);
+        assert_eq!(Operation::other_to_deeper_amount(1), 0);
+        assert_eq!(Operation::processed_inbound(1, source), None);
+        assert_eq!(Operation::inbound_attestations(1, source).len(), 2);
+        System::assert_last_event(RuntimeEvent::from(crate::Event::BridgeTransferRefused {
+            flow,
+            transfer: RefusedTransfer::Inbound(source),
+            amount: 400,
+        }));
+        assert_eq!(
+            Operation::paused_calls((b"Operation".to_vec(), b"attest_inbound".to_vec())),
+            Some(flow)
+        );
+
+        // once governance raises the limit a relayer retries the attested transfer
+        assert_ok!(Operation::reset_circuit_breaker(
+            RuntimeOrigin::root(),
+            flow
+        ));
+        assert_ok!(Operation::set_circuit_breaker(
+            RuntimeOrigin::root(),
+            flow,
+            Some(BreakerThreshold {
+                limit: 500,
+                window: 10,
+            })
+        ));
+        assert_noop!(
+            Operation::attest_inbound(RuntimeOrigin::signed(10), 3, sol.clone(), 300, 1, source),
+            Error::<Test>::AlreadyAttested
+        );
+        assert_ok!(Operation::attest_inbound(
+            RuntimeOrigin::signed(10),
+            3,
+            sol,
+            400,
+            1,
+            source
+        ));
+        assert_eq!(Balances::free_balance(&3), 400);
+        assert_eq!(Operation::processed_inbound(1, source), Some(1));
+        assert!(Operation::inbound_attestations(1, source).is_empty());
+    });
+}
+
+#[test]
+fn release_circuit_breaker_test() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(Operation::set_release_limit_parameter(
+            RuntimeOrigin::root(),
+            1000,
+            2000
+        ));
+        assert_ok!(Operation::set_circuit_breaker(
+            RuntimeOrigin::root(),
+            BreakerFlow::Release,
+            Some(BreakerThreshold {
+                limit: 1500,
+                window: BLOCKS_PER_DAY * 2,
+            })
+        ));
+        assert_ok!(Operation::unstaking_release(
+            RuntimeOrigin::signed(1),
+            ReleaseInfo::<Test>::new(3, 2, 0, 2000)
+        ));
+        assert_ok!(Operation::unstaking_release(
+            RuntimeOrigin::signed(1),
+            ReleaseInfo::<Test>::new(4, 2, 0, 2000)
+        ));
+
+        // the second release would go above the limit, it is held back and pauses releasing
+        run_to_block(BLOCKS_PER_DAY + 3);
+        assert_eq!(
+            Balances::free_balance(&3) + Balances::free_balance(&4),
+            1000
+        );
+        assert!(<frame_system::Pallet<Test>>::events().iter().any(|record| {
+            record.event
+                == RuntimeEvent::from(crate::Event::CircuitBreakerTripped {
+                    flow: BreakerFlow::Release,
+                    volume: 2000,
+                    limit: 1500,
+                })
+        }));
+
+        run_to_block(BLOCKS_PER_DAY * 2 + 3);
+        assert_eq!(
+            Balances::free_balance(&3) + Balances::free_balance(&4),
+            1000
+        );
+        assert_eq!(
+            Operation::paused_calls((b"Operation".to_vec(), b"release_staking_balance".to_vec())),
+            Some(BreakerFlow::Release)
+        );
+        // only paying out is paused, releases can still be set up
+        assert_ok!(Operation::unstaking_release(
+            RuntimeOrigin::signed(1),
+            ReleaseInfo::<Test>::new(5, 1, 0, 1000)
+        ));
+
+        assert_ok!(Operation::reset_circuit_breaker(
+            RuntimeOrigin::root(),
+            BreakerFlow::Release
+        ));
+        assert_eq!(
+            Operation::paused_calls((b"Operation".to_vec(), b"release_staking_balance".to_vec())),
+            None
         );
-        assert_eq!(Balances::free_balance(&3), 150);
     });
 }
@@ -39,8 +39,12 @@ pub trait WeightInfo {
 	fn set_release_limit_parameter() -> Weight;
 	fn unstaking_release() -> Weight;
 	fn burn_for_ezc() -> Weight;
-	fn bridge_deeper_to_other() -> Weight;
 	fn bridge_other_to_deeper() -> Weight;
+	fn attest_inbound(r: u32, ) -> Weight;
+	fn apply_for_bridge_transfer() -> Weight;
+	fn complete_bridge_application() -> Weight;
+	fn reject_bridge_application() -> Weight;
+	fn refund_bridge_application() -> Weight;
 }
 
 /// Weights for `pallet_operation` using the Substrate node and recommended hardware.
@@ -100,13 +104,15 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	/// Proof: `Credit::UserCreditHistory` (`max_values`: None, `max_size`: None, mode: `Measured`)
 	/// Storage: `Operation::AccountsReleaseInfo` (r:0 w:1)
 	/// Proof: `Operation::AccountsReleaseInfo` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Operation::PausedCalls` (r:1 w:0)
+	/// Proof: `Operation::PausedCalls` (`max_values`: None, `max_size`: None, mode: `Measured`)
 	fn unstaking_release() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `946`
 		//  Estimated: `4411`
 		// Minimum execution time: 760_866_000 picoseconds.
 		Weight::from_parts(766_316_000, 4411)
-			.saturating_add(T::DbWeight::get().reads(6_u64))
+			.saturating_add(T::DbWeight::get().reads(7_u64))
 			.saturating_add(T::DbWeight::get().writes(3_u64))
 	}
 	/// Storage: `System::Account` (r:2 w:2)
@@ -126,33 +132,157 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	}
 	/// Storage: `UserPrivileges::UserPrivileges` (r:1 w:0)
 	/// Proof: `UserPrivileges::UserPrivileges` (`max_values`: None, `max_size`: Some(48), added: 2523, mode: `MaxEncodedLen`)
+	/// Storage: `UserPrivileges::RequiredApprovals` (r:1 w:0)
+	/// Proof: `UserPrivileges::RequiredApprovals` (`max_values`: None, `max_size`: Some(13), added: 2488, mode: `MaxEncodedLen`)
 	/// Storage: `Operation::BridgeFundAddreess` (r:1 w:0)
 	/// Proof: `Operation::BridgeFundAddreess` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// Storage: `Operation::BridgeChains` (r:1 w:0)
+	/// Proof: `Operation::BridgeChains` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Operation::ProcessedInbound` (r:1 w:1)
+	/// Proof: `Operation::ProcessedInbound` (`max_values`: None, `max_size`: None, mode: `Measured`)
 	/// Storage: `System::Account` (r:2 w:2)
 	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
-	fn bridge_deeper_to_other() -> Weight {
+	/// Storage: `Operation::OtherToDeeperByChain` (r:1 w:1)
+	/// Proof: `Operation::OtherToDeeperByChain` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `UserPrivileges::PrivilegeQuotas` (r:1 w:0)
+	/// Proof: `UserPrivileges::PrivilegeQuotas` (`max_values`: None, `max_size`: Some(44), added: 2519, mode: `MaxEncodedLen`)
+	/// Storage: `UserPrivileges::QuotaUsage` (r:1 w:1)
+	/// Proof: `UserPrivileges::QuotaUsage` (`max_values`: None, `max_size`: Some(82), added: 2557, mode: `MaxEncodedLen`)
+	/// Storage: `UserPrivileges::GlobalQuotaUsage` (r:1 w:1)
+	/// Proof: `UserPrivileges::GlobalQuotaUsage` (`max_values`: None, `max_size`: Some(42), added: 2517, mode: `MaxEncodedLen`)
+	/// Storage: `Operation::PausedCalls` (r:1 w:2)
+	/// Proof: `Operation::PausedCalls` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Operation::CircuitBreakers` (r:1 w:0)
+	/// Proof: `Operation::CircuitBreakers` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Operation::BreakerVolumes` (r:1 w:1)
+	/// Proof: `Operation::BreakerVolumes` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	fn bridge_other_to_deeper() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `525`
 		//  Estimated: `6196`
-		// Minimum execution time: 1_052_433_000 picoseconds.
-		Weight::from_parts(1_077_010_000, 6196)
-			.saturating_add(T::DbWeight::get().reads(4_u64))
-			.saturating_add(T::DbWeight::get().writes(2_u64))
+		// Minimum execution time: 1_196_742_000 picoseconds.
+		Weight::from_parts(1_213_380_000, 6196)
+			.saturating_add(T::DbWeight::get().reads(14_u64))
+			.saturating_add(T::DbWeight::get().writes(9_u64))
+	}
+	/// Storage: `Operation::BridgeChains` (r:1 w:0)
+	/// Proof: `Operation::BridgeChains` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Operation::BridgeRelayers` (r:1 w:0)
+	/// Proof: `Operation::BridgeRelayers` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Operation::ProcessedInbound` (r:1 w:1)
+	/// Proof: `Operation::ProcessedInbound` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Operation::InboundAttestations` (r:1 w:1)
+	/// Proof: `Operation::InboundAttestations` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Operation::RelayerDisagreements` (r:16 w:16)
+	/// Proof: `Operation::RelayerDisagreements` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Operation::BridgeFundAddreess` (r:1 w:0)
+	/// Proof: `Operation::BridgeFundAddreess` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// Storage: `System::Account` (r:2 w:2)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// Storage: `Operation::OtherToDeeperByChain` (r:1 w:1)
+	/// Proof: `Operation::OtherToDeeperByChain` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// The range of component `r` is `[0, 16]`.
+	/// Storage: `Operation::PausedCalls` (r:1 w:2)
+	/// Proof: `Operation::PausedCalls` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Operation::CircuitBreakers` (r:1 w:0)
+	/// Proof: `Operation::CircuitBreakers` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Operation::BreakerVolumes` (r:1 w:1)
+	/// Proof: `Operation::BreakerVolumes` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	fn attest_inbound(r: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1034 + r * (97 ±0)`
+		//  Estimated: `6196 + r * (2573 ±0)`
+		// Minimum execution time: 1_253_415_000 picoseconds.
+		Weight::from_parts(1_268_902_400, 6196)
+			// Standard Error: 64_212
+			.saturating_add(Weight::from_parts(21_374_520, 0).saturating_mul(r.into()))
+			.saturating_add(T::DbWeight::get().reads(11_u64))
+			.saturating_add(T::DbWeight::get().reads((1_u64).saturating_mul(r.into())))
+			.saturating_add(T::DbWeight::get().writes(8_u64))
+			.saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(r.into())))
+			.saturating_add(Weight::from_parts(0, 2573).saturating_mul(r.into()))
+	}
+	/// Storage: `Operation::BridgeChains` (r:1 w:0)
+	/// Proof: `Operation::BridgeChains` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// Storage: `Operation::NextBridgeApplicationId` (r:1 w:1)
+	/// Proof: `Operation::NextBridgeApplicationId` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// Storage: `Operation::BridgeApplications` (r:0 w:1)
+	/// Proof: `Operation::BridgeApplications` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Operation::BridgeApplicationExpirations` (r:0 w:1)
+	/// Proof: `Operation::BridgeApplicationExpirations` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	fn apply_for_bridge_transfer() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `312`
+		//  Estimated: `3593`
+		// Minimum execution time: 402_118_000 picoseconds.
+		Weight::from_parts(409_634_000, 3593)
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(4_u64))
 	}
 	/// Storage: `UserPrivileges::UserPrivileges` (r:1 w:0)
 	/// Proof: `UserPrivileges::UserPrivileges` (`max_values`: None, `max_size`: Some(48), added: 2523, mode: `MaxEncodedLen`)
 	/// Storage: `Operation::BridgeFundAddreess` (r:1 w:0)
 	/// Proof: `Operation::BridgeFundAddreess` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// Storage: `Operation::BridgeApplications` (r:1 w:1)
+	/// Proof: `Operation::BridgeApplications` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Operation::BridgeChains` (r:1 w:0)
+	/// Proof: `Operation::BridgeChains` (`max_values`: None, `max_size`: None, mode: `Measured`)
 	/// Storage: `System::Account` (r:2 w:2)
 	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
-	fn bridge_other_to_deeper() -> Weight {
+	/// Storage: `Operation::DeeperToOtherByChain` (r:1 w:1)
+	/// Proof: `Operation::DeeperToOtherByChain` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Operation::OutboundNonce` (r:1 w:1)
+	/// Proof: `Operation::OutboundNonce` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Operation::BridgeApplicationExpirations` (r:0 w:1)
+	/// Proof: `Operation::BridgeApplicationExpirations` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Operation::PausedCalls` (r:1 w:2)
+	/// Proof: `Operation::PausedCalls` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Operation::CircuitBreakers` (r:1 w:0)
+	/// Proof: `Operation::CircuitBreakers` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Operation::BreakerVolumes` (r:1 w:1)
+	/// Proof: `Operation::BreakerVolumes` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	fn complete_bridge_application() -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `525`
+		//  Measured:  `734`
 		//  Estimated: `6196`
-		// Minimum execution time: 1_048_436_000 picoseconds.
-		Weight::from_parts(1_058_505_000, 6196)
-			.saturating_add(T::DbWeight::get().reads(4_u64))
-			.saturating_add(T::DbWeight::get().writes(2_u64))
+		// Minimum execution time: 1_087_553_000 picoseconds.
+		Weight::from_parts(1_102_871_000, 6196)
+			.saturating_add(T::DbWeight::get().reads(11_u64))
+			.saturating_add(T::DbWeight::get().writes(9_u64))
+	}
+	/// Storage: `UserPrivileges::UserPrivileges` (r:1 w:0)
+	/// Proof: `UserPrivileges::UserPrivileges` (`max_values`: None, `max_size`: Some(48), added: 2523, mode: `MaxEncodedLen`)
+	/// Storage: `Operation::BridgeApplications` (r:1 w:1)
+	/// Proof: `Operation::BridgeApplications` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// Storage: `Operation::BridgeApplicationExpirations` (r:0 w:1)
+	/// Proof: `Operation::BridgeApplicationExpirations` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	fn reject_bridge_application() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `541`
+		//  Estimated: `4006`
+		// Minimum execution time: 486_310_000 picoseconds.
+		Weight::from_parts(495_027_000, 4006)
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+	}
+	/// Storage: `Operation::BridgeApplications` (r:1 w:1)
+	/// Proof: `Operation::BridgeApplications` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// Storage: `Operation::BridgeApplicationExpirations` (r:0 w:1)
+	/// Proof: `Operation::BridgeApplicationExpirations` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	fn refund_bridge_application() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `451`
+		//  Estimated: `3916`
+		// Minimum execution time: 431_765_000 picoseconds.
+		Weight::from_parts(438_902_000, 3916)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
 	}
 }
 
@@ -212,13 +342,15 @@ impl WeightInfo for () {
 	/// Proof: `Credit::UserCreditHistory` (`max_values`: None, `max_size`: None, mode: `Measured`)
 	/// Storage: `Operation::AccountsReleaseInfo` (r:0 w:1)
 	/// Proof: `Operation::AccountsReleaseInfo` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Operation::PausedCalls` (r:1 w:0)
+	/// Proof: `Operation::PausedCalls` (`max_values`: None, `max_size`: None, mode: `Measured`)
 	fn unstaking_release() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `946`
 		//  Estimated: `4411`
 		// Minimum execution time: 760_866_000 picoseconds.
 		Weight::from_parts(766_316_000, 4411)
-			.saturating_add(RocksDbWeight::get().reads(6_u64))
+			.saturating_add(RocksDbWeight::get().reads(7_u64))
 			.saturating_add(RocksDbWeight::get().writes(3_u64))
 	}
 	/// Storage: `System::Account` (r:2 w:2)
@@ -238,32 +370,156 @@ impl WeightInfo for () {
 	}
 	/// Storage: `UserPrivileges::UserPrivileges` (r:1 w:0)
 	/// Proof: `UserPrivileges::UserPrivileges` (`max_values`: None, `max_size`: Some(48), added: 2523, mode: `MaxEncodedLen`)
+	/// Storage: `UserPrivileges::RequiredApprovals` (r:1 w:0)
+	/// Proof: `UserPrivileges::RequiredApprovals` (`max_values`: None, `max_size`: Some(13), added: 2488, mode: `MaxEncodedLen`)
 	/// Storage: `Operation::BridgeFundAddreess` (r:1 w:0)
 	/// Proof: `Operation::BridgeFundAddreess` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// Storage: `Operation::BridgeChains` (r:1 w:0)
+	/// Proof: `Operation::BridgeChains` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Operation::ProcessedInbound` (r:1 w:1)
+	/// Proof: `Operation::ProcessedInbound` (`max_values`: None, `max_size`: None, mode: `Measured`)
 	/// Storage: `System::Account` (r:2 w:2)
 	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
-	fn bridge_deeper_to_other() -> Weight {
+	/// Storage: `Operation::OtherToDeeperByChain` (r:1 w:1)
+	/// Proof: `Operation::OtherToDeeperByChain` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `UserPrivileges::PrivilegeQuotas` (r:1 w:0)
+	/// Proof: `UserPrivileges::PrivilegeQuotas` (`max_values`: None, `max_size`: Some(44), added: 2519, mode: `MaxEncodedLen`)
+	/// Storage: `UserPrivileges::QuotaUsage` (r:1 w:1)
+	/// Proof: `UserPrivileges::QuotaUsage` (`max_values`: None, `max_size`: Some(82), added: 2557, mode: `MaxEncodedLen`)
+	/// Storage: `UserPrivileges::GlobalQuotaUsage` (r:1 w:1)
+	/// Proof: `UserPrivileges::GlobalQuotaUsage` (`max_values`: None, `max_size`: Some(42), added: 2517, mode: `MaxEncodedLen`)
+	/// Storage: `Operation::PausedCalls` (r:1 w:2)
+	/// Proof: `Operation::PausedCalls` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Operation::CircuitBreakers` (r:1 w:0)
+	/// Proof: `Operation::CircuitBreakers` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Operation::BreakerVolumes` (r:1 w:1)
+	/// Proof: `Operation::BreakerVolumes` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	fn bridge_other_to_deeper() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `525`
 		//  Estimated: `6196`
-		// Minimum execution time: 1_052_433_000 picoseconds.
-		Weight::from_parts(1_077_010_000, 6196)
-			.saturating_add(RocksDbWeight::get().reads(4_u64))
-			.saturating_add(RocksDbWeight::get().writes(2_u64))
+		// Minimum execution time: 1_196_742_000 picoseconds.
+		Weight::from_parts(1_213_380_000, 6196)
+			.saturating_add(RocksDbWeight::get().reads(14_u64))
+			.saturating_add(RocksDbWeight::get().writes(9_u64))
+	}
+	/// Storage: `Operation::BridgeChains` (r:1 w:0)
+	/// Proof: `Operation::BridgeChains` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Operation::BridgeRelayers` (r:1 w:0)
+	/// Proof: `Operation::BridgeRelayers` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Operation::ProcessedInbound` (r:1 w:1)
+	/// Proof: `Operation::ProcessedInbound` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Operation::InboundAttestations` (r:1 w:1)
+	/// Proof: `Operation::InboundAttestations` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Operation::RelayerDisagreements` (r:16 w:16)
+	/// Proof: `Operation::RelayerDisagreements` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Operation::BridgeFundAddreess` (r:1 w:0)
+	/// Proof: `Operation::BridgeFundAddreess` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// Storage: `System::Account` (r:2 w:2)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// Storage: `Operation::OtherToDeeperByChain` (r:1 w:1)
+	/// Proof: `Operation::OtherToDeeperByChain` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// The range of component `r` is `[0, 16]`.
+	/// Storage: `Operation::PausedCalls` (r:1 w:2)
+	/// Proof: `Operation::PausedCalls` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Operation::CircuitBreakers` (r:1 w:0)
+	/// Proof: `Operation::CircuitBreakers` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Operation::BreakerVolumes` (r:1 w:1)
+	/// Proof: `Operation::BreakerVolumes` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	fn attest_inbound(r: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1034 + r * (97 ±0)`
+		//  Estimated: `6196 + r * (2573 ±0)`
+		// Minimum execution time: 1_253_415_000 picoseconds.
+		Weight::from_parts(1_268_902_400, 6196)
+			// Standard Error: 64_212
+			.saturating_add(Weight::from_parts(21_374_520, 0).saturating_mul(r.into()))
+			.saturating_add(RocksDbWeight::get().reads(11_u64))
+			.saturating_add(RocksDbWeight::get().reads((1_u64).saturating_mul(r.into())))
+			.saturating_add(RocksDbWeight::get().writes(8_u64))
+			.saturating_add(RocksDbWeight::get().writes((1_u64).saturating_mul(r.into())))
+			.saturating_add(Weight::from_parts(0, 2573).saturating_mul(r.into()))
+	}
+	/// Storage: `Operation::BridgeChains` (r:1 w:0)
+	/// Proof: `Operation::BridgeChains` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// Storage: `Operation::NextBridgeApplicationId` (r:1 w:1)
+	/// Proof: `Operation::NextBridgeApplicationId` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// Storage: `Operation::BridgeApplications` (r:0 w:1)
+	/// Proof: `Operation::BridgeApplications` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Operation::BridgeApplicationExpirations` (r:0 w:1)
+	/// Proof: `Operation::BridgeApplicationExpirations` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	fn apply_for_bridge_transfer() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `312`
+		//  Estimated: `3593`
+		// Minimum execution time: 402_118_000 picoseconds.
+		Weight::from_parts(409_634_000, 3593)
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(4_u64))
 	}
 	/// Storage: `UserPrivileges::UserPrivileges` (r:1 w:0)
 	/// Proof: `UserPrivileges::UserPrivileges` (`max_values`: None, `max_size`: Some(48), added: 2523, mode: `MaxEncodedLen`)
 	/// Storage: `Operation::BridgeFundAddreess` (r:1 w:0)
 	/// Proof: `Operation::BridgeFundAddreess` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// Storage: `Operation::BridgeApplications` (r:1 w:1)
+	/// Proof: `Operation::BridgeApplications` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Operation::BridgeChains` (r:1 w:0)
+	/// Proof: `Operation::BridgeChains` (`max_values`: None, `max_size`: None, mode: `Measured`)
 	/// Storage: `System::Account` (r:2 w:2)
 	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
-	fn bridge_other_to_deeper() -> Weight {
+	/// Storage: `Operation::DeeperToOtherByChain` (r:1 w:1)
+	/// Proof: `Operation::DeeperToOtherByChain` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Operation::OutboundNonce` (r:1 w:1)
+	/// Proof: `Operation::OutboundNonce` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Operation::BridgeApplicationExpirations` (r:0 w:1)
+	/// Proof: `Operation::BridgeApplicationExpirations` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Operation::PausedCalls` (r:1 w:2)
+	/// Proof: `Operation::PausedCalls` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Operation::CircuitBreakers` (r:1 w:0)
+	/// Proof: `Operation::CircuitBreakers` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Operation::BreakerVolumes` (r:1 w:1)
+	/// Proof: `Operation::BreakerVolumes` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	fn complete_bridge_application() -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `525`
+		//  Measured:  `734`
 		//  Estimated: `6196`
-		// Minimum execution time: 1_048_436_000 picoseconds.
-		Weight::from_parts(1_058_505_000, 6196)
-			.saturating_add(RocksDbWeight::get().reads(4_u64))
-			.saturating_add(RocksDbWeight::get().writes(2_u64))
+		// Minimum execution time: 1_087_553_000 picoseconds.
+		Weight::from_parts(1_102_871_000, 6196)
+			.saturating_add(RocksDbWeight::get().reads(11_u64))
+			.saturating_add(RocksDbWeight::get().writes(9_u64))
+	}
+	/// Storage: `UserPrivileges::UserPrivileges` (r:1 w:0)
+	/// Proof: `UserPrivileges::UserPrivileges` (`max_values`: None, `max_size`: Some(48), added: 2523, mode: `MaxEncodedLen`)
+	/// Storage: `Operation::BridgeApplications` (r:1 w:1)
+	/// Proof: `Operation::BridgeApplications` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// Storage: `Operation::BridgeApplicationExpirations` (r:0 w:1)
+	/// Proof: `Operation::BridgeApplicationExpirations` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	fn reject_bridge_application() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `541`
+		//  Estimated: `4006`
+		// Minimum execution time: 486_310_000 picoseconds.
+		Weight::from_parts(495_027_000, 4006)
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
+	}
+	/// Storage: `Operation::BridgeApplications` (r:1 w:1)
+	/// Proof: `Operation::BridgeApplications` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// Storage: `Operation::BridgeApplicationExpirations` (r:0 w:1)
+	/// Proof: `Operation::BridgeApplicationExpirations` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	fn refund_bridge_application() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `451`
+		//  Estimated: `3916`
+		// Minimum execution time: 431_765_000 picoseconds.
+		Weight::from_parts(438_902_000, 3916)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
 	}
 }
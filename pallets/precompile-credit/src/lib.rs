@@ -19,9 +19,6 @@
 
 extern crate alloc;
 
-mod util;
-
-use crate::util::{Gasometer, RuntimeHelper};
 use alloc::{borrow::ToOwned, vec};
 use arrayref::array_ref;
 use codec::Decode;
@@ -33,6 +30,7 @@ use fp_evm::{
 use frame_support::dispatch::{GetDispatchInfo, PostDispatchInfo};
 use node_primitives::credit::CreditInterface;
 use pallet_credit::Call as CreditCall;
+use pallet_evm_precompile_utils::{FunctionModifier, Gasometer, RuntimeHelper};
 use sp_core::{H160, U256};
 use sp_runtime::traits::Dispatchable;
 
@@ -107,7 +105,7 @@ where
         gasometer.check_function_modifier(
             handle.context(),
             handle.is_static(),
-            util::FunctionModifier::View,
+            FunctionModifier::View,
         )?;
 
         let account = H160::from(array_ref!(input, BASIC_LEN - 20, 20));
@@ -161,7 +159,7 @@ where
         gasometer.check_function_modifier(
             handle.context(),
             handle.is_static(),
-            util::FunctionModifier::NonPayable,
+            FunctionModifier::NonPayable,
         )?;
 
         let account = H160::from(array_ref!(input, BASIC_LEN - 20, 20));
@@ -0,0 +1,48 @@
+pragma solidity ^0.8.10;
+
+interface UserPrivileges {
+    event EvmPrivilegeSet(address indexed target, uint64 privilege);
+
+    event EvmPrivilegeUnSet(address indexed target, uint64 privilege);
+
+    function hasEvmPrivilege(address target, uint64 privilege)
+        external
+        view
+        returns (bool);
+
+    function hasPrivilege(bytes32 account, uint64 privilege)
+        external
+        view
+        returns (bool);
+
+    function setEvmPrivilege(address target, uint64 privilege) external;
+
+    function unsetEvmPrivilege(address target, uint64 privilege) external;
+}
+
+contract deeper {
+    UserPrivileges private constant USER_PRIVILEGES =
+        UserPrivileges(0x0000000000000000000000000000000000000407);
+    // privileges are passed as their flag value
+    uint64 private constant EVM_CREDIT_OPERATION = 8;
+
+    modifier onlyCreditOperator() {
+        require(
+            USER_PRIVILEGES.hasEvmPrivilege(msg.sender, EVM_CREDIT_OPERATION),
+            "caller is not a credit operator"
+        );
+        _;
+    }
+
+    function is_credit_operator(address target) public view returns (bool) {
+        return USER_PRIVILEGES.hasEvmPrivilege(target, EVM_CREDIT_OPERATION);
+    }
+
+    function grant_credit_operator(address target) public {
+        USER_PRIVILEGES.setEvmPrivilege(target, EVM_CREDIT_OPERATION);
+    }
+
+    function revoke_credit_operator(address target) public {
+        USER_PRIVILEGES.unsetEvmPrivilege(target, EVM_CREDIT_OPERATION);
+    }
+}
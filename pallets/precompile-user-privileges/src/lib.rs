@@ -0,0 +1,196 @@
+// Copyright (C) 2021 Deeper Network Inc.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+extern crate alloc;
+
+use alloc::{borrow::ToOwned, vec};
+use arrayref::array_ref;
+use codec::Decode;
+use core::marker::PhantomData;
+use enumflags2::BitFlags;
+use fp_evm::{
+    ExitError, ExitSucceed, Precompile, PrecompileFailure, PrecompileHandle, PrecompileOutput,
+    PrecompileResult,
+};
+use node_primitives::user_privileges::{Privilege, UserPrivilegeInterface};
+use pallet_evm::AddressMapping;
+use pallet_evm_precompile_utils::{FunctionModifier, Gasometer, RuntimeHelper};
+use pallet_user_privileges::Pallet as UserPrivileges;
+use sp_core::{H160, H256, U256};
+
+// selector + address or account + privilege
+const INPUT_LEN: usize = 4 + 32 + 32;
+const SELECTOR_HAS_EVM_PRIVILEGE: u32 = 0xbfde54f3;
+const SELECTOR_HAS_PRIVILEGE: u32 = 0x07b8bff8;
+const SELECTOR_SET_EVM_PRIVILEGE: u32 = 0x355e4e5c;
+const SELECTOR_UNSET_EVM_PRIVILEGE: u32 = 0x0a88a673;
+// gas of a log with two topics and one word of data
+const LOG_COST: u64 = 375 + 375 * 2 + 8 * 32;
+
+pub struct UserPrivilegesDispatch<Runtime> {
+    _marker: PhantomData<Runtime>,
+}
+
+impl<Runtime> Precompile for UserPrivilegesDispatch<Runtime>
+where
+    Runtime: pallet_user_privileges::Config + pallet_evm::Config,
+{
+    fn execute(handle: &mut impl PrecompileHandle) -> PrecompileResult {
+        /*
+        selector:
+        0xbfde54f3: hasEvmPrivilege(address,uint64)
+        0x07b8bff8: hasPrivilege(bytes32,uint64)
+        0x355e4e5c: setEvmPrivilege(address,uint64)
+        0x0a88a673: unsetEvmPrivilege(address,uint64)
+        */
+
+        let input = handle.input();
+        Self::check_input_len(input, INPUT_LEN)?;
+        let real_type = u32::from_be_bytes(array_ref!(input, 0, 4).to_owned());
+        match real_type {
+            SELECTOR_HAS_EVM_PRIVILEGE => Self::has_evm_privilege(handle),
+            SELECTOR_HAS_PRIVILEGE => Self::has_privilege(handle),
+            SELECTOR_SET_EVM_PRIVILEGE => Self::update_evm_privilege(handle, true),
+            SELECTOR_UNSET_EVM_PRIVILEGE => Self::update_evm_privilege(handle, false),
+            _ => Err(PrecompileFailure::Error {
+                exit_status: ExitError::Other("InvalidCode".into()),
+            }),
+        }
+    }
+}
+
+impl<Runtime> UserPrivilegesDispatch<Runtime>
+where
+    Runtime: pallet_user_privileges::Config + pallet_evm::Config,
+{
+    pub fn has_evm_privilege(handle: &mut impl PrecompileHandle) -> PrecompileResult {
+        let gasometer = Gasometer::new(None);
+        gasometer.check_function_modifier(
+            handle.context(),
+            handle.is_static(),
+            FunctionModifier::View,
+        )?;
+
+        let input = handle.input();
+        let address = H160::from(array_ref!(input, 4 + 12, 20));
+        let privilege = Self::privilege(&gasometer, input)?;
+        handle.record_cost(RuntimeHelper::<Runtime>::db_read_gas_cost() * 2)?;
+
+        let holds = UserPrivileges::<Runtime>::has_evm_privilege(&address, privilege);
+        Ok(Self::bool_output(holds))
+    }
+
+    pub fn has_privilege(handle: &mut impl PrecompileHandle) -> PrecompileResult {
+        let gasometer = Gasometer::new(None);
+        gasometer.check_function_modifier(
+            handle.context(),
+            handle.is_static(),
+            FunctionModifier::View,
+        )?;
+
+        let input = handle.input();
+        let account = Runtime::AccountId::decode(&mut &input[4..36])
+            .map_err(|_| gasometer.revert("invalid account"))?;
+        let privilege = Self::privilege(&gasometer, input)?;
+        handle.record_cost(RuntimeHelper::<Runtime>::db_read_gas_cost() * 2)?;
+
+        let holds = UserPrivileges::<Runtime>::has_privilege(&account, privilege);
+        Ok(Self::bool_output(holds))
+    }
+
+    /// Grant or revoke a privilege of an evm address, the caller or its paired account
+    /// must hold `EvmAddressSetter`
+    fn update_evm_privilege(handle: &mut impl PrecompileHandle, grant: bool) -> PrecompileResult {
+        let gasometer = Gasometer::new(None);
+        gasometer.check_function_modifier(
+            handle.context(),
+            handle.is_static(),
+            FunctionModifier::NonPayable,
+        )?;
+
+        let input = handle.input();
+        let address = H160::from(array_ref!(input, 4 + 12, 20));
+        let privilege = Self::privilege(&gasometer, input)?;
+        let context = handle.context();
+        let (caller, precompile) = (context.caller, context.address);
+        handle.record_cost(
+            RuntimeHelper::<Runtime>::db_read_gas_cost() * 4
+                + RuntimeHelper::<Runtime>::db_write_gas_cost() * 3
+                + LOG_COST,
+        )?;
+
+        let setter = Runtime::AddressMapping::into_account_id(caller);
+        if !UserPrivileges::<Runtime>::has_evm_privilege(&caller, Privilege::EvmAddressSetter)
+            && !UserPrivileges::<Runtime>::has_privilege(&setter, Privilege::EvmAddressSetter)
+        {
+            return Err(gasometer.revert("no permission"));
+        }
+
+        let event: &[u8] = if grant {
+            UserPrivileges::<Runtime>::grant_evm_privilege(&address, privilege);
+            b"EvmPrivilegeSet(address,uint64)"
+        } else {
+            UserPrivileges::<Runtime>::revoke_evm_privilege(&address, privilege)
+                .map_err(|_| gasometer.revert("privilege not exist"))?;
+            b"EvmPrivilegeUnSet(address,uint64)"
+        };
+        handle.log(
+            precompile,
+            vec![H256(sp_io::hashing::keccak_256(event)), H256::from(address)],
+            Self::word(privilege as u64),
+        )?;
+
+        Ok(PrecompileOutput {
+            exit_status: ExitSucceed::Returned,
+            output: Default::default(),
+        })
+    }
+
+    /// The privilege is passed as its flag value, e.g. 8 for `EvmCreditOperation`
+    fn privilege(gasometer: &Gasometer, input: &[u8]) -> Result<Privilege, PrecompileFailure> {
+        let bits = U256::from(array_ref!(input, 36, 32));
+        if bits > U256::from(u64::MAX) {
+            return Err(gasometer.revert("invalid privilege"));
+        }
+        BitFlags::<Privilege>::from_bits(bits.low_u64())
+            .ok()
+            .and_then(|privileges| privileges.exactly_one())
+            .ok_or_else(|| gasometer.revert("invalid privilege"))
+    }
+
+    fn word(value: u64) -> vec::Vec<u8> {
+        let mut output = vec![0; 32];
+        U256::from(value).to_big_endian(&mut output);
+        output
+    }
+
+    fn bool_output(value: bool) -> PrecompileOutput {
+        PrecompileOutput {
+            exit_status: ExitSucceed::Returned,
+            output: Self::word(value as u64),
+        }
+    }
+
+    fn check_input_len(input: &[u8], base_len: usize) -> Result<(), PrecompileFailure> {
+        if input.len() < base_len {
+            return Err(PrecompileFailure::Error {
+                exit_status: ExitError::Other("input len not enough".into()),
+            });
+        }
+        Ok(())
+    }
+}
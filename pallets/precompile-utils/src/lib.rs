@@ -13,6 +13,11 @@
 
 // You should have received a copy of the GNU General Public License
 // along with Moonbeam.  If not, see <http://www.gnu.org/licenses/>.
+
+//! Gas metering and dispatch helpers shared by the deeper chain precompiles.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
 extern crate alloc;
 
 use crate::alloc::borrow::ToOwned;
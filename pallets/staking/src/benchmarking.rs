@@ -24,7 +24,6 @@ use testing_utils::*;
 pub use frame_benchmarking::{account, benchmarks, whitelist_account, whitelisted_caller};
 use frame_support::assert_ok;
 use frame_system::RawOrigin;
-use hex_literal::hex;
 use node_primitives::{credit::H160, user_privileges::Privilege, AccountCreator};
 use sp_runtime::{
     traits::{One, TrailingZeroInput},
@@ -147,18 +146,20 @@ benchmarks! {
     staking_delegate {
         let delegator = <T as pallet_credit_accumulation::Config>::AccountCreator::create_account("Alice");
         high_funded_user::<T>(&delegator,1000);
-        let bob = <T as pallet_credit_accumulation::Config>::AccountCreator::create_account("Bob");
-        assert_ok!(pallet_credit_accumulation::Pallet::<T>::set_atmos_pubkey(
-            RawOrigin::Root.into(),
-            bob,
-        ));
-
         let nonce: u64 = 0;
-        let signature: [u8; 64] = hex!("5071a1a526b1d2d1833e4de43d1ce22ad3506de2e10ee4a9c18c0b310c54286b9cb10bfb4ee12be6b93e91337de0fa2ea2edd787d083db36211109bdc8438989");
+        let valid_until: BlockNumberFor<T> = 100u32.into();
+        let message = pallet_credit_accumulation::Pallet::<T>::atmos_message(
+            AtmosPurpose::StakingDelegate,
+            nonce,
+            valid_until,
+            sp_io::hashing::blake2_256(&2u8.encode()),
+            delegator.clone(),
+        );
+        let signature = pallet_credit_accumulation::benchmarking::atmos_signature::<T>(&message);
 
         let validators = create_validators_is_accountid::<T>(MAX_DELEGATES, 100)?;
         whitelist_account!(delegator);
-    }: _(RawOrigin::Signed(delegator.clone()),0,signature.into(), 2)
+    }: _(RawOrigin::Signed(delegator.clone()), nonce, valid_until, signature, 2)
     verify {
         assert!(Delegators::<T>::contains_key(delegator));
     }
@@ -52,8 +52,8 @@ use frame_system::{ensure_root, ensure_signed, offchain::SendTransactionTypes, p
 use node_primitives::{
     credit::{CreditInterface, CreditLevel},
     deeper_node::NodeInterface,
-    user_privileges::{Privilege, UserPrivilegeInterface},
-    OperationInterface, VerifySignatureInterface, DPR,
+    user_privileges::{Privilege, QuotaResource, UserPrivilegeInterface},
+    AtmosPurpose, OperationInterface, VerifySignatureInterface,
 };
 pub use pallet::*;
 use pallet_session::historical;
@@ -61,8 +61,7 @@ use scale_info::TypeInfo;
 use sp_runtime::{
     traits::{
         AccountIdConversion, AtLeast32BitUnsigned, CheckedSub, Convert, Dispatchable,
-        SaturatedConversion, Saturating, StaticLookup, UniqueSaturatedFrom, UniqueSaturatedInto,
-        Zero,
+        SaturatedConversion, Saturating, StaticLookup, UniqueSaturatedInto, Zero,
     },
     Perbill, Percent, RuntimeDebug,
 };
@@ -659,7 +658,10 @@ pub mod pallet {
         #[pallet::constant]
         type PalletId: Get<PalletId>;
         /// verify dev signature
-        type VerifySignatureInterface: VerifySignatureInterface<Self::AccountId>;
+        type VerifySignatureInterface: VerifySignatureInterface<
+            Self::AccountId,
+            BlockNumberFor<Self>,
+        >;
     }
 
     #[pallet::type_value]
@@ -955,11 +957,6 @@ pub mod pallet {
     pub type UserRefereeCount<T: Config> =
         StorageMap<_, Blake2_128Concat, T::AccountId, u32, ValueQuery>;
 
-    #[pallet::type_value]
-    pub fn NpowMintDayLimitDefault<T: Config>() -> BalanceOf<T> {
-        UniqueSaturatedFrom::unique_saturated_from(100_000 * DPR)
-    }
-
     #[pallet::storage]
     #[pallet::getter(fn black_list)]
     pub type BlackList<T: Config> = StorageMap<_, Twox64Concat, T::AccountId, EraIndex>;
@@ -1055,15 +1052,26 @@ pub mod pallet {
         pub fn staking_delegate(
             origin: OriginFor<T>,
             nonce: u64,
+            valid_until: BlockNumberFor<T>,
             signature: Vec<u8>,
             dst_level: u8,
         ) -> DispatchResult {
             let who = ensure_signed(origin)?;
 
-            if !T::VerifySignatureInterface::verify_atomos_signature(nonce, signature, who.clone())
-            {
+            if !T::VerifySignatureInterface::verify_atomos_signature(
+                AtmosPurpose::StakingDelegate,
+                nonce,
+                valid_until,
+                sp_io::hashing::blake2_256(&dst_level.encode()),
+                signature,
+                who.clone(),
+            ) {
                 Err(Error::<T>::SignatureVerifyFailed)?
             }
+            ensure!(
+                T::VerifySignatureInterface::consume_atomos_nonce(&who, nonce),
+                Error::<T>::InvalidAtomosNonce
+            );
             Self::do_staking_delegate(who, dst_level)
         }
 
@@ -1959,7 +1967,7 @@ pub mod pallet {
         ) -> DispatchResult {
             let who = ensure_signed(origin)?;
             ensure!(
-                T::UserPrivilegeInterface::has_privilege(&who, Privilege::ReleaseSetter),
+                T::UserPrivilegeInterface::has_approved_privilege(&who, Privilege::ReleaseSetter),
                 Error::<T>::UnauthorizedAccounts
             );
             let remainder_mining_reward = T::NumberToCurrency::convert(
@@ -2073,7 +2081,7 @@ pub mod pallet {
         ) -> DispatchResultWithPostInfo {
             let who = ensure_signed(origin)?;
             ensure!(
-                T::UserPrivilegeInterface::has_privilege(&who, Privilege::NpowMint),
+                T::UserPrivilegeInterface::has_approved_privilege(&who, Privilege::NpowMint),
                 Error::<T>::UnauthorizedAccounts
             );
 
@@ -2084,6 +2092,12 @@ pub mod pallet {
                 dpr <= remainder_mining_reward,
                 Error::<T>::InsufficientValue
             );
+            T::UserPrivilegeInterface::consume_quota(
+                &who,
+                Privilege::NpowMint,
+                QuotaResource::NpowMint,
+                dpr.unique_saturated_into(),
+            )?;
 
             T::Currency::deposit_creating(&target, dpr);
             Self::deposit_event(Event::<T>::NpowMint(target, dpr));
@@ -2325,6 +2339,8 @@ pub mod pallet {
         NotAllowUpdateCrdit,
         /// fail for verify signature
         SignatureVerifyFailed,
+        /// atomos nonce was already used
+        InvalidAtomosNonce,
         /// refere self not allow
         SelfReferee,
         /// staking balance not enough to upgrade credit level
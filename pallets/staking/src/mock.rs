@@ -193,12 +193,15 @@ impl pallet_micropayment::Config for Test {
     type NodeInterface = DeeperNode;
     type MicropaymentBurn = MicropaymentBurn;
     type Slash = ();
+    type ChannelDeposit = ConstU128<0>;
+    type RatingWeightUnit = ConstU128<1>;
 }
 
 parameter_types! {
     pub const MinLockAmt: u32 = 100;
     pub const MaxDurationEras: u8 = 7;
     pub const MaxIpLength: usize = 256;
+    pub const ReputationDecay: sp_runtime::Perbill = sp_runtime::Perbill::from_percent(90);
 }
 impl pallet_deeper_node::Config for Test {
     type RuntimeEvent = RuntimeEvent;
@@ -209,6 +212,11 @@ impl pallet_deeper_node::Config for Test {
     type MaxIpLength = MaxIpLength;
     type WeightInfo = ();
     type VerifySignatureInterface = ();
+    type RegionOrigin = frame_system::EnsureRoot<Self::AccountId>;
+    type DeviceSignature = sp_runtime::testing::TestSignature;
+    type UnsignedPriority = frame_support::traits::ConstU64<700>;
+    type MaxCreditProofs = frame_support::traits::ConstU32<4>;
+    type ReputationDecay = ReputationDecay;
 }
 
 pub const MILLISECS_PER_BLOCK: Moment = 5000;
@@ -254,6 +262,7 @@ parameter_types! {
     pub const MicropaymentToCreditFactor: u128 = 1_000_000_000_000_000;
     pub const BlocksPerEra: BlockNumber =  BLOCKS_PER_ERA;
     pub const MaxBurnCreditPerAddress: u32 = 50;
+    pub const AnomalyDeviations: u32 = 3;
 }
 
 impl pallet_credit::Config for Test {
@@ -270,6 +279,7 @@ impl pallet_credit::Config for Test {
     type BurnedTo = ();
     type UserPrivilegeInterface = UserPrivileges;
     type MaxBurnCreditPerAddress = MaxBurnCreditPerAddress;
+    type AnomalyDeviations = AnomalyDeviations;
 }
 
 parameter_types! {
@@ -285,12 +295,20 @@ impl pallet_operation::Config for Test {
     type MinimumBurnedDPR = MinimumBurnedDPR;
     type CreditInterface = Credit;
     type UserPrivilegeInterface = UserPrivileges;
+    type MaxRelayers = ConstU32<16>;
+    type BridgeApplicationTimeout = frame_support::traits::ConstU64<100>;
+    type CircuitBreakerOrigin = frame_system::EnsureRoot<AccountId>;
 }
 
 impl pallet_user_privileges::Config for Test {
     type RuntimeEvent = RuntimeEvent;
     type ForceOrigin = frame_system::EnsureRoot<AccountId>;
     type WeightInfo = ();
+    type BlocksPerDay = frame_support::traits::ConstU64<14_400>;
+    type RuntimeCall = RuntimeCall;
+    type ProposalTimeout = frame_support::traits::ConstU64<100>;
+    type MaxApprovals = frame_support::traits::ConstU32<16>;
+    type ProposableCalls = frame_support::traits::Everything;
 }
 
 parameter_types! {
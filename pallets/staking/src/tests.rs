@@ -26,6 +26,7 @@ use frame_system::RawOrigin;
 use mock::*;
 use node_primitives::{
     credit::{CreditData, CreditInterface, CreditLevel, CreditSetting, H160},
+    user_privileges::QuotaResource,
     DPR,
 };
 use pallet_balances::Error as BalancesError;
@@ -3359,6 +3360,68 @@ fn npow_mint() {
 
         RemainderMiningReward::<Test>::put(1000000);
         assert_ok!(Staking::npow_mint(RuntimeOrigin::signed(1), 2, 99900));
+
+        assert_ok!(UserPrivileges::set_privilege_quota(
+            RuntimeOrigin::root(),
+            Privilege::NpowMint,
+            QuotaResource::NpowMint,
+            Some(pallet_user_privileges::PrivilegeQuota {
+                per_account: Some(1000),
+                global: None,
+            })
+        ));
+        assert_ok!(Staking::npow_mint(RuntimeOrigin::signed(1), 2, 600));
+        assert_noop!(
+            Staking::npow_mint(RuntimeOrigin::signed(1), 2, 401),
+            pallet_user_privileges::Error::<Test>::QuotaExceeded
+        );
+        assert_eq!(
+            Staking::remainder_mining_reward(),
+            Some(1000000 - 99900 - 600)
+        );
+    });
+}
+
+#[test]
+fn npow_mint_requires_approvals() {
+    ExtBuilder::default().build_and_execute(|| {
+        run_to_block(1);
+
+        for holder in [1, 3] {
+            assert_ok!(UserPrivileges::set_user_privilege(
+                RuntimeOrigin::root(),
+                holder,
+                Privilege::NpowMint
+            ));
+        }
+        assert_ok!(UserPrivileges::set_required_approvals(
+            RuntimeOrigin::root(),
+            Privilege::NpowMint,
+            2
+        ));
+        // a single holder can no longer mint alone
+        assert_noop!(
+            Staking::npow_mint(RuntimeOrigin::signed(1), 2, 100),
+            Error::<Test>::UnauthorizedAccounts
+        );
+
+        let remainder = Staking::remainder_mining_reward().unwrap_or(MiningReward::get());
+        let call = RuntimeCall::Staking(Call::npow_mint {
+            target: 2,
+            dpr: 100,
+        });
+        assert_ok!(UserPrivileges::propose(
+            RuntimeOrigin::signed(1),
+            Privilege::NpowMint,
+            Box::new(call)
+        ));
+        assert_ok!(UserPrivileges::approve(
+            RuntimeOrigin::signed(3),
+            0,
+            Weight::MAX
+        ));
+        assert!(UserPrivileges::proposals(0).is_none());
+        assert_eq!(Staking::remainder_mining_reward(), Some(remainder - 100));
     });
 }
 
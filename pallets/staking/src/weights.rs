@@ -209,10 +209,12 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 			.saturating_add(T::DbWeight::get().reads(2_u64))
 			.saturating_add(T::DbWeight::get().writes(1_u64))
 	}
-	/// Storage: `CreditAccumulation::AtmosAccountid` (r:1 w:0)
-	/// Proof: `CreditAccumulation::AtmosAccountid` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
-	/// Storage: `CreditAccumulation::TmpAtmosAccountid` (r:1 w:0)
-	/// Proof: `CreditAccumulation::TmpAtmosAccountid` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// Storage: `CreditAccumulation::AtmosSigners` (r:1 w:0)
+	/// Proof: `CreditAccumulation::AtmosSigners` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// Storage: `CreditAccumulation::AtmosNonceSince` (r:1 w:1)
+	/// Proof: `CreditAccumulation::AtmosNonceSince` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `CreditAccumulation::AtmosNonce` (r:1 w:1)
+	/// Proof: `CreditAccumulation::AtmosNonce` (`max_values`: None, `max_size`: None, mode: `Measured`)
 	/// Storage: `Credit::DefaultCampaignId` (r:1 w:0)
 	/// Proof: `Credit::DefaultCampaignId` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
 	/// Storage: `Credit::UserCredit` (r:1 w:1)
@@ -243,8 +245,8 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 		//  Estimated: `10355`
 		// Minimum execution time: 2_656_141_000 picoseconds.
 		Weight::from_parts(2_656_141_000, 10355)
-			.saturating_add(T::DbWeight::get().reads(16_u64))
-			.saturating_add(T::DbWeight::get().writes(9_u64))
+			.saturating_add(T::DbWeight::get().reads(17_u64))
+			.saturating_add(T::DbWeight::get().writes(11_u64))
 	}
 	/// Storage: `UserPrivileges::UserPrivileges` (r:1 w:0)
 	/// Proof: `UserPrivileges::UserPrivileges` (`max_values`: None, `max_size`: Some(48), added: 2523, mode: `MaxEncodedLen`)
@@ -660,12 +662,16 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	}
 	/// Storage: `UserPrivileges::UserPrivileges` (r:1 w:0)
 	/// Proof: `UserPrivileges::UserPrivileges` (`max_values`: None, `max_size`: Some(48), added: 2523, mode: `MaxEncodedLen`)
-	/// Storage: `Staking::NpowMintDayLimit` (r:1 w:0)
-	/// Proof: `Staking::NpowMintDayLimit` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// Storage: `UserPrivileges::PrivilegeExpiry` (r:1 w:0)
+	/// Proof: `UserPrivileges::PrivilegeExpiry` (`max_values`: None, `max_size`: Some(49), added: 2524, mode: `MaxEncodedLen`)
 	/// Storage: `Staking::RemainderMiningReward` (r:1 w:1)
 	/// Proof: `Staking::RemainderMiningReward` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
-	/// Storage: `Staking::NpowDayMintedDPR` (r:1 w:1)
-	/// Proof: `Staking::NpowDayMintedDPR` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// Storage: `UserPrivileges::PrivilegeQuotas` (r:1 w:0)
+	/// Proof: `UserPrivileges::PrivilegeQuotas` (`max_values`: None, `max_size`: Some(44), added: 2519, mode: `MaxEncodedLen`)
+	/// Storage: `UserPrivileges::QuotaUsage` (r:1 w:1)
+	/// Proof: `UserPrivileges::QuotaUsage` (`max_values`: None, `max_size`: Some(82), added: 2557, mode: `MaxEncodedLen`)
+	/// Storage: `UserPrivileges::GlobalQuotaUsage` (r:1 w:1)
+	/// Proof: `UserPrivileges::GlobalQuotaUsage` (`max_values`: None, `max_size`: Some(42), added: 2517, mode: `MaxEncodedLen`)
 	/// Storage: `System::Account` (r:1 w:1)
 	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
 	fn npow_mint() -> Weight {
@@ -674,8 +680,8 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 		//  Estimated: `3593`
 		// Minimum execution time: 915_685_000 picoseconds.
 		Weight::from_parts(915_685_000, 3593)
-			.saturating_add(T::DbWeight::get().reads(5_u64))
-			.saturating_add(T::DbWeight::get().writes(3_u64))
+			.saturating_add(T::DbWeight::get().reads(7_u64))
+			.saturating_add(T::DbWeight::get().writes(4_u64))
 	}
 }
 
@@ -821,10 +827,12 @@ impl WeightInfo for () {
 			.saturating_add(RocksDbWeight::get().reads(2_u64))
 			.saturating_add(RocksDbWeight::get().writes(1_u64))
 	}
-	/// Storage: `CreditAccumulation::AtmosAccountid` (r:1 w:0)
-	/// Proof: `CreditAccumulation::AtmosAccountid` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
-	/// Storage: `CreditAccumulation::TmpAtmosAccountid` (r:1 w:0)
-	/// Proof: `CreditAccumulation::TmpAtmosAccountid` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// Storage: `CreditAccumulation::AtmosSigners` (r:1 w:0)
+	/// Proof: `CreditAccumulation::AtmosSigners` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// Storage: `CreditAccumulation::AtmosNonceSince` (r:1 w:1)
+	/// Proof: `CreditAccumulation::AtmosNonceSince` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `CreditAccumulation::AtmosNonce` (r:1 w:1)
+	/// Proof: `CreditAccumulation::AtmosNonce` (`max_values`: None, `max_size`: None, mode: `Measured`)
 	/// Storage: `Credit::DefaultCampaignId` (r:1 w:0)
 	/// Proof: `Credit::DefaultCampaignId` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
 	/// Storage: `Credit::UserCredit` (r:1 w:1)
@@ -855,8 +863,8 @@ impl WeightInfo for () {
 		//  Estimated: `10355`
 		// Minimum execution time: 2_656_141_000 picoseconds.
 		Weight::from_parts(2_656_141_000, 10355)
-			.saturating_add(RocksDbWeight::get().reads(16_u64))
-			.saturating_add(RocksDbWeight::get().writes(9_u64))
+			.saturating_add(RocksDbWeight::get().reads(17_u64))
+			.saturating_add(RocksDbWeight::get().writes(11_u64))
 	}
 	/// Storage: `UserPrivileges::UserPrivileges` (r:1 w:0)
 	/// Proof: `UserPrivileges::UserPrivileges` (`max_values`: None, `max_size`: Some(48), added: 2523, mode: `MaxEncodedLen`)
@@ -1272,12 +1280,16 @@ impl WeightInfo for () {
 	}
 	/// Storage: `UserPrivileges::UserPrivileges` (r:1 w:0)
 	/// Proof: `UserPrivileges::UserPrivileges` (`max_values`: None, `max_size`: Some(48), added: 2523, mode: `MaxEncodedLen`)
-	/// Storage: `Staking::NpowMintDayLimit` (r:1 w:0)
-	/// Proof: `Staking::NpowMintDayLimit` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// Storage: `UserPrivileges::PrivilegeExpiry` (r:1 w:0)
+	/// Proof: `UserPrivileges::PrivilegeExpiry` (`max_values`: None, `max_size`: Some(49), added: 2524, mode: `MaxEncodedLen`)
 	/// Storage: `Staking::RemainderMiningReward` (r:1 w:1)
 	/// Proof: `Staking::RemainderMiningReward` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
-	/// Storage: `Staking::NpowDayMintedDPR` (r:1 w:1)
-	/// Proof: `Staking::NpowDayMintedDPR` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// Storage: `UserPrivileges::PrivilegeQuotas` (r:1 w:0)
+	/// Proof: `UserPrivileges::PrivilegeQuotas` (`max_values`: None, `max_size`: Some(44), added: 2519, mode: `MaxEncodedLen`)
+	/// Storage: `UserPrivileges::QuotaUsage` (r:1 w:1)
+	/// Proof: `UserPrivileges::QuotaUsage` (`max_values`: None, `max_size`: Some(82), added: 2557, mode: `MaxEncodedLen`)
+	/// Storage: `UserPrivileges::GlobalQuotaUsage` (r:1 w:1)
+	/// Proof: `UserPrivileges::GlobalQuotaUsage` (`max_values`: None, `max_size`: Some(42), added: 2517, mode: `MaxEncodedLen`)
 	/// Storage: `System::Account` (r:1 w:1)
 	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
 	fn npow_mint() -> Weight {
@@ -1286,7 +1298,7 @@ impl WeightInfo for () {
 		//  Estimated: `3593`
 		// Minimum execution time: 915_685_000 picoseconds.
 		Weight::from_parts(915_685_000, 3593)
-			.saturating_add(RocksDbWeight::get().reads(5_u64))
-			.saturating_add(RocksDbWeight::get().writes(3_u64))
+			.saturating_add(RocksDbWeight::get().reads(7_u64))
+			.saturating_add(RocksDbWeight::get().writes(4_u64))
 	}
 }
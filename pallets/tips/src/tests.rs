@@ -199,6 +199,7 @@ parameter_types! {
     pub const BlocksPerEra: u64 =  17280;
     pub const SecsPerBlock: u32 = 5u32;
     pub const MaxBurnCreditPerAddress: u32 = 50;
+    pub const AnomalyDeviations: u32 = 3;
 }
 impl pallet_credit::Config for Test {
     type RuntimeEvent = RuntimeEvent;
@@ -214,6 +215,7 @@ impl pallet_credit::Config for Test {
     type BurnedTo = ();
     type UserPrivilegeInterface = ();
     type MaxBurnCreditPerAddress = MaxBurnCreditPerAddress;
+    type AnomalyDeviations = AnomalyDeviations;
 }
 parameter_types! {
     pub const MinimumPeriod: u64 = 5;
@@ -228,6 +230,7 @@ parameter_types! {
     pub const MinLockAmt: u32 = 100;
     pub const MaxDurationEras: u8 = 7;
     pub const MaxIpLength: usize = 256;
+    pub const ReputationDecay: sp_runtime::Perbill = sp_runtime::Perbill::from_percent(90);
 }
 impl pallet_deeper_node::Config for Test {
     type RuntimeEvent = RuntimeEvent;
@@ -238,6 +241,11 @@ impl pallet_deeper_node::Config for Test {
     type MaxIpLength = MaxIpLength;
     type WeightInfo = ();
     type VerifySignatureInterface = ();
+    type RegionOrigin = frame_system::EnsureRoot<Self::AccountId>;
+    type DeviceSignature = sp_runtime::testing::TestSignature;
+    type UnsignedPriority = frame_support::traits::ConstU64<700>;
+    type MaxCreditProofs = frame_support::traits::ConstU32<4>;
+    type ReputationDecay = ReputationDecay;
 }
 parameter_types! {
     pub const TipCountdown: u64 = 1;
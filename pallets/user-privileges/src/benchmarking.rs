@@ -24,10 +24,11 @@ use super::*;
 use crate::Pallet as UserPriv;
 
 use frame_benchmarking::{account, benchmarks};
-use frame_support::traits::EnsureOrigin;
+use frame_support::{dispatch::GetDispatchInfo, traits::EnsureOrigin};
 use frame_system::RawOrigin;
-use node_primitives::user_privileges::{Privilege, UserPrivilegeInterface};
+use node_primitives::user_privileges::{Privilege, QuotaResource, UserPrivilegeInterface};
 use sp_runtime::traits::StaticLookup;
+use sp_std::vec;
 
 benchmarks! {
     set_user_privilege {
@@ -68,5 +69,101 @@ benchmarks! {
         assert_eq!(UserPriv::<T>::has_evm_privilege(&H160::from_low_u64_be(88), Privilege::LockerMember),false);
     }
 
+    set_user_privilege_with_expiry {
+        let user: T::AccountId = account("user", 0, 2);
+        let user_lookup = T::Lookup::unlookup(user.clone());
+        let origin = T::ForceOrigin::try_successful_origin().expect("author user");
+    }: _<T::RuntimeOrigin>(origin, user_lookup, Privilege::OracleWorker, 100u32.into())
+    verify {
+        assert_eq!(UserPriv::<T>::privilege_expiry(&user, Privilege::OracleWorker), Some(100u32.into()));
+    }
+
+    set_evm_privilege_with_expiry {
+        let user: T::AccountId = account("user", 0, 1);
+        let user_lookup = T::Lookup::unlookup(user.clone());
+        let origin = T::ForceOrigin::try_successful_origin().expect("author user");
+        let _ = UserPriv::<T>::set_user_privilege(origin, user_lookup, Privilege::EvmAddressSetter);
+    }: _(RawOrigin::Signed(user), H160::from_low_u64_be(88), Privilege::EvmCreditOperation, 100u32.into())
+    verify {
+        assert_eq!(UserPriv::<T>::evm_privilege_expiry(&H160::from_low_u64_be(88), Privilege::EvmCreditOperation), Some(100u32.into()));
+    }
+
+    expire_privilege {
+        let user: T::AccountId = account("user", 0, 2);
+        let user_lookup = T::Lookup::unlookup(user.clone());
+        let origin = T::ForceOrigin::try_successful_origin().expect("author user");
+        let _ = UserPriv::<T>::set_user_privilege_with_expiry(origin, user_lookup, Privilege::OracleWorker, 100u32.into());
+        let holder = PrivilegeHolder::Account(user.clone());
+    }: {
+        UserPriv::<T>::expire_privilege(100u32.into(), holder, Privilege::OracleWorker);
+    }
+    verify {
+        assert_eq!(UserPriv::<T>::privilege_expiry(&user, Privilege::OracleWorker), None);
+        assert_eq!(UserPriv::<T>::user_privileges(&user).map(|privs| privs.0.contains(Privilege::OracleWorker)), Some(false));
+    }
+
+    set_privilege_quota {
+        let origin = T::ForceOrigin::try_successful_origin().expect("author user");
+        let quota = PrivilegeQuota { per_account: Some(100), global: Some(1_000) };
+    }: _<T::RuntimeOrigin>(origin, Privilege::NpowMint, QuotaResource::NpowMint, Some(quota))
+    verify {
+        assert_eq!(UserPriv::<T>::privilege_quota((Privilege::NpowMint, QuotaResource::NpowMint)), Some(quota));
+    }
+
+    set_required_approvals {
+        let origin = T::ForceOrigin::try_successful_origin().expect("author user");
+    }: _<T::RuntimeOrigin>(origin, Privilege::NpowMint, 2)
+    verify {
+        assert_eq!(UserPriv::<T>::required_approvals(Privilege::NpowMint), 2);
+    }
+
+    propose {
+        let user: T::AccountId = account("user", 0, 1);
+        let user_lookup = T::Lookup::unlookup(user.clone());
+        let origin = T::ForceOrigin::try_successful_origin().expect("author user");
+        let _ = UserPriv::<T>::set_user_privilege(origin.clone(), user_lookup, Privilege::NpowMint);
+        let _ = UserPriv::<T>::set_required_approvals(origin, Privilege::NpowMint, 2);
+        let call: <T as Config>::RuntimeCall = frame_system::Call::<T>::remark { remark: vec![] }.into();
+    }: _(RawOrigin::Signed(user), Privilege::NpowMint, Box::new(call))
+    verify {
+        assert!(UserPriv::<T>::proposals(0).is_some());
+    }
+
+    approve {
+        let a in 2 .. T::MaxApprovals::get();
+        let proposer: T::AccountId = account("user", 0, 1);
+        let approver: T::AccountId = account("user", a - 1, 1);
+        let origin = T::ForceOrigin::try_successful_origin().expect("author user");
+        for i in 0 .. a {
+            let holder: T::AccountId = account("user", i, 1);
+            let _ = UserPriv::<T>::set_user_privilege(origin.clone(), T::Lookup::unlookup(holder), Privilege::NpowMint);
+        }
+        let _ = UserPriv::<T>::set_required_approvals(origin, Privilege::NpowMint, a);
+        let call: <T as Config>::RuntimeCall = frame_system::Call::<T>::remark { remark: vec![] }.into();
+        let max_weight = call.get_dispatch_info().weight;
+        let _ = UserPriv::<T>::propose(RawOrigin::Signed(proposer).into(), Privilege::NpowMint, Box::new(call));
+        for i in 1 .. a - 1 {
+            let holder: T::AccountId = account("user", i, 1);
+            let _ = UserPriv::<T>::approve(RawOrigin::Signed(holder).into(), 0, max_weight);
+        }
+    }: _(RawOrigin::Signed(approver), 0, max_weight)
+    verify {
+        assert!(UserPriv::<T>::proposals(0).is_none());
+    }
+
+    remove_expired_proposal {
+        let user: T::AccountId = account("user", 0, 1);
+        let user_lookup = T::Lookup::unlookup(user.clone());
+        let origin = T::ForceOrigin::try_successful_origin().expect("author user");
+        let _ = UserPriv::<T>::set_user_privilege(origin.clone(), user_lookup, Privilege::NpowMint);
+        let _ = UserPriv::<T>::set_required_approvals(origin, Privilege::NpowMint, 2);
+        let call: <T as Config>::RuntimeCall = frame_system::Call::<T>::remark { remark: vec![] }.into();
+        let _ = UserPriv::<T>::propose(RawOrigin::Signed(user.clone()).into(), Privilege::NpowMint, Box::new(call));
+        frame_system::Pallet::<T>::set_block_number(T::ProposalTimeout::get() + 1u32.into());
+    }: _(RawOrigin::Signed(user), 0)
+    verify {
+        assert!(UserPriv::<T>::proposals(0).is_none());
+    }
+
     impl_benchmark_test_suite!(UserPriv, crate::tests::new_test_ext(), crate::tests::Test);
 }
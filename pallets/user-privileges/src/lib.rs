@@ -24,7 +24,7 @@ mod tests;
 pub mod benchmarking;
 
 pub mod weights;
-use sp_std::prelude::*;
+use sp_std::{prelude::*, vec};
 pub use weights::WeightInfo;
 
 #[frame_support::pallet]
@@ -32,23 +32,86 @@ pub mod pallet {
     use super::*;
     use codec::{Decode, Encode, MaxEncodedLen};
     use enumflags2::BitFlags;
-    use frame_support::{ensure, pallet_prelude::*, storage::migration::get_storage_value};
+    use frame_support::{
+        dispatch::{extract_actual_weight, GetDispatchInfo, PostDispatchInfo},
+        ensure,
+        pallet_prelude::*,
+        storage::migration::get_storage_value,
+        traits::Contains,
+    };
     use frame_system::{self, ensure_signed, pallet_prelude::*};
-    use node_primitives::user_privileges::{Privilege, Privileges, UserPrivilegeInterface};
+    use node_primitives::{
+        sweep::sweep_expirations,
+        user_privileges::{Privilege, Privileges, QuotaResource, UserPrivilegeInterface},
+        DPR,
+    };
     pub use sp_core::H160;
-    use sp_runtime::{traits::StaticLookup, RuntimeDebug};
+    use sp_runtime::{
+        traits::{Dispatchable, One, StaticLookup},
+        RuntimeDebug,
+    };
 
     #[derive(Encode, Decode, Clone, Copy, PartialEq, Eq, RuntimeDebug, MaxEncodedLen, TypeInfo)]
     pub enum Releases {
         V1_0_0,
         V2_0_0,
+        V3_0_0,
     }
 
+    /// Holder of a privilege which expires
+    #[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, MaxEncodedLen, TypeInfo)]
+    pub enum PrivilegeHolder<AccountId> {
+        Account(AccountId),
+        Evm(H160),
+    }
+
+    /// Daily limits on what the holders of a privilege may consume, `None` means unlimited
+    #[derive(
+        Encode, Decode, Clone, Copy, Default, PartialEq, Eq, RuntimeDebug, MaxEncodedLen, TypeInfo,
+    )]
+    pub struct PrivilegeQuota {
+        pub per_account: Option<u128>,
+        pub global: Option<u128>,
+    }
+
+    /// A call waiting for the holders of `privilege` to approve it
+    #[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+    pub struct Proposal<AccountId, Call, BlockNumber> {
+        pub proposer: AccountId,
+        pub privilege: Privilege,
+        pub call: Call,
+        /// holders who approved the call, the proposer included
+        pub approvals: Vec<AccountId>,
+        pub expiry: BlockNumber,
+    }
+
+    pub type ProposalOf<T> = Proposal<
+        <T as frame_system::Config>::AccountId,
+        <T as Config>::RuntimeCall,
+        BlockNumberFor<T>,
+    >;
+
     #[pallet::config]
     pub trait Config: frame_system::Config {
         type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
         type ForceOrigin: EnsureOrigin<Self::RuntimeOrigin>;
         type WeightInfo: WeightInfo;
+        /// Number of blocks after which quotas are replenished
+        #[pallet::constant]
+        type BlocksPerDay: Get<BlockNumberFor<Self>>;
+        /// The call a proposal executes once approved
+        type RuntimeCall: Parameter
+            + Dispatchable<RuntimeOrigin = Self::RuntimeOrigin, PostInfo = PostDispatchInfo>
+            + GetDispatchInfo
+            + From<frame_system::Call<Self>>;
+        /// Number of blocks a proposal can collect approvals for
+        #[pallet::constant]
+        type ProposalTimeout: Get<BlockNumberFor<Self>>;
+        /// Maximum number of approvals a privilege can require
+        #[pallet::constant]
+        type MaxApprovals: Get<u32>;
+        /// Calls gated by each privilege, the only calls a proposal for it may execute
+        type ProposableCalls: Contains<(Privilege, <Self as Config>::RuntimeCall)>;
     }
 
     #[pallet::pallet]
@@ -64,6 +127,16 @@ pub mod pallet {
         EvmPrivilegeSet(H160, Privilege),
         EvmPrivilegeUnSet(H160, Privilege),
         EvmPrivilegeClear(H160),
+        UserPrivilegeSetUntil(T::AccountId, Privilege, BlockNumberFor<T>),
+        EvmPrivilegeSetUntil(H160, Privilege, BlockNumberFor<T>),
+        PrivilegeExpired(T::AccountId, Privilege),
+        EvmPrivilegeExpired(H160, Privilege),
+        PrivilegeQuotaSet(Privilege, QuotaResource, PrivilegeQuota),
+        RequiredApprovalsSet(Privilege, u32),
+        Proposed(u32, T::AccountId, Privilege),
+        ProposalApproved(u32, T::AccountId),
+        ProposalExecuted(u32, DispatchResult),
+        ProposalExpired(u32),
     }
 
     // Errors inform users that something went wrong.
@@ -73,6 +146,26 @@ pub mod pallet {
         NotExistPrivilege,
         /// not has right to do
         NoPermission,
+        /// expiry block is not in the future
+        ExpiryInPast,
+        /// daily quota of the privilege is exhausted
+        QuotaExceeded,
+        /// more approvals than MaxApprovals
+        TooManyApprovals,
+        /// the privilege does not require several approvals
+        ApprovalNotRequired,
+        /// proposal does not exist
+        ProposalNotFound,
+        /// proposal timed out
+        ProposalExpired,
+        /// proposal can still be approved
+        ProposalNotExpired,
+        /// proposal is already approved by the account
+        AlreadyApproved,
+        /// max weight is lower than the weight of the proposed call
+        MaxWeightTooLow,
+        /// the call is not gated by the privilege of the proposal
+        CallNotProposable,
     }
 
     #[pallet::storage]
@@ -88,6 +181,119 @@ pub mod pallet {
     #[pallet::storage]
     pub(super) type StorageVersion<T: Config> = StorageValue<_, Releases>;
 
+    // block at which a privilege of an account is revoked, absent for permanent grants
+    #[pallet::storage]
+    #[pallet::getter(fn privilege_expiry)]
+    pub(super) type PrivilegeExpiry<T: Config> = StorageDoubleMap<
+        _,
+        Twox64Concat,
+        T::AccountId,
+        Twox64Concat,
+        Privilege,
+        BlockNumberFor<T>,
+        OptionQuery,
+    >;
+
+    // block at which a privilege of an evm address is revoked, absent for permanent grants
+    #[pallet::storage]
+    #[pallet::getter(fn evm_privilege_expiry)]
+    pub(super) type EvmPrivilegeExpiry<T: Config> = StorageDoubleMap<
+        _,
+        Twox64Concat,
+        H160,
+        Twox64Concat,
+        Privilege,
+        BlockNumberFor<T>,
+        OptionQuery,
+    >;
+
+    // privileges to revoke, indexed by expiry block
+    #[pallet::storage]
+    #[pallet::getter(fn privilege_expiration)]
+    pub(super) type PrivilegeExpirations<T: Config> = StorageDoubleMap<
+        _,
+        Twox64Concat,
+        BlockNumberFor<T>,
+        Blake2_128Concat,
+        (PrivilegeHolder<T::AccountId>, Privilege),
+        (),
+        OptionQuery,
+    >;
+
+    // accounts holding a privilege
+    #[pallet::storage]
+    pub(super) type PrivilegeHolders<T: Config> = StorageDoubleMap<
+        _,
+        Twox64Concat,
+        Privilege,
+        Blake2_128Concat,
+        T::AccountId,
+        (),
+        OptionQuery,
+    >;
+
+    // evm addresses holding a privilege
+    #[pallet::storage]
+    pub(super) type EvmPrivilegeHolders<T: Config> =
+        StorageDoubleMap<_, Twox64Concat, Privilege, Blake2_128Concat, H160, (), OptionQuery>;
+
+    // daily quota of a resource consumed under a privilege, resources without one are unlimited
+    #[pallet::storage]
+    #[pallet::getter(fn privilege_quota)]
+    pub(super) type PrivilegeQuotas<T: Config> =
+        StorageMap<_, Twox64Concat, (Privilege, QuotaResource), PrivilegeQuota, OptionQuery>;
+
+    // day and amount of a privilege quota an account consumed that day
+    #[pallet::storage]
+    #[pallet::getter(fn quota_usage)]
+    pub(super) type QuotaUsage<T: Config> = StorageDoubleMap<
+        _,
+        Twox64Concat,
+        (Privilege, QuotaResource),
+        Blake2_128Concat,
+        T::AccountId,
+        (BlockNumberFor<T>, u128),
+        ValueQuery,
+    >;
+
+    // day and amount of a privilege quota all accounts consumed that day
+    #[pallet::storage]
+    #[pallet::getter(fn global_quota_usage)]
+    pub(super) type GlobalQuotaUsage<T: Config> = StorageMap<
+        _,
+        Twox64Concat,
+        (Privilege, QuotaResource),
+        (BlockNumberFor<T>, u128),
+        ValueQuery,
+    >;
+
+    // number of distinct holders who must approve a call gated by a privilege, at most one
+    // means the holders act alone
+    #[pallet::storage]
+    #[pallet::getter(fn required_approvals)]
+    pub(super) type RequiredApprovals<T: Config> =
+        StorageMap<_, Twox64Concat, Privilege, u32, ValueQuery>;
+
+    #[pallet::storage]
+    #[pallet::getter(fn next_proposal_id)]
+    pub(super) type NextProposalId<T: Config> = StorageValue<_, u32, ValueQuery>;
+
+    #[pallet::storage]
+    #[pallet::getter(fn proposals)]
+    #[pallet::unbounded]
+    pub(super) type Proposals<T: Config> =
+        StorageMap<_, Twox64Concat, u32, ProposalOf<T>, OptionQuery>;
+
+    // proposer and privilege of the approved proposal being executed
+    #[pallet::storage]
+    pub(super) type ApprovedExecution<T: Config> =
+        StorageValue<_, (T::AccountId, Privilege), OptionQuery>;
+
+    // the earliest expiry block which has not been swept yet
+    #[pallet::storage]
+    #[pallet::getter(fn next_expiry_to_sweep)]
+    pub(super) type NextExpiryToSweep<T: Config> = StorageValue<_, BlockNumberFor<T>, ValueQuery>;
+
     #[pallet::hooks]
     impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
         fn on_runtime_upgrade() -> Weight {
@@ -136,9 +342,20 @@ pub mod pallet {
                 }
                 StorageVersion::<T>::put(Releases::V2_0_0);
                 return T::DbWeight::get().reads_writes(2, 2);
+            } else if version == Some(Releases::V2_0_0) {
+                // nothing expires before the upgrade, start sweeping from here
+                NextExpiryToSweep::<T>::put(<frame_system::Pallet<T>>::block_number());
+                let indexed = Self::index_privilege_holders();
+                Self::set_default_quotas();
+                StorageVersion::<T>::put(Releases::V3_0_0);
+                return T::DbWeight::get().reads_writes(2 + indexed, 3 + indexed);
             }
             Weight::zero()
         }
+
+        fn on_idle(now: BlockNumberFor<T>, remaining_weight: Weight) -> Weight {
+            Self::sweep_expired_privileges(now, remaining_weight)
+        }
     }
 
     #[pallet::call]
@@ -152,16 +369,7 @@ pub mod pallet {
         ) -> DispatchResult {
             T::ForceOrigin::ensure_origin(origin)?;
             let who = T::Lookup::lookup(who)?;
-
-            let old_priv = Self::user_privileges(&who);
-            let new_priv = {
-                match old_priv {
-                    Some(old_priv) => old_priv.0 | BitFlags::from_flag(privilege),
-                    None => privilege.into(),
-                }
-            };
-
-            UserPrivileges::<T>::insert(&who, Privileges(new_priv));
+            Self::do_set_user_privilege(&who, privilege, None);
             Self::deposit_event(Event::UserPrivilegeSet(who, privilege));
             Ok(().into())
         }
@@ -183,6 +391,8 @@ pub mod pallet {
             let mut new_priv = old_priv.unwrap();
             new_priv.0.remove(privilege);
             UserPrivileges::<T>::insert(&who, new_priv);
+            PrivilegeHolders::<T>::remove(privilege, &who);
+            Self::update_expiry(&PrivilegeHolder::Account(who.clone()), privilege, None);
             Self::deposit_event(Event::UserPrivilegeUnSet(who, privilege));
             Ok(().into())
         }
@@ -195,7 +405,17 @@ pub mod pallet {
         ) -> DispatchResult {
             T::ForceOrigin::ensure_origin(origin)?;
             let who = T::Lookup::lookup(who)?;
-            UserPrivileges::<T>::remove(&who);
+            if let Some(privs) = UserPrivileges::<T>::take(&who) {
+                for privilege in privs.0.iter() {
+                    PrivilegeHolders::<T>::remove(privilege, &who);
+                }
+            }
+            for (privilege, expiry) in PrivilegeExpiry::<T>::drain_prefix(&who) {
+                PrivilegeExpirations::<T>::remove(
+                    expiry,
+                    (PrivilegeHolder::Account(who.clone()), privilege),
+                );
+            }
             Self::deposit_event(Event::UserPrivilegeClear(who));
             Ok(().into())
         }
@@ -212,15 +432,7 @@ pub mod pallet {
                 Self::has_privilege(&sender, Privilege::EvmAddressSetter),
                 Error::<T>::NoPermission
             );
-            let old_priv = Self::evm_address_privileges(&who);
-            let new_priv = {
-                match old_priv {
-                    Some(old_priv) => old_priv.0 | BitFlags::from_flag(privilege),
-                    None => privilege.into(),
-                }
-            };
-            EvmAddressPrivileges::<T>::insert(&who, Privileges(new_priv));
-            Self::deposit_event(Event::EvmPrivilegeSet(who, privilege));
+            Self::grant_evm_privilege(&who, privilege);
             Ok(().into())
         }
 
@@ -236,16 +448,7 @@ pub mod pallet {
                 Self::has_privilege(&sender, Privilege::EvmAddressSetter),
                 Error::<T>::NoPermission
             );
-            let old_priv = Self::evm_address_privileges(&who);
-            if old_priv.is_none() {
-                return Err(Error::<T>::NotExistPrivilege.into());
-            }
-            let mut new_priv = old_priv.unwrap();
-            new_priv.0.remove(privilege);
-            EvmAddressPrivileges::<T>::insert(&who, new_priv);
-
-            Self::deposit_event(Event::EvmPrivilegeUnSet(who, privilege));
-            Ok(().into())
+            Self::revoke_evm_privilege(&who, privilege)
         }
 
         #[pallet::call_index(5)]
@@ -256,10 +459,420 @@ pub mod pallet {
                 Self::has_privilege(&sender, Privilege::EvmAddressSetter),
                 Error::<T>::NoPermission
             );
-            EvmAddressPrivileges::<T>::remove(&who);
+            if let Some(privs) = EvmAddressPrivileges::<T>::take(&who) {
+                for privilege in privs.0.iter() {
+                    EvmPrivilegeHolders::<T>::remove(privilege, &who);
+                }
+            }
+            for (privilege, expiry) in EvmPrivilegeExpiry::<T>::drain_prefix(&who) {
+                PrivilegeExpirations::<T>::remove(expiry, (PrivilegeHolder::Evm(who), privilege));
+            }
             Self::deposit_event(Event::EvmPrivilegeClear(who));
             Ok(().into())
         }
+
+        /// Grant a privilege which is revoked at block `expiry`
+        #[pallet::call_index(6)]
+        #[pallet::weight(T::WeightInfo::set_user_privilege_with_expiry())]
+        pub fn set_user_privilege_with_expiry(
+            origin: OriginFor<T>,
+            who: <T::Lookup as StaticLookup>::Source,
+            privilege: Privilege,
+            expiry: BlockNumberFor<T>,
+        ) -> DispatchResult {
+            T::ForceOrigin::ensure_origin(origin)?;
+            let who = T::Lookup::lookup(who)?;
+            ensure!(
+                expiry > <frame_system::Pallet<T>>::block_number(),
+                Error::<T>::ExpiryInPast
+            );
+            Self::do_set_user_privilege(&who, privilege, Some(expiry));
+            Self::deposit_event(Event::UserPrivilegeSetUntil(who, privilege, expiry));
+            Ok(().into())
+        }
+
+        /// Grant a privilege to an evm address which is revoked at block `expiry`
+        #[pallet::call_index(7)]
+        #[pallet::weight(T::WeightInfo::set_evm_privilege_with_expiry())]
+        pub fn set_evm_privilege_with_expiry(
+            origin: OriginFor<T>,
+            who: H160,
+            privilege: Privilege,
+            expiry: BlockNumberFor<T>,
+        ) -> DispatchResult {
+            let sender = ensure_signed(origin)?;
+            ensure!(
+                Self::has_privilege(&sender, Privilege::EvmAddressSetter),
+                Error::<T>::NoPermission
+            );
+            ensure!(
+                expiry > <frame_system::Pallet<T>>::block_number(),
+                Error::<T>::ExpiryInPast
+            );
+            Self::do_set_evm_privilege(&who, privilege, Some(expiry));
+            Self::deposit_event(Event::EvmPrivilegeSetUntil(who, privilege, expiry));
+            Ok(().into())
+        }
+
+        /// Set the daily quota of a resource consumed under a privilege, in the unit of the
+        /// resource. Each resource a privilege gates has its own quota.
+        #[pallet::call_index(8)]
+        #[pallet::weight(T::WeightInfo::set_privilege_quota())]
+        pub fn set_privilege_quota(
+            origin: OriginFor<T>,
+            privilege: Privilege,
+            resource: QuotaResource,
+            quota: Option<PrivilegeQuota>,
+        ) -> DispatchResult {
+            T::ForceOrigin::ensure_origin(origin)?;
+            match quota {
+                Some(quota) => PrivilegeQuotas::<T>::insert((privilege, resource), quota),
+                None => PrivilegeQuotas::<T>::remove((privilege, resource)),
+            }
+            Self::deposit_event(Event::PrivilegeQuotaSet(
+                privilege,
+                resource,
+                quota.unwrap_or_default(),
+            ));
+            Ok(().into())
+        }
+
+        /// Require `approvals` distinct holders of a privilege to approve the calls it gates
+        #[pallet::call_index(9)]
+        #[pallet::weight(T::WeightInfo::set_required_approvals())]
+        pub fn set_required_approvals(
+            origin: OriginFor<T>,
+            privilege: Privilege,
+            approvals: u32,
+        ) -> DispatchResult {
+            T::ForceOrigin::ensure_origin(origin)?;
+            ensure!(
+                approvals <= T::MaxApprovals::get(),
+                Error::<T>::TooManyApprovals
+            );
+            if approvals > 1 {
+                RequiredApprovals::<T>::insert(privilege, approvals);
+            } else {
+                RequiredApprovals::<T>::remove(privilege);
+            }
+            Self::deposit_event(Event::RequiredApprovalsSet(privilege, approvals));
+            Ok(().into())
+        }
+
+        /// Propose a call gated by `privilege`, the proposal counts as the first approval
+        #[pallet::call_index(10)]
+        #[pallet::weight(T::WeightInfo::propose())]
+        pub fn propose(
+            origin: OriginFor<T>,
+            privilege: Privilege,
+            call: Box<<T as Config>::RuntimeCall>,
+        ) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+            ensure!(
+                Self::has_privilege(&who, privilege),
+                Error::<T>::NoPermission
+            );
+            ensure!(
+                Self::required_approvals(privilege) > 1,
+                Error::<T>::ApprovalNotRequired
+            );
+            let proposed = (privilege, *call);
+            ensure!(
+                T::ProposableCalls::contains(&proposed),
+                Error::<T>::CallNotProposable
+            );
+            let (privilege, call) = proposed;
+            let id = Self::next_proposal_id();
+            let expiry =
+                <frame_system::Pallet<T>>::block_number().saturating_add(T::ProposalTimeout::get());
+            Proposals::<T>::insert(
+                id,
+                Proposal {
+                    proposer: who.clone(),
+                    privilege,
+                    call,
+                    approvals: vec![who.clone()],
+                    expiry,
+                },
+            );
+            NextProposalId::<T>::put(id.wrapping_add(1));
+            Self::deposit_event(Event::Proposed(id, who, privilege));
+            Ok(().into())
+        }
+
+        /// Approve a proposal, the call is executed as the proposer by the last required
+        /// approval. Only approvals of accounts still holding the privilege are counted.
+        /// `max_weight` must cover the weight of the call.
+        #[pallet::call_index(11)]
+        #[pallet::weight(
+            T::WeightInfo::approve(T::MaxApprovals::get()).saturating_add(*max_weight)
+        )]
+        pub fn approve(
+            origin: OriginFor<T>,
+            id: u32,
+            max_weight: Weight,
+        ) -> DispatchResultWithPostInfo {
+            let who = ensure_signed(origin)?;
+            let mut proposal = Self::proposals(id).ok_or(Error::<T>::ProposalNotFound)?;
+            ensure!(
+                !Self::is_expired(Some(proposal.expiry)),
+                Error::<T>::ProposalExpired
+            );
+            ensure!(
+                Self::has_privilege(&who, proposal.privilege),
+                Error::<T>::NoPermission
+            );
+            ensure!(
+                !proposal.approvals.contains(&who),
+                Error::<T>::AlreadyApproved
+            );
+            proposal.approvals.push(who.clone());
+            Self::deposit_event(Event::ProposalApproved(id, who));
+
+            // drop the approvals of holders whose privilege was revoked or expired since
+            let approve_weight = T::WeightInfo::approve(proposal.approvals.len() as u32);
+            let privilege = proposal.privilege;
+            proposal
+                .approvals
+                .retain(|approver| Self::has_privilege(approver, privilege));
+            if (proposal.approvals.len() as u32) < Self::required_approvals(privilege) {
+                Proposals::<T>::insert(id, proposal);
+                return Ok(Some(approve_weight).into());
+            }
+
+            let call_info = proposal.call.get_dispatch_info();
+            ensure!(
+                call_info.weight.all_lte(max_weight),
+                Error::<T>::MaxWeightTooLow
+            );
+            Proposals::<T>::remove(id);
+            let outer_execution = ApprovedExecution::<T>::get();
+            ApprovedExecution::<T>::put((proposal.proposer.clone(), proposal.privilege));
+            let result = proposal
+                .call
+                .dispatch(frame_system::RawOrigin::Signed(proposal.proposer).into());
+            ApprovedExecution::<T>::set(outer_execution);
+            let call_weight = extract_actual_weight(&result, &call_info);
+            Self::deposit_event(Event::ProposalExecuted(
+                id,
+                result.map(|_| ()).map_err(|e| e.error),
+            ));
+            Ok(Some(approve_weight.saturating_add(call_weight)).into())
+        }
+
+        /// Remove a proposal which timed out
+        #[pallet::call_index(12)]
+        #[pallet::weight(T::WeightInfo::remove_expired_proposal())]
+        pub fn remove_expired_proposal(origin: OriginFor<T>, id: u32) -> DispatchResult {
+            ensure_signed(origin)?;
+            let proposal = Self::proposals(id).ok_or(Error::<T>::ProposalNotFound)?;
+            ensure!(
+                Self::is_expired(Some(proposal.expiry)),
+                Error::<T>::ProposalNotExpired
+            );
+            Proposals::<T>::remove(id);
+            Self::deposit_event(Event::ProposalExpired(id));
+            Ok(().into())
+        }
+    }
+
+    impl<T: Config> Pallet<T> {
+        /// Daily npow minting was meant to be capped at 100_000 DPR
+        fn set_default_quotas() {
+            PrivilegeQuotas::<T>::insert(
+                (Privilege::NpowMint, QuotaResource::NpowMint),
+                PrivilegeQuota {
+                    per_account: None,
+                    global: Some(100_000 * DPR),
+                },
+            );
+        }
+
+        fn current_day() -> BlockNumberFor<T> {
+            <frame_system::Pallet<T>>::block_number() / T::BlocksPerDay::get().max(One::one())
+        }
+
+        fn do_set_user_privilege(
+            who: &T::AccountId,
+            privilege: Privilege,
+            expiry: Option<BlockNumberFor<T>>,
+        ) {
+            let new_priv = match Self::user_privileges(who) {
+                Some(old_priv) => old_priv.0 | BitFlags::from_flag(privilege),
+                None => privilege.into(),
+            };
+            UserPrivileges::<T>::insert(who, Privileges(new_priv));
+            PrivilegeHolders::<T>::insert(privilege, who, ());
+            Self::update_expiry(&PrivilegeHolder::Account(who.clone()), privilege, expiry);
+        }
+
+        fn do_set_evm_privilege(
+            who: &H160,
+            privilege: Privilege,
+            expiry: Option<BlockNumberFor<T>>,
+        ) {
+            let new_priv = match Self::evm_address_privileges(who) {
+                Some(old_priv) => old_priv.0 | BitFlags::from_flag(privilege),
+                None => privilege.into(),
+            };
+            EvmAddressPrivileges::<T>::insert(who, Privileges(new_priv));
+            EvmPrivilegeHolders::<T>::insert(privilege, who, ());
+            Self::update_expiry(&PrivilegeHolder::Evm(*who), privilege, expiry);
+        }
+
+        /// Permanently grant a privilege to an evm address, the caller checks the permission
+        pub fn grant_evm_privilege(who: &H160, privilege: Privilege) {
+            Self::do_set_evm_privilege(who, privilege, None);
+            Self::deposit_event(Event::EvmPrivilegeSet(*who, privilege));
+        }
+
+        /// Revoke a privilege of an evm address, the caller checks the permission
+        pub fn revoke_evm_privilege(who: &H160, privilege: Privilege) -> DispatchResult {
+            let mut new_priv =
+                Self::evm_address_privileges(who).ok_or(Error::<T>::NotExistPrivilege)?;
+            new_priv.0.remove(privilege);
+            EvmAddressPrivileges::<T>::insert(who, new_priv);
+            EvmPrivilegeHolders::<T>::remove(privilege, who);
+            Self::update_expiry(&PrivilegeHolder::Evm(*who), privilege, None);
+            Self::deposit_event(Event::EvmPrivilegeUnSet(*who, privilege));
+            Ok(())
+        }
+
+        /// Replace the expiry of a privilege, `None` makes the grant permanent
+        fn update_expiry(
+            holder: &PrivilegeHolder<T::AccountId>,
+            privilege: Privilege,
+            expiry: Option<BlockNumberFor<T>>,
+        ) {
+            let old_expiry = match holder {
+                PrivilegeHolder::Account(who) => PrivilegeExpiry::<T>::take(who, privilege),
+                PrivilegeHolder::Evm(who) => EvmPrivilegeExpiry::<T>::take(who, privilege),
+            };
+            if let Some(old_expiry) = old_expiry {
+                PrivilegeExpirations::<T>::remove(old_expiry, (holder, privilege));
+            }
+            if let Some(expiry) = expiry {
+                match holder {
+                    PrivilegeHolder::Account(who) => {
+                        PrivilegeExpiry::<T>::insert(who, privilege, expiry)
+                    }
+                    PrivilegeHolder::Evm(who) => {
+                        EvmPrivilegeExpiry::<T>::insert(who, privilege, expiry)
+                    }
+                }
+                PrivilegeExpirations::<T>::insert(expiry, (holder, privilege), ());
+            }
+        }
+
+        /// Accounts holding an unexpired `privilege`
+        pub fn privilege_holders(privilege: Privilege) -> Vec<T::AccountId> {
+            PrivilegeHolders::<T>::iter_key_prefix(privilege)
+                .filter(|who| !Self::is_expired(Self::privilege_expiry(who, privilege)))
+                .collect()
+        }
+
+        /// Evm addresses holding an unexpired `privilege`
+        pub fn evm_privilege_holders(privilege: Privilege) -> Vec<H160> {
+            EvmPrivilegeHolders::<T>::iter_key_prefix(privilege)
+                .filter(|who| !Self::is_expired(Self::evm_privilege_expiry(who, privilege)))
+                .collect()
+        }
+
+        /// Unexpired privileges of an account
+        pub fn privileges_of(who: &T::AccountId) -> Vec<Privilege> {
+            Self::user_privileges(who)
+                .map(|privs| {
+                    privs
+                        .0
+                        .iter()
+                        .filter(|p| !Self::is_expired(Self::privilege_expiry(who, p)))
+                        .collect()
+                })
+                .unwrap_or_default()
+        }
+
+        /// Unexpired privileges of an evm address
+        pub fn evm_privileges_of(who: &H160) -> Vec<Privilege> {
+            Self::evm_address_privileges(who)
+                .map(|privs| {
+                    privs
+                        .0
+                        .iter()
+                        .filter(|p| !Self::is_expired(Self::evm_privilege_expiry(who, p)))
+                        .collect()
+                })
+                .unwrap_or_default()
+        }
+
+        /// Build the holder index from the granted privileges, returns the number of grants
+        fn index_privilege_holders() -> u64 {
+            let mut indexed = 0u64;
+            for (who, privs) in UserPrivileges::<T>::iter() {
+                for privilege in privs.0.iter() {
+                    PrivilegeHolders::<T>::insert(privilege, &who, ());
+                    indexed += 1;
+                }
+            }
+            for (who, privs) in EvmAddressPrivileges::<T>::iter() {
+                for privilege in privs.0.iter() {
+                    EvmPrivilegeHolders::<T>::insert(privilege, who, ());
+                    indexed += 1;
+                }
+            }
+            indexed
+        }
+
+        fn is_expired(expiry: Option<BlockNumberFor<T>>) -> bool {
+            expiry.map_or(false, |expiry| {
+                expiry <= <frame_system::Pallet<T>>::block_number()
+            })
+        }
+
+        /// Revoke the privileges whose expiry block is not after `now`, within `remaining_weight`.
+        /// Expiry blocks are visited in order starting from `NextExpiryToSweep`.
+        pub(crate) fn sweep_expired_privileges(
+            now: BlockNumberFor<T>,
+            remaining_weight: Weight,
+        ) -> Weight {
+            sweep_expirations::<NextExpiryToSweep<T>, PrivilegeExpirations<T>, _, _, _>(
+                now.saturating_add(1u32.into()),
+                remaining_weight,
+                T::DbWeight::get(),
+                T::WeightInfo::expire_privilege(),
+                |block, (holder, privilege)| Self::expire_privilege(block, holder, privilege),
+            )
+        }
+
+        /// Revoke a privilege which expired at `block`
+        pub(crate) fn expire_privilege(
+            block: BlockNumberFor<T>,
+            holder: PrivilegeHolder<T::AccountId>,
+            privilege: Privilege,
+        ) {
+            PrivilegeExpirations::<T>::remove(block, (&holder, privilege));
+            match holder {
+                PrivilegeHolder::Account(who) => {
+                    PrivilegeExpiry::<T>::remove(&who, privilege);
+                    UserPrivileges::<T>::mutate(&who, |privs| {
+                        if let Some(privs) = privs {
+                            privs.0.remove(privilege);
+                        }
+                    });
+                    PrivilegeHolders::<T>::remove(privilege, &who);
+                    Self::deposit_event(Event::PrivilegeExpired(who, privilege));
+                }
+                PrivilegeHolder::Evm(who) => {
+                    EvmPrivilegeExpiry::<T>::remove(&who, privilege);
+                    EvmAddressPrivileges::<T>::mutate(&who, |privs| {
+                        if let Some(privs) = privs {
+                            privs.0.remove(privilege);
+                        }
+                    });
+                    EvmPrivilegeHolders::<T>::remove(privilege, &who);
+                    Self::deposit_event(Event::EvmPrivilegeExpired(who, privilege));
+                }
+            }
+        }
     }
 
     impl<T: Config> UserPrivilegeInterface<T::AccountId> for Pallet<T> {
@@ -267,7 +880,9 @@ pub mod pallet {
             let privs = Self::user_privileges(user);
             match privs {
                 None => false,
-                Some(privs) => privs.0.contains(p),
+                Some(privs) => {
+                    privs.0.contains(p) && !Self::is_expired(Self::privilege_expiry(user, p))
+                }
             }
         }
 
@@ -275,8 +890,49 @@ pub mod pallet {
             let privs = Self::evm_address_privileges(user);
             match privs {
                 None => false,
-                Some(privs) => privs.0.contains(p),
+                Some(privs) => {
+                    privs.0.contains(p) && !Self::is_expired(Self::evm_privilege_expiry(user, p))
+                }
             }
         }
+
+        fn has_approved_privilege(user: &T::AccountId, p: Privilege) -> bool {
+            Self::has_privilege(user, p)
+                && (Self::required_approvals(p) <= 1
+                    || ApprovedExecution::<T>::get().as_ref() == Some(&(user.clone(), p)))
+        }
+
+        fn consume_quota(
+            user: &T::AccountId,
+            p: Privilege,
+            resource: QuotaResource,
+            amount: u128,
+        ) -> DispatchResult {
+            let key = (p, resource);
+            let quota = match Self::privilege_quota(key) {
+                Some(quota) => quota,
+                None => return Ok(()),
+            };
+            let today = Self::current_day();
+            let used_today = |(day, used): (BlockNumberFor<T>, u128)| {
+                if day == today {
+                    used
+                } else {
+                    0
+                }
+            };
+            let account_used = used_today(QuotaUsage::<T>::get(key, user)).saturating_add(amount);
+            let global_used = used_today(GlobalQuotaUsage::<T>::get(key)).saturating_add(amount);
+            ensure!(
+                quota
+                    .per_account
+                    .map_or(true, |limit| account_used <= limit)
+                    && quota.global.map_or(true, |limit| global_used <= limit),
+                Error::<T>::QuotaExceeded
+            );
+            QuotaUsage::<T>::insert(key, user, (today, account_used));
+            GlobalQuotaUsage::<T>::insert(key, (today, global_used));
+            Ok(())
+        }
     }
 }
@@ -19,13 +19,17 @@
 #[cfg(test)]
 use sp_core::H256;
 use sp_runtime::{
-    traits::{BlakeTwo256, IdentityLookup},
+    traits::{BadOrigin, BlakeTwo256, IdentityLookup},
     BuildStorage, Perbill,
 };
 
-use frame_support::{assert_ok, parameter_types, traits::ConstU32, weights::Weight};
+use frame_support::{
+    assert_noop, assert_ok, parameter_types,
+    traits::{ConstU32, Contains, Hooks},
+    weights::Weight,
+};
 use frame_system::EnsureRoot;
-use node_primitives::user_privileges::{Privilege, UserPrivilegeInterface};
+use node_primitives::user_privileges::{Privilege, QuotaResource, UserPrivilegeInterface};
 
 use super::*;
 use crate::{self as pallet_user_privileges};
@@ -72,10 +76,32 @@ impl frame_system::Config for Test {
     type MaxConsumers = ConstU32<16>;
 }
 
+pub struct ProposableCalls;
+impl Contains<(Privilege, RuntimeCall)> for ProposableCalls {
+    fn contains((privilege, call): &(Privilege, RuntimeCall)) -> bool {
+        match privilege {
+            Privilege::EvmAddressSetter => matches!(
+                call,
+                RuntimeCall::UserPrivileges(Call::set_evm_privilege { .. })
+            ),
+            // benchmarks propose a remark
+            Privilege::NpowMint => {
+                matches!(call, RuntimeCall::System(frame_system::Call::remark { .. }))
+            }
+            _ => false,
+        }
+    }
+}
+
 impl pallet_user_privileges::Config for Test {
     type RuntimeEvent = RuntimeEvent;
     type ForceOrigin = EnsureRoot<Self::AccountId>;
     type WeightInfo = ();
+    type BlocksPerDay = frame_support::traits::ConstU64<14_400>;
+    type RuntimeCall = RuntimeCall;
+    type ProposalTimeout = frame_support::traits::ConstU64<100>;
+    type MaxApprovals = frame_support::traits::ConstU32<16>;
+    type ProposableCalls = ProposableCalls;
 }
 
 pub fn new_test_ext() -> sp_io::TestExternalities {
@@ -174,3 +200,581 @@ fn op_user_privilege() {
         );
     });
 }
+
+#[test]
+fn expiring_privilege() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        assert_noop!(
+            UserPrivileges::set_user_privilege_with_expiry(
+                RuntimeOrigin::root(),
+                1,
+                Privilege::OracleWorker,
+                1
+            ),
+            Error::<Test>::ExpiryInPast
+        );
+        assert_ok!(UserPrivileges::set_user_privilege_with_expiry(
+            RuntimeOrigin::root(),
+            1,
+            Privilege::OracleWorker,
+            5
+        ));
+        assert_ok!(UserPrivileges::set_user_privilege(
+            RuntimeOrigin::root(),
+            1,
+            Privilege::EvmAddressSetter
+        ));
+        assert_ok!(UserPrivileges::set_evm_privilege_with_expiry(
+            RuntimeOrigin::signed(1),
+            H160::from_low_u64_be(88),
+            Privilege::EvmCreditOperation,
+            5
+        ));
+        // a permanent grant replaces an expiring one
+        assert_ok!(UserPrivileges::set_user_privilege_with_expiry(
+            RuntimeOrigin::root(),
+            2,
+            Privilege::BridgeAdmin,
+            5
+        ));
+        assert_ok!(UserPrivileges::set_user_privilege(
+            RuntimeOrigin::root(),
+            2,
+            Privilege::BridgeAdmin
+        ));
+        assert_eq!(
+            UserPrivileges::privilege_expiry(2, Privilege::BridgeAdmin),
+            None
+        );
+        // unset drops the expiry
+        assert_ok!(UserPrivileges::set_user_privilege_with_expiry(
+            RuntimeOrigin::root(),
+            3,
+            Privilege::OracleWorker,
+            5
+        ));
+        assert_ok!(UserPrivileges::unset_user_privilege(
+            RuntimeOrigin::root(),
+            3,
+            Privilege::OracleWorker
+        ));
+        assert_eq!(
+            UserPrivileges::privilege_expiration(
+                5,
+                (PrivilegeHolder::Account(3), Privilege::OracleWorker)
+            ),
+            None
+        );
+
+        System::set_block_number(4);
+        assert!(UserPrivileges::has_privilege(&1, Privilege::OracleWorker));
+        assert!(UserPrivileges::has_evm_privilege(
+            &H160::from_low_u64_be(88),
+            Privilege::EvmCreditOperation
+        ));
+
+        // expired grants are absent before they are swept
+        System::set_block_number(5);
+        assert!(!UserPrivileges::has_privilege(&1, Privilege::OracleWorker));
+        assert!(!UserPrivileges::has_evm_privilege(
+            &H160::from_low_u64_be(88),
+            Privilege::EvmCreditOperation
+        ));
+        assert!(UserPrivileges::user_privileges(1)
+            .unwrap()
+            .0
+            .contains(Privilege::OracleWorker));
+
+        UserPrivileges::on_idle(5, Weight::MAX);
+        assert_eq!(UserPrivileges::next_expiry_to_sweep(), 6);
+        assert!(!UserPrivileges::user_privileges(1)
+            .unwrap()
+            .0
+            .contains(Privilege::OracleWorker));
+        assert!(UserPrivileges::has_privilege(
+            &1,
+            Privilege::EvmAddressSetter
+        ));
+        assert!(UserPrivileges::has_privilege(&2, Privilege::BridgeAdmin));
+        assert_eq!(
+            UserPrivileges::privilege_expiry(1, Privilege::OracleWorker),
+            None
+        );
+        assert_eq!(
+            UserPrivileges::evm_privilege_expiry(
+                H160::from_low_u64_be(88),
+                Privilege::EvmCreditOperation
+            ),
+            None
+        );
+        System::assert_has_event(RuntimeEvent::UserPrivileges(Event::PrivilegeExpired(
+            1,
+            Privilege::OracleWorker,
+        )));
+        System::assert_has_event(RuntimeEvent::UserPrivileges(Event::EvmPrivilegeExpired(
+            H160::from_low_u64_be(88),
+            Privilege::EvmCreditOperation,
+        )));
+    });
+}
+
+#[test]
+fn privilege_holders_index() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        assert_ok!(UserPrivileges::set_user_privilege(
+            RuntimeOrigin::root(),
+            1,
+            Privilege::NpowMint
+        ));
+        assert_ok!(UserPrivileges::set_user_privilege(
+            RuntimeOrigin::root(),
+            1,
+            Privilege::EvmAddressSetter
+        ));
+        assert_ok!(UserPrivileges::set_user_privilege(
+            RuntimeOrigin::root(),
+            2,
+            Privilege::NpowMint
+        ));
+        assert_ok!(UserPrivileges::set_user_privilege_with_expiry(
+            RuntimeOrigin::root(),
+            3,
+            Privilege::NpowMint,
+            5
+        ));
+        assert_ok!(UserPrivileges::set_evm_privilege(
+            RuntimeOrigin::signed(1),
+            H160::from_low_u64_be(88),
+            Privilege::NpowMint
+        ));
+
+        let mut holders = UserPrivileges::privilege_holders(Privilege::NpowMint);
+        holders.sort();
+        assert_eq!(holders, vec![1, 2, 3]);
+        assert_eq!(
+            UserPrivileges::evm_privilege_holders(Privilege::NpowMint),
+            vec![H160::from_low_u64_be(88)]
+        );
+        assert_eq!(
+            UserPrivileges::privileges_of(&1),
+            vec![Privilege::EvmAddressSetter, Privilege::NpowMint]
+        );
+
+        assert_ok!(UserPrivileges::unset_user_privilege(
+            RuntimeOrigin::root(),
+            2,
+            Privilege::NpowMint
+        ));
+        assert_ok!(UserPrivileges::clear_evm_privilege(
+            RuntimeOrigin::signed(1),
+            H160::from_low_u64_be(88)
+        ));
+        let mut holders = UserPrivileges::privilege_holders(Privilege::NpowMint);
+        holders.sort();
+        assert_eq!(holders, vec![1, 3]);
+        assert!(UserPrivileges::evm_privilege_holders(Privilege::NpowMint).is_empty());
+        assert!(UserPrivileges::evm_privileges_of(&H160::from_low_u64_be(88)).is_empty());
+
+        // expired grants are not listed, and are dropped from the index once swept
+        System::set_block_number(5);
+        assert_eq!(
+            UserPrivileges::privilege_holders(Privilege::NpowMint),
+            vec![1]
+        );
+        assert!(UserPrivileges::privileges_of(&3).is_empty());
+        UserPrivileges::on_idle(5, Weight::MAX);
+        assert!(!PrivilegeHolders::<Test>::contains_key(
+            Privilege::NpowMint,
+            3
+        ));
+
+        assert_ok!(UserPrivileges::clear_user_privilege(
+            RuntimeOrigin::root(),
+            1
+        ));
+        assert!(UserPrivileges::privilege_holders(Privilege::NpowMint).is_empty());
+        assert!(UserPrivileges::privilege_holders(Privilege::EvmAddressSetter).is_empty());
+    });
+}
+
+#[test]
+fn privilege_quota() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        // without a quota consumption is unlimited
+        assert_ok!(UserPrivileges::consume_quota(
+            &1,
+            Privilege::NpowMint,
+            QuotaResource::NpowMint,
+            u128::MAX
+        ));
+
+        let quota = PrivilegeQuota {
+            per_account: Some(100),
+            global: Some(150),
+        };
+        assert_noop!(
+            UserPrivileges::set_privilege_quota(
+                RuntimeOrigin::signed(1),
+                Privilege::NpowMint,
+                QuotaResource::NpowMint,
+                Some(quota)
+            ),
+            BadOrigin
+        );
+        assert_ok!(UserPrivileges::set_privilege_quota(
+            RuntimeOrigin::root(),
+            Privilege::NpowMint,
+            QuotaResource::NpowMint,
+            Some(quota)
+        ));
+
+        assert_ok!(UserPrivileges::consume_quota(
+            &1,
+            Privilege::NpowMint,
+            QuotaResource::NpowMint,
+            60
+        ));
+        assert_noop!(
+            UserPrivileges::consume_quota(&1, Privilege::NpowMint, QuotaResource::NpowMint, 41),
+            Error::<Test>::QuotaExceeded
+        );
+        assert_ok!(UserPrivileges::consume_quota(
+            &1,
+            Privilege::NpowMint,
+            QuotaResource::NpowMint,
+            40
+        ));
+        // the global quota is shared by all accounts
+        assert_noop!(
+            UserPrivileges::consume_quota(&2, Privilege::NpowMint, QuotaResource::NpowMint, 51),
+            Error::<Test>::QuotaExceeded
+        );
+        assert_ok!(UserPrivileges::consume_quota(
+            &2,
+            Privilege::NpowMint,
+            QuotaResource::NpowMint,
+            50
+        ));
+        // other privileges are not limited
+        assert_ok!(UserPrivileges::consume_quota(
+            &1,
+            Privilege::BridgeAdmin,
+            QuotaResource::BridgeTransfer,
+            1_000
+        ));
+        // resources consumed under the same privilege have separate quotas
+        assert_ok!(UserPrivileges::set_privilege_quota(
+            RuntimeOrigin::root(),
+            Privilege::CreditAdmin,
+            QuotaResource::AdscMint,
+            Some(quota)
+        ));
+        assert_ok!(UserPrivileges::set_privilege_quota(
+            RuntimeOrigin::root(),
+            Privilege::CreditAdmin,
+            QuotaResource::StakingCredit,
+            Some(PrivilegeQuota {
+                per_account: Some(2),
+                global: None,
+            })
+        ));
+        assert_ok!(UserPrivileges::consume_quota(
+            &1,
+            Privilege::CreditAdmin,
+            QuotaResource::AdscMint,
+            100
+        ));
+        assert_ok!(UserPrivileges::consume_quota(
+            &1,
+            Privilege::CreditAdmin,
+            QuotaResource::StakingCredit,
+            2
+        ));
+        assert_noop!(
+            UserPrivileges::consume_quota(
+                &1,
+                Privilege::CreditAdmin,
+                QuotaResource::StakingCredit,
+                1
+            ),
+            Error::<Test>::QuotaExceeded
+        );
+
+        // quotas replenish the next day
+        System::set_block_number(14_400);
+        assert_ok!(UserPrivileges::consume_quota(
+            &1,
+            Privilege::NpowMint,
+            QuotaResource::NpowMint,
+            100
+        ));
+        assert_eq!(
+            UserPrivileges::quota_usage((Privilege::NpowMint, QuotaResource::NpowMint), 1),
+            (1, 100)
+        );
+        assert_eq!(
+            UserPrivileges::global_quota_usage((Privilege::NpowMint, QuotaResource::NpowMint)),
+            (1, 100)
+        );
+
+        assert_ok!(UserPrivileges::set_privilege_quota(
+            RuntimeOrigin::root(),
+            Privilege::NpowMint,
+            QuotaResource::NpowMint,
+            None
+        ));
+        assert_ok!(UserPrivileges::consume_quota(
+            &1,
+            Privilege::NpowMint,
+            QuotaResource::NpowMint,
+            1_000
+        ));
+    });
+}
+
+#[test]
+fn proposal_requires_approvals() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        for holder in 1..=3 {
+            assert_ok!(UserPrivileges::set_user_privilege(
+                RuntimeOrigin::root(),
+                holder,
+                Privilege::EvmAddressSetter
+            ));
+        }
+        let call = Box::new(RuntimeCall::UserPrivileges(Call::set_evm_privilege {
+            who: H160::from_low_u64_be(88),
+            privilege: Privilege::LockerMember,
+        }));
+        assert_noop!(
+            UserPrivileges::propose(
+                RuntimeOrigin::signed(1),
+                Privilege::EvmAddressSetter,
+                call.clone()
+            ),
+            Error::<Test>::ApprovalNotRequired
+        );
+        assert!(UserPrivileges::has_approved_privilege(
+            &1,
+            Privilege::EvmAddressSetter
+        ));
+
+        assert_noop!(
+            UserPrivileges::set_required_approvals(
+                RuntimeOrigin::signed(1),
+                Privilege::EvmAddressSetter,
+                3
+            ),
+            BadOrigin
+        );
+        assert_noop!(
+            UserPrivileges::set_required_approvals(
+                RuntimeOrigin::root(),
+                Privilege::EvmAddressSetter,
+                17
+            ),
+            Error::<Test>::TooManyApprovals
+        );
+        assert_ok!(UserPrivileges::set_required_approvals(
+            RuntimeOrigin::root(),
+            Privilege::EvmAddressSetter,
+            3
+        ));
+        assert!(!UserPrivileges::has_approved_privilege(
+            &1,
+            Privilege::EvmAddressSetter
+        ));
+
+        assert_noop!(
+            UserPrivileges::propose(
+                RuntimeOrigin::signed(4),
+                Privilege::EvmAddressSetter,
+                call.clone()
+            ),
+            Error::<Test>::NoPermission
+        );
+        assert_ok!(UserPrivileges::propose(
+            RuntimeOrigin::signed(1),
+            Privilege::EvmAddressSetter,
+            call.clone()
+        ));
+        assert_noop!(
+            UserPrivileges::approve(RuntimeOrigin::signed(1), 0, Weight::MAX),
+            Error::<Test>::AlreadyApproved
+        );
+        assert_noop!(
+            UserPrivileges::approve(RuntimeOrigin::signed(4), 0, Weight::MAX),
+            Error::<Test>::NoPermission
+        );
+        assert_ok!(UserPrivileges::approve(
+            RuntimeOrigin::signed(2),
+            0,
+            Weight::MAX
+        ));
+        assert_eq!(
+            UserPrivileges::proposals(0).map(|proposal| proposal.approvals),
+            Some(vec![1, 2])
+        );
+        assert!(!UserPrivileges::has_evm_privilege(
+            &H160::from_low_u64_be(88),
+            Privilege::LockerMember
+        ));
+
+        assert_noop!(
+            UserPrivileges::approve(RuntimeOrigin::signed(3), 0, Weight::zero()),
+            Error::<Test>::MaxWeightTooLow
+        );
+        assert_ok!(UserPrivileges::approve(
+            RuntimeOrigin::signed(3),
+            0,
+            Weight::MAX
+        ));
+        assert!(UserPrivileges::proposals(0).is_none());
+        assert!(UserPrivileges::has_evm_privilege(
+            &H160::from_low_u64_be(88),
+            Privilege::LockerMember
+        ));
+        System::assert_last_event(RuntimeEvent::UserPrivileges(Event::ProposalExecuted(
+            0,
+            Ok(()),
+        )));
+
+        // proposals time out
+        assert_ok!(UserPrivileges::propose(
+            RuntimeOrigin::signed(1),
+            Privilege::EvmAddressSetter,
+            call
+        ));
+        assert_noop!(
+            UserPrivileges::remove_expired_proposal(RuntimeOrigin::signed(4), 1),
+            Error::<Test>::ProposalNotExpired
+        );
+        System::set_block_number(101);
+        assert_noop!(
+            UserPrivileges::approve(RuntimeOrigin::signed(2), 1, Weight::MAX),
+            Error::<Test>::ProposalExpired
+        );
+        assert_ok!(UserPrivileges::remove_expired_proposal(
+            RuntimeOrigin::signed(4),
+            1
+        ));
+        assert!(UserPrivileges::proposals(1).is_none());
+    });
+}
+
+#[test]
+fn revoked_approvals_are_not_counted() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        for holder in 1..=4 {
+            assert_ok!(UserPrivileges::set_user_privilege(
+                RuntimeOrigin::root(),
+                holder,
+                Privilege::EvmAddressSetter
+            ));
+        }
+        assert_ok!(UserPrivileges::set_required_approvals(
+            RuntimeOrigin::root(),
+            Privilege::EvmAddressSetter,
+            3
+        ));
+        let call = Box::new(RuntimeCall::UserPrivileges(Call::set_evm_privilege {
+            who: H160::from_low_u64_be(88),
+            privilege: Privilege::LockerMember,
+        }));
+        assert_ok!(UserPrivileges::propose(
+            RuntimeOrigin::signed(1),
+            Privilege::EvmAddressSetter,
+            call
+        ));
+        assert_ok!(UserPrivileges::approve(
+            RuntimeOrigin::signed(2),
+            0,
+            Weight::MAX
+        ));
+        assert_ok!(UserPrivileges::unset_user_privilege(
+            RuntimeOrigin::root(),
+            2,
+            Privilege::EvmAddressSetter
+        ));
+
+        // the approval of the revoked holder no longer counts
+        assert_ok!(UserPrivileges::approve(
+            RuntimeOrigin::signed(3),
+            0,
+            Weight::MAX
+        ));
+        assert_eq!(
+            UserPrivileges::proposals(0).map(|proposal| proposal.approvals),
+            Some(vec![1, 3])
+        );
+        assert!(!UserPrivileges::has_evm_privilege(
+            &H160::from_low_u64_be(88),
+            Privilege::LockerMember
+        ));
+
+        assert_ok!(UserPrivileges::approve(
+            RuntimeOrigin::signed(4),
+            0,
+            Weight::MAX
+        ));
+        assert!(UserPrivileges::proposals(0).is_none());
+        assert!(UserPrivileges::has_evm_privilege(
+            &H160::from_low_u64_be(88),
+            Privilege::LockerMember
+        ));
+    });
+}
+
+#[test]
+fn only_gated_calls_can_be_proposed() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        for holder in 1..=2 {
+            assert_ok!(UserPrivileges::set_user_privilege(
+                RuntimeOrigin::root(),
+                holder,
+                Privilege::EvmAddressSetter
+            ));
+        }
+        assert_ok!(UserPrivileges::set_required_approvals(
+            RuntimeOrigin::root(),
+            Privilege::EvmAddressSetter,
+            2
+        ));
+        assert_noop!(
+            UserPrivileges::propose(
+                RuntimeOrigin::signed(1),
+                Privilege::EvmAddressSetter,
+                Box::new(RuntimeCall::System(frame_system::Call::remark {
+                    remark: vec![]
+                }))
+            ),
+            Error::<Test>::CallNotProposable
+        );
+        // a proposal cannot wrap the approval of another one
+        assert_noop!(
+            UserPrivileges::propose(
+                RuntimeOrigin::signed(1),
+                Privilege::EvmAddressSetter,
+                Box::new(RuntimeCall::UserPrivileges(Call::approve {
+                    id: 0,
+                    max_weight: Weight::MAX
+                }))
+            ),
+            Error::<Test>::CallNotProposable
+        );
+        assert_ok!(UserPrivileges::propose(
+            RuntimeOrigin::signed(1),
+            Privilege::EvmAddressSetter,
+            Box::new(RuntimeCall::UserPrivileges(Call::set_evm_privilege {
+                who: H160::from_low_u64_be(88),
+                privilege: Privilege::LockerMember,
+            }))
+        ));
+    });
+}
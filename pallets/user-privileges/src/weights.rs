@@ -38,6 +38,14 @@ pub trait WeightInfo {
 	fn clear_user_privilege() -> Weight;
 	fn set_evm_privilege() -> Weight;
 	fn clear_evm_privilege() -> Weight;
+	fn set_user_privilege_with_expiry() -> Weight;
+	fn set_evm_privilege_with_expiry() -> Weight;
+	fn expire_privilege() -> Weight;
+	fn set_privilege_quota() -> Weight;
+	fn set_required_approvals() -> Weight;
+	fn propose() -> Weight;
+	fn approve(a: u32, ) -> Weight;
+	fn remove_expired_proposal() -> Weight;
 }
 
 /// Weights for `pallet_user_privileges` using the Substrate node and recommended hardware.
@@ -45,48 +53,202 @@ pub struct SubstrateWeight<T>(PhantomData<T>);
 impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	/// Storage: `UserPrivileges::UserPrivileges` (r:1 w:1)
 	/// Proof: `UserPrivileges::UserPrivileges` (`max_values`: None, `max_size`: Some(48), added: 2523, mode: `MaxEncodedLen`)
+	/// Storage: `UserPrivileges::PrivilegeExpiry` (r:1 w:1)
+	/// Proof: `UserPrivileges::PrivilegeExpiry` (`max_values`: None, `max_size`: Some(49), added: 2524, mode: `MaxEncodedLen`)
+	/// Storage: `UserPrivileges::PrivilegeHolders` (r:0 w:1)
+	/// Proof: `UserPrivileges::PrivilegeHolders` (`max_values`: None, `max_size`: Some(57), added: 2532, mode: `MaxEncodedLen`)
 	fn set_user_privilege() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `76`
-		//  Estimated: `3513`
+		//  Estimated: `3514`
 		// Minimum execution time: 247_653_000 picoseconds.
-		Weight::from_parts(251_480_000, 3513)
-			.saturating_add(T::DbWeight::get().reads(1_u64))
-			.saturating_add(T::DbWeight::get().writes(1_u64))
+		Weight::from_parts(251_480_000, 3514)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
 	}
-	/// Storage: `UserPrivileges::UserPrivileges` (r:0 w:1)
+	/// Storage: `UserPrivileges::UserPrivileges` (r:1 w:1)
 	/// Proof: `UserPrivileges::UserPrivileges` (`max_values`: None, `max_size`: Some(48), added: 2523, mode: `MaxEncodedLen`)
+	/// Storage: `UserPrivileges::PrivilegeExpiry` (r:14 w:13)
+	/// Proof: `UserPrivileges::PrivilegeExpiry` (`max_values`: None, `max_size`: Some(49), added: 2524, mode: `MaxEncodedLen`)
+	/// Storage: `UserPrivileges::PrivilegeExpirations` (r:0 w:13)
+	/// Proof: `UserPrivileges::PrivilegeExpirations` (`max_values`: None, `max_size`: Some(70), added: 2545, mode: `MaxEncodedLen`)
+	/// Storage: `UserPrivileges::PrivilegeHolders` (r:0 w:13)
+	/// Proof: `UserPrivileges::PrivilegeHolders` (`max_values`: None, `max_size`: Some(57), added: 2532, mode: `MaxEncodedLen`)
 	fn clear_user_privilege() -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `0`
-		//  Estimated: `0`
-		// Minimum execution time: 177_706_000 picoseconds.
-		Weight::from_parts(181_283_000, 0)
-			.saturating_add(T::DbWeight::get().writes(1_u64))
+		//  Measured:  `1024`
+		//  Estimated: `35816`
+		// Minimum execution time: 1_304_117_000 picoseconds.
+		Weight::from_parts(1_321_905_000, 35816)
+			.saturating_add(T::DbWeight::get().reads(15_u64))
+			.saturating_add(T::DbWeight::get().writes(40_u64))
 	}
 	/// Storage: `UserPrivileges::UserPrivileges` (r:1 w:0)
 	/// Proof: `UserPrivileges::UserPrivileges` (`max_values`: None, `max_size`: Some(48), added: 2523, mode: `MaxEncodedLen`)
+	/// Storage: `UserPrivileges::PrivilegeExpiry` (r:1 w:0)
+	/// Proof: `UserPrivileges::PrivilegeExpiry` (`max_values`: None, `max_size`: Some(49), added: 2524, mode: `MaxEncodedLen`)
 	/// Storage: `UserPrivileges::EvmAddressPrivileges` (r:1 w:1)
 	/// Proof: `UserPrivileges::EvmAddressPrivileges` (`max_values`: None, `max_size`: Some(36), added: 2511, mode: `MaxEncodedLen`)
+	/// Storage: `UserPrivileges::EvmPrivilegeExpiry` (r:1 w:1)
+	/// Proof: `UserPrivileges::EvmPrivilegeExpiry` (`max_values`: None, `max_size`: Some(37), added: 2512, mode: `MaxEncodedLen`)
+	/// Storage: `UserPrivileges::EvmPrivilegeHolders` (r:0 w:1)
+	/// Proof: `UserPrivileges::EvmPrivilegeHolders` (`max_values`: None, `max_size`: Some(45), added: 2520, mode: `MaxEncodedLen`)
 	fn set_evm_privilege() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `150`
-		//  Estimated: `3513`
+		//  Estimated: `3514`
 		// Minimum execution time: 312_623_000 picoseconds.
-		Weight::from_parts(315_408_000, 3513)
-			.saturating_add(T::DbWeight::get().reads(2_u64))
-			.saturating_add(T::DbWeight::get().writes(1_u64))
+		Weight::from_parts(315_408_000, 3514)
+			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
 	}
 	/// Storage: `UserPrivileges::UserPrivileges` (r:1 w:0)
 	/// Proof: `UserPrivileges::UserPrivileges` (`max_values`: None, `max_size`: Some(48), added: 2523, mode: `MaxEncodedLen`)
-	/// Storage: `UserPrivileges::EvmAddressPrivileges` (r:0 w:1)
+	/// Storage: `UserPrivileges::PrivilegeExpiry` (r:1 w:0)
+	/// Proof: `UserPrivileges::PrivilegeExpiry` (`max_values`: None, `max_size`: Some(49), added: 2524, mode: `MaxEncodedLen`)
+	/// Storage: `UserPrivileges::EvmAddressPrivileges` (r:1 w:1)
 	/// Proof: `UserPrivileges::EvmAddressPrivileges` (`max_values`: None, `max_size`: Some(36), added: 2511, mode: `MaxEncodedLen`)
+	/// Storage: `UserPrivileges::EvmPrivilegeExpiry` (r:14 w:13)
+	/// Proof: `UserPrivileges::EvmPrivilegeExpiry` (`max_values`: None, `max_size`: Some(37), added: 2512, mode: `MaxEncodedLen`)
+	/// Storage: `UserPrivileges::PrivilegeExpirations` (r:0 w:13)
+	/// Proof: `UserPrivileges::PrivilegeExpirations` (`max_values`: None, `max_size`: Some(70), added: 2545, mode: `MaxEncodedLen`)
+	/// Storage: `UserPrivileges::EvmPrivilegeHolders` (r:0 w:13)
+	/// Proof: `UserPrivileges::EvmPrivilegeHolders` (`max_values`: None, `max_size`: Some(45), added: 2520, mode: `MaxEncodedLen`)
 	fn clear_evm_privilege() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1098`
+		//  Estimated: `35816`
+		// Minimum execution time: 1_392_540_000 picoseconds.
+		Weight::from_parts(1_410_228_000, 35816)
+			.saturating_add(T::DbWeight::get().reads(17_u64))
+			.saturating_add(T::DbWeight::get().writes(40_u64))
+	}
+	/// Storage: `UserPrivileges::UserPrivileges` (r:1 w:1)
+	/// Proof: `UserPrivileges::UserPrivileges` (`max_values`: None, `max_size`: Some(48), added: 2523, mode: `MaxEncodedLen`)
+	/// Storage: `UserPrivileges::PrivilegeExpiry` (r:1 w:1)
+	/// Proof: `UserPrivileges::PrivilegeExpiry` (`max_values`: None, `max_size`: Some(49), added: 2524, mode: `MaxEncodedLen`)
+	/// Storage: `UserPrivileges::PrivilegeExpirations` (r:0 w:2)
+	/// Proof: `UserPrivileges::PrivilegeExpirations` (`max_values`: None, `max_size`: Some(70), added: 2545, mode: `MaxEncodedLen`)
+	/// Storage: `UserPrivileges::PrivilegeHolders` (r:0 w:1)
+	/// Proof: `UserPrivileges::PrivilegeHolders` (`max_values`: None, `max_size`: Some(57), added: 2532, mode: `MaxEncodedLen`)
+	fn set_user_privilege_with_expiry() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `76`
+		//  Estimated: `3514`
+		// Minimum execution time: 268_310_000 picoseconds.
+		Weight::from_parts(272_904_000, 3514)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(5_u64))
+	}
+	/// Storage: `UserPrivileges::UserPrivileges` (r:1 w:0)
+	/// Proof: `UserPrivileges::UserPrivileges` (`max_values`: None, `max_size`: Some(48), added: 2523, mode: `MaxEncodedLen`)
+	/// Storage: `UserPrivileges::PrivilegeExpiry` (r:1 w:0)
+	/// Proof: `UserPrivileges::PrivilegeExpiry` (`max_values`: None, `max_size`: Some(49), added: 2524, mode: `MaxEncodedLen`)
+	/// Storage: `UserPrivileges::EvmAddressPrivileges` (r:1 w:1)
+	/// Proof: `UserPrivileges::EvmAddressPrivileges` (`max_values`: None, `max_size`: Some(36), added: 2511, mode: `MaxEncodedLen`)
+	/// Storage: `UserPrivileges::EvmPrivilegeExpiry` (r:1 w:1)
+	/// Proof: `UserPrivileges::EvmPrivilegeExpiry` (`max_values`: None, `max_size`: Some(37), added: 2512, mode: `MaxEncodedLen`)
+	/// Storage: `UserPrivileges::PrivilegeExpirations` (r:0 w:2)
+	/// Proof: `UserPrivileges::PrivilegeExpirations` (`max_values`: None, `max_size`: Some(70), added: 2545, mode: `MaxEncodedLen`)
+	/// Storage: `UserPrivileges::EvmPrivilegeHolders` (r:0 w:1)
+	/// Proof: `UserPrivileges::EvmPrivilegeHolders` (`max_values`: None, `max_size`: Some(45), added: 2520, mode: `MaxEncodedLen`)
+	fn set_evm_privilege_with_expiry() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `150`
+		//  Estimated: `3514`
+		// Minimum execution time: 335_127_000 picoseconds.
+		Weight::from_parts(339_560_000, 3514)
+			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(T::DbWeight::get().writes(5_u64))
+	}
+	/// Storage: `UserPrivileges::PrivilegeExpirations` (r:0 w:1)
+	/// Proof: `UserPrivileges::PrivilegeExpirations` (`max_values`: None, `max_size`: Some(70), added: 2545, mode: `MaxEncodedLen`)
+	/// Storage: `UserPrivileges::PrivilegeExpiry` (r:0 w:1)
+	/// Proof: `UserPrivileges::PrivilegeExpiry` (`max_values`: None, `max_size`: Some(49), added: 2524, mode: `MaxEncodedLen`)
+	/// Storage: `UserPrivileges::UserPrivileges` (r:1 w:1)
+	/// Proof: `UserPrivileges::UserPrivileges` (`max_values`: None, `max_size`: Some(48), added: 2523, mode: `MaxEncodedLen`)
+	/// Storage: `UserPrivileges::PrivilegeHolders` (r:0 w:1)
+	/// Proof: `UserPrivileges::PrivilegeHolders` (`max_values`: None, `max_size`: Some(57), added: 2532, mode: `MaxEncodedLen`)
+	fn expire_privilege() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `112`
 		//  Estimated: `3513`
-		// Minimum execution time: 276_407_000 picoseconds.
-		Weight::from_parts(278_620_000, 3513)
+		// Minimum execution time: 203_446_000 picoseconds.
+		Weight::from_parts(206_871_000, 3513)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(4_u64))
+	}
+	/// Storage: `UserPrivileges::PrivilegeQuotas` (r:0 w:1)
+	/// Proof: `UserPrivileges::PrivilegeQuotas` (`max_values`: None, `max_size`: Some(44), added: 2519, mode: `MaxEncodedLen`)
+	fn set_privilege_quota() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `0`
+		// Minimum execution time: 152_318_000 picoseconds.
+		Weight::from_parts(155_034_000, 0)
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `UserPrivileges::RequiredApprovals` (r:0 w:1)
+	/// Proof: `UserPrivileges::RequiredApprovals` (`max_values`: None, `max_size`: Some(13), added: 2488, mode: `MaxEncodedLen`)
+	fn set_required_approvals() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `0`
+		// Minimum execution time: 148_905_000 picoseconds.
+		Weight::from_parts(151_627_000, 0)
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `UserPrivileges::UserPrivileges` (r:1 w:0)
+	/// Proof: `UserPrivileges::UserPrivileges` (`max_values`: None, `max_size`: Some(48), added: 2523, mode: `MaxEncodedLen`)
+	/// Storage: `UserPrivileges::PrivilegeExpiry` (r:1 w:0)
+	/// Proof: `UserPrivileges::PrivilegeExpiry` (`max_values`: None, `max_size`: Some(49), added: 2524, mode: `MaxEncodedLen`)
+	/// Storage: `UserPrivileges::RequiredApprovals` (r:1 w:0)
+	/// Proof: `UserPrivileges::RequiredApprovals` (`max_values`: None, `max_size`: Some(13), added: 2488, mode: `MaxEncodedLen`)
+	/// Storage: `UserPrivileges::NextProposalId` (r:1 w:1)
+	/// Proof: `UserPrivileges::NextProposalId` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `UserPrivileges::Proposals` (r:0 w:1)
+	/// Proof: `UserPrivileges::Proposals` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	fn propose() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `142`
+		//  Estimated: `3514`
+		// Minimum execution time: 356_412_000 picoseconds.
+		Weight::from_parts(362_870_000, 3514)
+			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+	/// Storage: `UserPrivileges::Proposals` (r:1 w:1)
+	/// Proof: `UserPrivileges::Proposals` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `UserPrivileges::UserPrivileges` (r:17 w:0)
+	/// Proof: `UserPrivileges::UserPrivileges` (`max_values`: None, `max_size`: Some(48), added: 2523, mode: `MaxEncodedLen`)
+	/// Storage: `UserPrivileges::PrivilegeExpiry` (r:17 w:0)
+	/// Proof: `UserPrivileges::PrivilegeExpiry` (`max_values`: None, `max_size`: Some(49), added: 2524, mode: `MaxEncodedLen`)
+	/// Storage: `UserPrivileges::RequiredApprovals` (r:1 w:0)
+	/// Proof: `UserPrivileges::RequiredApprovals` (`max_values`: None, `max_size`: Some(13), added: 2488, mode: `MaxEncodedLen`)
+	/// Storage: `UserPrivileges::ApprovedExecution` (r:1 w:2)
+	/// Proof: `UserPrivileges::ApprovedExecution` (`max_values`: Some(1), `max_size`: Some(33), added: 528, mode: `MaxEncodedLen`)
+	/// The range of component `a` is `[2, 16]`.
+	fn approve(a: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `310 + a * (32 ±0)`
+		//  Estimated: `3775 + a * (5048 ±0)`
+		// Minimum execution time: 421_086_000 picoseconds.
+		Weight::from_parts(428_519_000, 3775)
+			// Standard Error: 41_306
+			.saturating_add(Weight::from_parts(33_918_240, 0).saturating_mul(a.into()))
+			.saturating_add(T::DbWeight::get().reads(5_u64))
+			.saturating_add(T::DbWeight::get().reads((2_u64).saturating_mul(a.into())))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+			.saturating_add(Weight::from_parts(0, 5048).saturating_mul(a.into()))
+	}
+	/// Storage: `UserPrivileges::Proposals` (r:1 w:1)
+	/// Proof: `UserPrivileges::Proposals` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	fn remove_expired_proposal() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `201`
+		//  Estimated: `3666`
+		// Minimum execution time: 203_744_000 picoseconds.
+		Weight::from_parts(207_315_000, 3666)
 			.saturating_add(T::DbWeight::get().reads(1_u64))
 			.saturating_add(T::DbWeight::get().writes(1_u64))
 	}
@@ -96,48 +258,202 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 impl WeightInfo for () {
 	/// Storage: `UserPrivileges::UserPrivileges` (r:1 w:1)
 	/// Proof: `UserPrivileges::UserPrivileges` (`max_values`: None, `max_size`: Some(48), added: 2523, mode: `MaxEncodedLen`)
+	/// Storage: `UserPrivileges::PrivilegeExpiry` (r:1 w:1)
+	/// Proof: `UserPrivileges::PrivilegeExpiry` (`max_values`: None, `max_size`: Some(49), added: 2524, mode: `MaxEncodedLen`)
+	/// Storage: `UserPrivileges::PrivilegeHolders` (r:0 w:1)
+	/// Proof: `UserPrivileges::PrivilegeHolders` (`max_values`: None, `max_size`: Some(57), added: 2532, mode: `MaxEncodedLen`)
 	fn set_user_privilege() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `76`
-		//  Estimated: `3513`
+		//  Estimated: `3514`
 		// Minimum execution time: 247_653_000 picoseconds.
-		Weight::from_parts(251_480_000, 3513)
-			.saturating_add(RocksDbWeight::get().reads(1_u64))
-			.saturating_add(RocksDbWeight::get().writes(1_u64))
+		Weight::from_parts(251_480_000, 3514)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
 	}
-	/// Storage: `UserPrivileges::UserPrivileges` (r:0 w:1)
+	/// Storage: `UserPrivileges::UserPrivileges` (r:1 w:1)
 	/// Proof: `UserPrivileges::UserPrivileges` (`max_values`: None, `max_size`: Some(48), added: 2523, mode: `MaxEncodedLen`)
+	/// Storage: `UserPrivileges::PrivilegeExpiry` (r:14 w:13)
+	/// Proof: `UserPrivileges::PrivilegeExpiry` (`max_values`: None, `max_size`: Some(49), added: 2524, mode: `MaxEncodedLen`)
+	/// Storage: `UserPrivileges::PrivilegeExpirations` (r:0 w:13)
+	/// Proof: `UserPrivileges::PrivilegeExpirations` (`max_values`: None, `max_size`: Some(70), added: 2545, mode: `MaxEncodedLen`)
+	/// Storage: `UserPrivileges::PrivilegeHolders` (r:0 w:13)
+	/// Proof: `UserPrivileges::PrivilegeHolders` (`max_values`: None, `max_size`: Some(57), added: 2532, mode: `MaxEncodedLen`)
 	fn clear_user_privilege() -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `0`
-		//  Estimated: `0`
-		// Minimum execution time: 177_706_000 picoseconds.
-		Weight::from_parts(181_283_000, 0)
-			.saturating_add(RocksDbWeight::get().writes(1_u64))
+		//  Measured:  `1024`
+		//  Estimated: `35816`
+		// Minimum execution time: 1_304_117_000 picoseconds.
+		Weight::from_parts(1_321_905_000, 35816)
+			.saturating_add(RocksDbWeight::get().reads(15_u64))
+			.saturating_add(RocksDbWeight::get().writes(40_u64))
 	}
 	/// Storage: `UserPrivileges::UserPrivileges` (r:1 w:0)
 	/// Proof: `UserPrivileges::UserPrivileges` (`max_values`: None, `max_size`: Some(48), added: 2523, mode: `MaxEncodedLen`)
+	/// Storage: `UserPrivileges::PrivilegeExpiry` (r:1 w:0)
+	/// Proof: `UserPrivileges::PrivilegeExpiry` (`max_values`: None, `max_size`: Some(49), added: 2524, mode: `MaxEncodedLen`)
 	/// Storage: `UserPrivileges::EvmAddressPrivileges` (r:1 w:1)
 	/// Proof: `UserPrivileges::EvmAddressPrivileges` (`max_values`: None, `max_size`: Some(36), added: 2511, mode: `MaxEncodedLen`)
+	/// Storage: `UserPrivileges::EvmPrivilegeExpiry` (r:1 w:1)
+	/// Proof: `UserPrivileges::EvmPrivilegeExpiry` (`max_values`: None, `max_size`: Some(37), added: 2512, mode: `MaxEncodedLen`)
+	/// Storage: `UserPrivileges::EvmPrivilegeHolders` (r:0 w:1)
+	/// Proof: `UserPrivileges::EvmPrivilegeHolders` (`max_values`: None, `max_size`: Some(45), added: 2520, mode: `MaxEncodedLen`)
 	fn set_evm_privilege() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `150`
-		//  Estimated: `3513`
+		//  Estimated: `3514`
 		// Minimum execution time: 312_623_000 picoseconds.
-		Weight::from_parts(315_408_000, 3513)
-			.saturating_add(RocksDbWeight::get().reads(2_u64))
-			.saturating_add(RocksDbWeight::get().writes(1_u64))
+		Weight::from_parts(315_408_000, 3514)
+			.saturating_add(RocksDbWeight::get().reads(4_u64))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
 	}
 	/// Storage: `UserPrivileges::UserPrivileges` (r:1 w:0)
 	/// Proof: `UserPrivileges::UserPrivileges` (`max_values`: None, `max_size`: Some(48), added: 2523, mode: `MaxEncodedLen`)
-	/// Storage: `UserPrivileges::EvmAddressPrivileges` (r:0 w:1)
+	/// Storage: `UserPrivileges::PrivilegeExpiry` (r:1 w:0)
+	/// Proof: `UserPrivileges::PrivilegeExpiry` (`max_values`: None, `max_size`: Some(49), added: 2524, mode: `MaxEncodedLen`)
+	/// Storage: `UserPrivileges::EvmAddressPrivileges` (r:1 w:1)
 	/// Proof: `UserPrivileges::EvmAddressPrivileges` (`max_values`: None, `max_size`: Some(36), added: 2511, mode: `MaxEncodedLen`)
+	/// Storage: `UserPrivileges::EvmPrivilegeExpiry` (r:14 w:13)
+	/// Proof: `UserPrivileges::EvmPrivilegeExpiry` (`max_values`: None, `max_size`: Some(37), added: 2512, mode: `MaxEncodedLen`)
+	/// Storage: `UserPrivileges::PrivilegeExpirations` (r:0 w:13)
+	/// Proof: `UserPrivileges::PrivilegeExpirations` (`max_values`: None, `max_size`: Some(70), added: 2545, mode: `MaxEncodedLen`)
+	/// Storage: `UserPrivileges::EvmPrivilegeHolders` (r:0 w:13)
+	/// Proof: `UserPrivileges::EvmPrivilegeHolders` (`max_values`: None, `max_size`: Some(45), added: 2520, mode: `MaxEncodedLen`)
 	fn clear_evm_privilege() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1098`
+		//  Estimated: `35816`
+		// Minimum execution time: 1_392_540_000 picoseconds.
+		Weight::from_parts(1_410_228_000, 35816)
+			.saturating_add(RocksDbWeight::get().reads(17_u64))
+			.saturating_add(RocksDbWeight::get().writes(40_u64))
+	}
+	/// Storage: `UserPrivileges::UserPrivileges` (r:1 w:1)
+	/// Proof: `UserPrivileges::UserPrivileges` (`max_values`: None, `max_size`: Some(48), added: 2523, mode: `MaxEncodedLen`)
+	/// Storage: `UserPrivileges::PrivilegeExpiry` (r:1 w:1)
+	/// Proof: `UserPrivileges::PrivilegeExpiry` (`max_values`: None, `max_size`: Some(49), added: 2524, mode: `MaxEncodedLen`)
+	/// Storage: `UserPrivileges::PrivilegeExpirations` (r:0 w:2)
+	/// Proof: `UserPrivileges::PrivilegeExpirations` (`max_values`: None, `max_size`: Some(70), added: 2545, mode: `MaxEncodedLen`)
+	/// Storage: `UserPrivileges::PrivilegeHolders` (r:0 w:1)
+	/// Proof: `UserPrivileges::PrivilegeHolders` (`max_values`: None, `max_size`: Some(57), added: 2532, mode: `MaxEncodedLen`)
+	fn set_user_privilege_with_expiry() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `76`
+		//  Estimated: `3514`
+		// Minimum execution time: 268_310_000 picoseconds.
+		Weight::from_parts(272_904_000, 3514)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(5_u64))
+	}
+	/// Storage: `UserPrivileges::UserPrivileges` (r:1 w:0)
+	/// Proof: `UserPrivileges::UserPrivileges` (`max_values`: None, `max_size`: Some(48), added: 2523, mode: `MaxEncodedLen`)
+	/// Storage: `UserPrivileges::PrivilegeExpiry` (r:1 w:0)
+	/// Proof: `UserPrivileges::PrivilegeExpiry` (`max_values`: None, `max_size`: Some(49), added: 2524, mode: `MaxEncodedLen`)
+	/// Storage: `UserPrivileges::EvmAddressPrivileges` (r:1 w:1)
+	/// Proof: `UserPrivileges::EvmAddressPrivileges` (`max_values`: None, `max_size`: Some(36), added: 2511, mode: `MaxEncodedLen`)
+	/// Storage: `UserPrivileges::EvmPrivilegeExpiry` (r:1 w:1)
+	/// Proof: `UserPrivileges::EvmPrivilegeExpiry` (`max_values`: None, `max_size`: Some(37), added: 2512, mode: `MaxEncodedLen`)
+	/// Storage: `UserPrivileges::PrivilegeExpirations` (r:0 w:2)
+	/// Proof: `UserPrivileges::PrivilegeExpirations` (`max_values`: None, `max_size`: Some(70), added: 2545, mode: `MaxEncodedLen`)
+	/// Storage: `UserPrivileges::EvmPrivilegeHolders` (r:0 w:1)
+	/// Proof: `UserPrivileges::EvmPrivilegeHolders` (`max_values`: None, `max_size`: Some(45), added: 2520, mode: `MaxEncodedLen`)
+	fn set_evm_privilege_with_expiry() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `150`
+		//  Estimated: `3514`
+		// Minimum execution time: 335_127_000 picoseconds.
+		Weight::from_parts(339_560_000, 3514)
+			.saturating_add(RocksDbWeight::get().reads(4_u64))
+			.saturating_add(RocksDbWeight::get().writes(5_u64))
+	}
+	/// Storage: `UserPrivileges::PrivilegeExpirations` (r:0 w:1)
+	/// Proof: `UserPrivileges::PrivilegeExpirations` (`max_values`: None, `max_size`: Some(70), added: 2545, mode: `MaxEncodedLen`)
+	/// Storage: `UserPrivileges::PrivilegeExpiry` (r:0 w:1)
+	/// Proof: `UserPrivileges::PrivilegeExpiry` (`max_values`: None, `max_size`: Some(49), added: 2524, mode: `MaxEncodedLen`)
+	/// Storage: `UserPrivileges::UserPrivileges` (r:1 w:1)
+	/// Proof: `UserPrivileges::UserPrivileges` (`max_values`: None, `max_size`: Some(48), added: 2523, mode: `MaxEncodedLen`)
+	/// Storage: `UserPrivileges::PrivilegeHolders` (r:0 w:1)
+	/// Proof: `UserPrivileges::PrivilegeHolders` (`max_values`: None, `max_size`: Some(57), added: 2532, mode: `MaxEncodedLen`)
+	fn expire_privilege() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `112`
 		//  Estimated: `3513`
-		// Minimum execution time: 276_407_000 picoseconds.
-		Weight::from_parts(278_620_000, 3513)
+		// Minimum execution time: 203_446_000 picoseconds.
+		Weight::from_parts(206_871_000, 3513)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(4_u64))
+	}
+	/// Storage: `UserPrivileges::PrivilegeQuotas` (r:0 w:1)
+	/// Proof: `UserPrivileges::PrivilegeQuotas` (`max_values`: None, `max_size`: Some(44), added: 2519, mode: `MaxEncodedLen`)
+	fn set_privilege_quota() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `0`
+		// Minimum execution time: 152_318_000 picoseconds.
+		Weight::from_parts(155_034_000, 0)
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `UserPrivileges::RequiredApprovals` (r:0 w:1)
+	/// Proof: `UserPrivileges::RequiredApprovals` (`max_values`: None, `max_size`: Some(13), added: 2488, mode: `MaxEncodedLen`)
+	fn set_required_approvals() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `0`
+		// Minimum execution time: 148_905_000 picoseconds.
+		Weight::from_parts(151_627_000, 0)
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `UserPrivileges::UserPrivileges` (r:1 w:0)
+	/// Proof: `UserPrivileges::UserPrivileges` (`max_values`: None, `max_size`: Some(48), added: 2523, mode: `MaxEncodedLen`)
+	/// Storage: `UserPrivileges::PrivilegeExpiry` (r:1 w:0)
+	/// Proof: `UserPrivileges::PrivilegeExpiry` (`max_values`: None, `max_size`: Some(49), added: 2524, mode: `MaxEncodedLen`)
+	/// Storage: `UserPrivileges::RequiredApprovals` (r:1 w:0)
+	/// Proof: `UserPrivileges::RequiredApprovals` (`max_values`: None, `max_size`: Some(13), added: 2488, mode: `MaxEncodedLen`)
+	/// Storage: `UserPrivileges::NextProposalId` (r:1 w:1)
+	/// Proof: `UserPrivileges::NextProposalId` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `UserPrivileges::Proposals` (r:0 w:1)
+	/// Proof: `UserPrivileges::Proposals` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	fn propose() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `142`
+		//  Estimated: `3514`
+		// Minimum execution time: 356_412_000 picoseconds.
+		Weight::from_parts(362_870_000, 3514)
+			.saturating_add(RocksDbWeight::get().reads(4_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+	/// Storage: `UserPrivileges::Proposals` (r:1 w:1)
+	/// Proof: `UserPrivileges::Proposals` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `UserPrivileges::UserPrivileges` (r:17 w:0)
+	/// Proof: `UserPrivileges::UserPrivileges` (`max_values`: None, `max_size`: Some(48), added: 2523, mode: `MaxEncodedLen`)
+	/// Storage: `UserPrivileges::PrivilegeExpiry` (r:17 w:0)
+	/// Proof: `UserPrivileges::PrivilegeExpiry` (`max_values`: None, `max_size`: Some(49), added: 2524, mode: `MaxEncodedLen`)
+	/// Storage: `UserPrivileges::RequiredApprovals` (r:1 w:0)
+	/// Proof: `UserPrivileges::RequiredApprovals` (`max_values`: None, `max_size`: Some(13), added: 2488, mode: `MaxEncodedLen`)
+	/// Storage: `UserPrivileges::ApprovedExecution` (r:1 w:2)
+	/// Proof: `UserPrivileges::ApprovedExecution` (`max_values`: Some(1), `max_size`: Some(33), added: 528, mode: `MaxEncodedLen`)
+	/// The range of component `a` is `[2, 16]`.
+	fn approve(a: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `310 + a * (32 ±0)`
+		//  Estimated: `3775 + a * (5048 ±0)`
+		// Minimum execution time: 421_086_000 picoseconds.
+		Weight::from_parts(428_519_000, 3775)
+			// Standard Error: 41_306
+			.saturating_add(Weight::from_parts(33_918_240, 0).saturating_mul(a.into()))
+			.saturating_add(RocksDbWeight::get().reads(5_u64))
+			.saturating_add(RocksDbWeight::get().reads((2_u64).saturating_mul(a.into())))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
+			.saturating_add(Weight::from_parts(0, 5048).saturating_mul(a.into()))
+	}
+	/// Storage: `UserPrivileges::Proposals` (r:1 w:1)
+	/// Proof: `UserPrivileges::Proposals` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	fn remove_expired_proposal() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `201`
+		//  Estimated: `3666`
+		// Minimum execution time: 203_744_000 picoseconds.
+		Weight::from_parts(207_315_000, 3666)
 			.saturating_add(RocksDbWeight::get().reads(1_u64))
 			.saturating_add(RocksDbWeight::get().writes(1_u64))
 	}
@@ -1,4 +1,89 @@
+use crate::Vec;
+use codec::{Decode, Encode};
+use scale_info::TypeInfo;
 use sp_core::H160;
+use sp_runtime::Perbill;
+
+/// Registration and liveness of a device, as reported by the deeper node runtime api
+#[derive(
+    Decode, Encode, Clone, Debug, PartialEq, Eq, TypeInfo, serde::Serialize, serde::Deserialize,
+)]
+pub struct DeviceStatus<AccountId, BlockNumber> {
+    pub account_id: AccountId,
+    pub country: Vec<u8>,
+    /// server registration is active until this block
+    pub expire: BlockNumber,
+    /// block of the last heartbeat
+    pub im_online: Option<BlockNumber>,
+    pub onboard_time: Option<BlockNumber>,
+    pub eras_offline: u32,
+    pub endpoint: Option<EndpointRecord>,
+}
+
+/// Tunnel protocols a server accepts connections on
+#[derive(
+    Decode,
+    Encode,
+    Clone,
+    Copy,
+    Debug,
+    PartialEq,
+    Eq,
+    TypeInfo,
+    serde::Serialize,
+    serde::Deserialize,
+)]
+pub enum TunnelProtocol {
+    Trident,
+    WireGuard,
+    OpenVpn,
+    Ipsec,
+}
+
+/// Address a server can be reached at
+#[derive(
+    Decode,
+    Encode,
+    Clone,
+    Copy,
+    Debug,
+    PartialEq,
+    Eq,
+    TypeInfo,
+    serde::Serialize,
+    serde::Deserialize,
+)]
+pub enum IpAddress {
+    V4([u8; 4]),
+    V6([u8; 16]),
+}
+
+/// How clients reach a server: its addresses, ports and tunnel protocols
+#[derive(
+    Decode, Encode, Clone, Debug, PartialEq, Eq, TypeInfo, serde::Serialize, serde::Deserialize,
+)]
+pub struct Endpoint {
+    pub addresses: Vec<IpAddress>,
+    pub ports: Vec<u16>,
+    pub protocols: Vec<TunnelProtocol>,
+}
+
+/// Endpoint of a server as published on chain
+#[derive(
+    Decode, Encode, Clone, Debug, PartialEq, Eq, TypeInfo, serde::Serialize, serde::Deserialize,
+)]
+pub enum EndpointRecord {
+    Plain(Endpoint),
+    /// encoded `Endpoint` encrypted to a published public key, only holders of the
+    /// matching secret key can read it
+    Encrypted {
+        key: [u8; 32],
+        ciphertext: Vec<u8>,
+    },
+}
+
+/// Highest rating a client can give a server, the lowest is 1
+pub const MAX_RATING: u8 = 5;
 
 pub trait NodeInterface<AccountId, BlockNumber> {
     /// This function tells if the device has been offline for a day
@@ -10,6 +95,19 @@ pub trait NodeInterface<AccountId, BlockNumber> {
     /// This function returns how many eras the device has been offline
     fn get_eras_offline(account_id: &AccountId) -> u32;
 
+    /// This function returns the reliability of the device computed from its recent credit proofs
+    fn get_reliability_score(account_id: &AccountId) -> Perbill;
+
+    /// This function records a client rating, from 1 to MAX_RATING, of a server, counted
+    /// `weight` times
+    fn rate_server(server: &AccountId, rating: u8, weight: u64);
+
+    /// This function returns the reputation of a server from the time decayed client ratings
+    fn get_reputation(server: &AccountId) -> Perbill;
+
+    /// This function returns the daily sharing traffic of the latest credit proof of the device
+    fn get_daily_sharing_traffic(account_id: &AccountId) -> Option<u64>;
+
     /// This function returns evm address associated with account
     fn get_accounts_deeper_evm(account_id: &AccountId) -> Option<H160>;
 
@@ -30,6 +128,20 @@ impl<AccountId, BlockNumber> NodeInterface<AccountId, BlockNumber> for () {
         0
     }
 
+    fn get_reliability_score(_account_id: &AccountId) -> Perbill {
+        Perbill::one()
+    }
+
+    fn rate_server(_server: &AccountId, _rating: u8, _weight: u64) {}
+
+    fn get_reputation(_server: &AccountId) -> Perbill {
+        Perbill::from_percent(50)
+    }
+
+    fn get_daily_sharing_traffic(_account_id: &AccountId) -> Option<u64> {
+        None
+    }
+
     fn get_accounts_deeper_evm(_account_id: &AccountId) -> Option<H160> {
         None
     }
@@ -21,14 +21,15 @@
 
 pub mod credit;
 pub mod deeper_node;
+pub mod sweep;
 pub mod user_privileges;
 
-use codec::Decode;
-use scale_info::prelude::vec::Vec;
+use codec::{Decode, Encode};
+use scale_info::{prelude::vec::Vec, TypeInfo};
 use sp_runtime::{
     generic,
     traits::{BlakeTwo256, IdentifyAccount, TrailingZeroInput, Verify},
-    MultiSignature, OpaqueExtrinsic,
+    MultiSignature, OpaqueExtrinsic, RuntimeDebug,
 };
 
 pub const DPR: u128 = 1_000_000_000_000_000_000;
@@ -74,18 +75,62 @@ pub type Block = generic::Block<Header, OpaqueExtrinsic>;
 /// Block ID.
 pub type BlockId = generic::BlockId<Block>;
 
+/// What an atomos attestation may be used for
+#[derive(Decode, Encode, Clone, Copy, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+pub enum AtmosPurpose {
+    Traffic,
+    StakingDelegate,
+    CreditProof,
+    RewardMapping,
+}
+
+impl AtmosPurpose {
+    /// Messages which move value need an aggregated attestation of the atomos signers
+    pub fn needs_attestation(&self) -> bool {
+        matches!(self, Self::StakingDelegate | Self::RewardMapping)
+    }
+}
+
+/// The message atomos signs. `payload_hash` is the blake2_256 hash of the encoded call
+/// arguments the attestation covers.
+#[derive(Decode, Encode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+pub struct AtmosMessage<AccountId, BlockNumber, Hash> {
+    pub purpose: AtmosPurpose,
+    pub genesis_hash: Hash,
+    pub nonce: u64,
+    pub sender: AccountId,
+    pub valid_until: BlockNumber,
+    pub payload_hash: [u8; 32],
+}
+
 /// Interface for verify device signature
-pub trait VerifySignatureInterface<AccountId> {
-    /// verify device signature
-    fn verify_atomos_signature(nonce: u64, signature: Vec<u8>, sender: AccountId) -> bool;
-    fn verify_atomos_new_signature(nonce: u64, signature: Vec<u8>, sender: AccountId) -> bool;
+pub trait VerifySignatureInterface<AccountId, BlockNumber> {
+    /// verify an atomos signature for `purpose`. For a purpose which needs an attestation,
+    /// `signature` is the concatenation of the signatures of enough atomos signers.
+    fn verify_atomos_signature(
+        purpose: AtmosPurpose,
+        nonce: u64,
+        valid_until: BlockNumber,
+        payload_hash: [u8; 32],
+        signature: Vec<u8>,
+        sender: AccountId,
+    ) -> bool;
+    /// consume `nonce` if it is the next atomos nonce of `sender`
+    fn consume_atomos_nonce(sender: &AccountId, nonce: u64) -> bool;
 }
 
-impl<AccountId> VerifySignatureInterface<AccountId> for () {
-    fn verify_atomos_signature(_nonce: u64, _signature: Vec<u8>, _sender: AccountId) -> bool {
+impl<AccountId, BlockNumber> VerifySignatureInterface<AccountId, BlockNumber> for () {
+    fn verify_atomos_signature(
+        _purpose: AtmosPurpose,
+        _nonce: u64,
+        _valid_until: BlockNumber,
+        _payload_hash: [u8; 32],
+        _signature: Vec<u8>,
+        _sender: AccountId,
+    ) -> bool {
         true
     }
-    fn verify_atomos_new_signature(_nonce: u64, _signature: Vec<u8>, _sender: AccountId) -> bool {
+    fn consume_atomos_nonce(_sender: &AccountId, _nonce: u64) -> bool {
         true
     }
 }
@@ -0,0 +1,61 @@
+use codec::FullCodec;
+use frame_support::{
+    storage::{IterableStorageDoubleMap, StorageValue},
+    weights::{RuntimeDbWeight, Weight},
+};
+use scale_info::prelude::vec::Vec;
+use sp_runtime::traits::{AtLeast32BitUnsigned, One, SaturatedConversion};
+
+/// Expire the entries of an expiration map keyed by block, from the block stored in `Cursor`
+/// up to but excluding `end`, calling `expire` with the block and the key of each entry.
+///
+/// Sweeping stops once `remaining_weight` cannot cover reading the next block or expiring
+/// one more entry (`per_item`); the cursor is left on the first block not fully swept.
+/// Returns the weight consumed, including reading and writing the cursor.
+pub fn sweep_expirations<Cursor, Expirations, B, K, V>(
+    end: B,
+    remaining_weight: Weight,
+    db_weight: RuntimeDbWeight,
+    per_item: Weight,
+    mut expire: impl FnMut(B, K),
+) -> Weight
+where
+    Cursor: StorageValue<B, Query = B>,
+    Expirations: IterableStorageDoubleMap<B, K, V>,
+    B: AtLeast32BitUnsigned + FullCodec + Copy,
+    K: FullCodec,
+    V: FullCodec,
+{
+    // read and write the sweep cursor
+    let mut consumed = db_weight.reads_writes(1, 1);
+    if remaining_weight.any_lt(consumed) {
+        return Weight::zero();
+    }
+    let mut block = Cursor::get();
+    while block < end {
+        // reading the expiration prefix of one block
+        if remaining_weight.any_lt(consumed.saturating_add(db_weight.reads(1))) {
+            break;
+        }
+        consumed = consumed.saturating_add(db_weight.reads(1));
+        let budget: usize = remaining_weight
+            .saturating_sub(consumed)
+            .checked_div_per_component(&per_item)
+            .unwrap_or(u64::MAX)
+            .saturated_into();
+        let expired: Vec<K> = Expirations::iter_key_prefix(block)
+            .take(budget.saturating_add(1))
+            .collect();
+        let drained = expired.len() <= budget;
+        for key in expired.into_iter().take(budget) {
+            expire(block, key);
+            consumed = consumed.saturating_add(per_item);
+        }
+        if !drained {
+            break;
+        }
+        block = block.saturating_add(One::one());
+    }
+    Cursor::put(block);
+    consumed
+}
@@ -2,11 +2,22 @@ use codec::{Decode, Encode, EncodeLike, MaxEncodedLen};
 use enumflags2::{bitflags, BitFlags};
 use scale_info::{build::Fields, meta_type, prelude::vec, Path, Type, TypeInfo, TypeParameter};
 use sp_core::H160;
-use sp_runtime::RuntimeDebug;
+use sp_runtime::{DispatchResult, RuntimeDebug};
 
 pub trait UserPrivilegeInterface<Account> {
     fn has_privilege(user: &Account, p: Privilege) -> bool;
     fn has_evm_privilege(user: &H160, p: Privilege) -> bool;
+    /// Like `has_privilege`, but when `p` requires several approvals it only holds while a
+    /// proposal of `user` for `p` has been approved and is being executed.
+    fn has_approved_privilege(user: &Account, p: Privilege) -> bool;
+    /// Consume `amount` from today's quota of `resource` under `p` for `user`, fails when the
+    /// per account or the global quota would be exceeded. `amount` is in the unit of `resource`.
+    fn consume_quota(
+        user: &Account,
+        p: Privilege,
+        resource: QuotaResource,
+        amount: u128,
+    ) -> DispatchResult;
 }
 
 impl<Account> UserPrivilegeInterface<Account> for () {
@@ -17,11 +28,53 @@ impl<Account> UserPrivilegeInterface<Account> for () {
     fn has_evm_privilege(_user: &H160, _p: Privilege) -> bool {
         true
     }
+
+    fn has_approved_privilege(_user: &Account, _p: Privilege) -> bool {
+        true
+    }
+
+    fn consume_quota(
+        _user: &Account,
+        _p: Privilege,
+        _resource: QuotaResource,
+        _amount: u128,
+    ) -> DispatchResult {
+        Ok(())
+    }
+}
+
+/// What a privilege quota limits, each resource has its own quota and unit
+#[derive(Clone, Copy, PartialEq, Eq, Encode, Decode, MaxEncodedLen, TypeInfo, RuntimeDebug)]
+pub enum QuotaResource {
+    /// npow rewards minted, in DPR
+    #[codec(index = 0)]
+    NpowMint,
+    /// adsc minted for bridged transfers, in ADSC
+    #[codec(index = 1)]
+    AdscMint,
+    /// user staking credit scores set, counted per score
+    #[codec(index = 2)]
+    StakingCredit,
+    /// inbound bridge transfers credited, in DPR
+    #[codec(index = 3)]
+    BridgeTransfer,
 }
 
 #[bitflags]
 #[repr(u64)]
-#[derive(Clone, Copy, PartialEq, Eq, Encode, Decode, TypeInfo, RuntimeDebug)]
+#[derive(
+    Clone,
+    Copy,
+    PartialEq,
+    Eq,
+    Encode,
+    Decode,
+    MaxEncodedLen,
+    TypeInfo,
+    RuntimeDebug,
+    serde::Serialize,
+    serde::Deserialize,
+)]
 pub enum Privilege {
     #[codec(index = 0)]
     LockerMember = 1 << 0, // 1
@@ -47,6 +100,8 @@ pub enum Privilege {
     BlackListAdmin = 1 << 10, // 1024
     #[codec(index = 11)]
     AdstAdmin = 1 << 11, // 2048
+    #[codec(index = 12)]
+    AtmosRelayer = 1 << 12, // 4096
 }
 
 /// Wrapper type for `BitFlags<Privilege>` that implements `Codec`.
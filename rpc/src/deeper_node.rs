@@ -0,0 +1,143 @@
+//! RPC interface for the deeper node pallet: server directory and device status.
+
+use std::{marker::PhantomData, sync::Arc};
+
+use codec::Codec;
+use jsonrpsee::{
+    core::RpcResult,
+    proc_macros::rpc,
+    types::error::{CallError, ErrorObject},
+};
+use node_primitives::deeper_node::{DeviceStatus, EndpointRecord};
+pub use node_runtime::deeper_node_api::DeeperNodeApi as DeeperNodeRuntimeApi;
+use sp_api::ProvideRuntimeApi;
+use sp_blockchain::HeaderBackend;
+use sp_runtime::traits::Block as BlockT;
+
+/// Error code returned when the runtime api call fails.
+const RUNTIME_ERROR: i32 = 1;
+
+#[rpc(server)]
+pub trait DeeperNodeApi<BlockHash, AccountId, BlockNumber> {
+    /// Active servers of a country and their endpoints, at most `limit`, starting after the
+    /// `start_after` cursor.
+    #[method(name = "deeperNode_serversByCountry")]
+    fn servers_by_country(
+        &self,
+        country: String,
+        start_after: Option<AccountId>,
+        limit: u32,
+        at: Option<BlockHash>,
+    ) -> RpcResult<Vec<(AccountId, Option<EndpointRecord>)>>;
+
+    /// Active servers of a region and their endpoints, at most `limit`, starting after the
+    /// `start_after` cursor.
+    #[method(name = "deeperNode_serversByRegion")]
+    fn servers_by_region(
+        &self,
+        region: String,
+        start_after: Option<AccountId>,
+        limit: u32,
+        at: Option<BlockHash>,
+    ) -> RpcResult<Vec<(AccountId, Option<EndpointRecord>)>>;
+
+    /// Registration, last heartbeat and eras offline of a device.
+    #[method(name = "deeperNode_deviceStatus")]
+    fn device_status(
+        &self,
+        account: AccountId,
+        at: Option<BlockHash>,
+    ) -> RpcResult<Option<DeviceStatus<AccountId, BlockNumber>>>;
+
+    /// All `(code, parent code)` pairs of the region map.
+    #[method(name = "deeperNode_regionTree")]
+    fn region_tree(&self, at: Option<BlockHash>) -> RpcResult<Vec<(String, String)>>;
+}
+
+/// Provides RPC methods to query the deeper node directory.
+pub struct DeeperNode<C, B> {
+    client: Arc<C>,
+    _marker: PhantomData<B>,
+}
+
+impl<C, B> DeeperNode<C, B> {
+    /// Create new `DeeperNode` with the given reference to the client.
+    pub fn new(client: Arc<C>) -> Self {
+        Self {
+            client,
+            _marker: Default::default(),
+        }
+    }
+}
+
+fn runtime_error(error: impl ToString) -> jsonrpsee::core::Error {
+    CallError::Custom(ErrorObject::owned(
+        RUNTIME_ERROR,
+        "Unable to query deeper node directory.",
+        Some(error.to_string()),
+    ))
+    .into()
+}
+
+impl<C, Block, AccountId, BlockNumber>
+    DeeperNodeApiServer<<Block as BlockT>::Hash, AccountId, BlockNumber> for DeeperNode<C, Block>
+where
+    Block: BlockT,
+    C: ProvideRuntimeApi<Block> + HeaderBackend<Block> + Send + Sync + 'static,
+    C::Api: DeeperNodeRuntimeApi<Block, AccountId, BlockNumber>,
+    AccountId: Codec + Clone + Send + Sync + 'static,
+    BlockNumber: Codec + Clone + Send + Sync + 'static,
+    AccountId: serde::Serialize + serde::de::DeserializeOwned,
+    BlockNumber: serde::Serialize + serde::de::DeserializeOwned,
+{
+    fn servers_by_country(
+        &self,
+        country: String,
+        start_after: Option<AccountId>,
+        limit: u32,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> RpcResult<Vec<(AccountId, Option<EndpointRecord>)>> {
+        let api = self.client.runtime_api();
+        let at = at.unwrap_or_else(|| self.client.info().best_hash);
+        api.servers_by_country(at, country.into_bytes(), start_after, limit)
+            .map_err(runtime_error)
+    }
+
+    fn servers_by_region(
+        &self,
+        region: String,
+        start_after: Option<AccountId>,
+        limit: u32,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> RpcResult<Vec<(AccountId, Option<EndpointRecord>)>> {
+        let api = self.client.runtime_api();
+        let at = at.unwrap_or_else(|| self.client.info().best_hash);
+        api.servers_by_region(at, region.into_bytes(), start_after, limit)
+            .map_err(runtime_error)
+    }
+
+    fn device_status(
+        &self,
+        account: AccountId,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> RpcResult<Option<DeviceStatus<AccountId, BlockNumber>>> {
+        let api = self.client.runtime_api();
+        let at = at.unwrap_or_else(|| self.client.info().best_hash);
+        api.device_status(at, account).map_err(runtime_error)
+    }
+
+    fn region_tree(&self, at: Option<<Block as BlockT>::Hash>) -> RpcResult<Vec<(String, String)>> {
+        let api = self.client.runtime_api();
+        let at = at.unwrap_or_else(|| self.client.info().best_hash);
+        let tree = api.region_tree(at).map_err(runtime_error)?;
+        Ok(tree
+            .into_iter()
+            .map(|(code, parent)| {
+                (
+                    String::from_utf8_lossy(&code).into_owned(),
+                    String::from_utf8_lossy(&parent).into_owned(),
+                )
+            })
+            .collect())
+    }
+}
@@ -30,7 +30,9 @@
 
 //#![warn(missing_docs)]
 //#![warn(unused_crate_dependencies)]
+pub mod deeper_node;
 pub mod eth;
+pub mod user_privileges;
 pub use eth::*;
 
 use std::sync::Arc;
@@ -154,6 +156,8 @@ where
     C::Api: substrate_frame_rpc_system::AccountNonceApi<Block, AccountId, Nonce>,
     C::Api: mmr_rpc::MmrRuntimeApi<Block, <Block as sp_runtime::traits::Block>::Hash, BlockNumber>,
     C::Api: pallet_transaction_payment_rpc::TransactionPaymentRuntimeApi<Block, Balance>,
+    C::Api: deeper_node::DeeperNodeRuntimeApi<Block, AccountId, BlockNumber>,
+    C::Api: user_privileges::UserPrivilegesRuntimeApi<Block, AccountId>,
     C::Api: BabeApi<Block>,
     C::Api: BlockBuilder<Block>,
     C::Api: fp_rpc::ConvertTransactionRuntimeApi<Block>,
@@ -167,6 +171,7 @@ where
     CIDP: CreateInherentDataProviders<Block, ()> + Send + 'static,
     CT: fp_rpc::ConvertTransaction<<Block as BlockT>::Extrinsic> + Send + Sync + 'static,
 {
+    use deeper_node::{DeeperNode, DeeperNodeApiServer};
     use mmr_rpc::{Mmr, MmrApiServer};
     use pallet_transaction_payment_rpc::{TransactionPayment, TransactionPaymentApiServer};
     use sc_consensus_babe_rpc::{Babe, BabeApiServer};
@@ -179,6 +184,7 @@ where
     use sc_sync_state_rpc::{SyncState, SyncStateApiServer};
     use substrate_frame_rpc_system::{System, SystemApiServer};
     use substrate_state_trie_migration_rpc::{StateMigration, StateMigrationApiServer};
+    use user_privileges::{UserPrivileges, UserPrivilegesApiServer};
 
     let mut io = RpcModule::new(());
 
@@ -217,6 +223,8 @@ where
         .into_rpc(),
     )?;
     io.merge(TransactionPayment::new(client.clone()).into_rpc())?;
+    io.merge(DeeperNode::new(client.clone()).into_rpc())?;
+    io.merge(UserPrivileges::new(client.clone()).into_rpc())?;
     io.merge(
         Babe::new(
             client.clone(),
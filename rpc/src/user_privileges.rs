@@ -0,0 +1,123 @@
+//! RPC interface for the user privileges pallet: privilege holders and privileges of an account.
+
+use std::{marker::PhantomData, sync::Arc};
+
+use codec::Codec;
+use jsonrpsee::{
+    core::RpcResult,
+    proc_macros::rpc,
+    types::error::{CallError, ErrorObject},
+};
+use node_primitives::user_privileges::Privilege;
+pub use node_runtime::user_privileges_api::UserPrivilegesApi as UserPrivilegesRuntimeApi;
+use sp_api::ProvideRuntimeApi;
+use sp_blockchain::HeaderBackend;
+use sp_core::H160;
+use sp_runtime::traits::Block as BlockT;
+
+/// Error code returned when the runtime api call fails.
+const RUNTIME_ERROR: i32 = 1;
+
+#[rpc(server)]
+pub trait UserPrivilegesApi<BlockHash, AccountId> {
+    /// Accounts holding an unexpired privilege.
+    #[method(name = "userPrivileges_privilegesHolders")]
+    fn privileges_holders(
+        &self,
+        privilege: Privilege,
+        at: Option<BlockHash>,
+    ) -> RpcResult<Vec<AccountId>>;
+
+    /// Evm addresses holding an unexpired privilege.
+    #[method(name = "userPrivileges_evmPrivilegesHolders")]
+    fn evm_privileges_holders(
+        &self,
+        privilege: Privilege,
+        at: Option<BlockHash>,
+    ) -> RpcResult<Vec<H160>>;
+
+    /// Unexpired privileges of an account.
+    #[method(name = "userPrivileges_privilegesOf")]
+    fn privileges_of(&self, account: AccountId, at: Option<BlockHash>)
+        -> RpcResult<Vec<Privilege>>;
+
+    /// Unexpired privileges of an evm address.
+    #[method(name = "userPrivileges_evmPrivilegesOf")]
+    fn evm_privileges_of(&self, address: H160, at: Option<BlockHash>) -> RpcResult<Vec<Privilege>>;
+}
+
+/// Provides RPC methods to audit user privileges.
+pub struct UserPrivileges<C, B> {
+    client: Arc<C>,
+    _marker: PhantomData<B>,
+}
+
+impl<C, B> UserPrivileges<C, B> {
+    /// Create new `UserPrivileges` with the given reference to the client.
+    pub fn new(client: Arc<C>) -> Self {
+        Self {
+            client,
+            _marker: Default::default(),
+        }
+    }
+}
+
+fn runtime_error(error: impl ToString) -> jsonrpsee::core::Error {
+    CallError::Custom(ErrorObject::owned(
+        RUNTIME_ERROR,
+        "Unable to query user privileges.",
+        Some(error.to_string()),
+    ))
+    .into()
+}
+
+impl<C, Block, AccountId> UserPrivilegesApiServer<<Block as BlockT>::Hash, AccountId>
+    for UserPrivileges<C, Block>
+where
+    Block: BlockT,
+    C: ProvideRuntimeApi<Block> + HeaderBackend<Block> + Send + Sync + 'static,
+    C::Api: UserPrivilegesRuntimeApi<Block, AccountId>,
+    AccountId: Codec + Clone + Send + Sync + 'static,
+    AccountId: serde::Serialize + serde::de::DeserializeOwned,
+{
+    fn privileges_holders(
+        &self,
+        privilege: Privilege,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> RpcResult<Vec<AccountId>> {
+        let api = self.client.runtime_api();
+        let at = at.unwrap_or_else(|| self.client.info().best_hash);
+        api.privileges_holders(at, privilege).map_err(runtime_error)
+    }
+
+    fn evm_privileges_holders(
+        &self,
+        privilege: Privilege,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> RpcResult<Vec<H160>> {
+        let api = self.client.runtime_api();
+        let at = at.unwrap_or_else(|| self.client.info().best_hash);
+        api.evm_privileges_holders(at, privilege)
+            .map_err(runtime_error)
+    }
+
+    fn privileges_of(
+        &self,
+        account: AccountId,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> RpcResult<Vec<Privilege>> {
+        let api = self.client.runtime_api();
+        let at = at.unwrap_or_else(|| self.client.info().best_hash);
+        api.privileges_of(at, account).map_err(runtime_error)
+    }
+
+    fn evm_privileges_of(
+        &self,
+        address: H160,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> RpcResult<Vec<Privilege>> {
+        let api = self.client.runtime_api();
+        let at = at.unwrap_or_else(|| self.client.info().best_hash);
+        api.evm_privileges_of(at, address).map_err(runtime_error)
+    }
+}
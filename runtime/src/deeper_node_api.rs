@@ -0,0 +1,33 @@
+use codec::Codec;
+use node_primitives::deeper_node::{DeviceStatus, EndpointRecord};
+use sp_std::vec::Vec;
+
+sp_api::decl_runtime_apis! {
+    pub trait DeeperNodeApi<AccountId, BlockNumber>
+    where
+        AccountId: Codec,
+        BlockNumber: Codec,
+    {
+        /// Returns at most `limit` active servers of a country and their endpoints,
+        /// in storage order, starting after the `start_after` cursor.
+        fn servers_by_country(
+            country: Vec<u8>,
+            start_after: Option<AccountId>,
+            limit: u32,
+        ) -> Vec<(AccountId, Option<EndpointRecord>)>;
+
+        /// Returns at most `limit` active servers of a region and their endpoints,
+        /// in storage order, starting after the `start_after` cursor.
+        fn servers_by_region(
+            region: Vec<u8>,
+            start_after: Option<AccountId>,
+            limit: u32,
+        ) -> Vec<(AccountId, Option<EndpointRecord>)>;
+
+        /// Returns the registration, last heartbeat and eras offline of a device.
+        fn device_status(account: AccountId) -> Option<DeviceStatus<AccountId, BlockNumber>>;
+
+        /// Returns all `(code, parent code)` pairs of the region map.
+        fn region_tree() -> Vec<(Vec<u8>, Vec<u8>)>;
+    }
+}
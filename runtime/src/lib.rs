@@ -44,6 +44,7 @@ use frame_system::{
     limits::{BlockLength, BlockWeights},
     EnsureRoot,
 };
+use node_primitives::user_privileges::Privilege;
 pub use node_primitives::{
     AccountId, AccountIndex, Balance, BlockNumber, Hash, Index, Moment, Nonce, Signature,
 };
@@ -94,6 +95,8 @@ mod precompiles;
 use precompiles::FrontierPrecompiles;
 
 pub mod assets_api;
+pub mod deeper_node_api;
+pub mod user_privileges_api;
 
 #[cfg(any(feature = "std", test))]
 pub use frame_system::Call as SystemCall;
@@ -508,10 +511,46 @@ impl pallet_balances::Config for Runtime {
     type MaxHolds = ConstU32<1>;
 }
 
+/// Calls checking `has_approved_privilege`, the only ones a privilege proposal may execute.
+pub struct PrivilegeGatedCalls;
+impl Contains<(Privilege, RuntimeCall)> for PrivilegeGatedCalls {
+    fn contains((privilege, call): &(Privilege, RuntimeCall)) -> bool {
+        // benchmarks propose a remark
+        #[cfg(feature = "runtime-benchmarks")]
+        if matches!(call, RuntimeCall::System(frame_system::Call::remark { .. })) {
+            return true;
+        }
+        match privilege {
+            Privilege::ReleaseSetter => matches!(
+                call,
+                RuntimeCall::Operation(pallet_operation::Call::unstaking_release { .. })
+                    | RuntimeCall::Staking(pallet_staking::Call::difference_compensation { .. })
+            ),
+            Privilege::NpowMint => matches!(
+                call,
+                RuntimeCall::Staking(pallet_staking::Call::npow_mint { .. })
+            ),
+            Privilege::BridgeAdmin => matches!(
+                call,
+                RuntimeCall::Operation(
+                    pallet_operation::Call::bridge_other_to_deeper { .. }
+                        | pallet_operation::Call::complete_bridge_application { .. }
+                )
+            ),
+            _ => false,
+        }
+    }
+}
+
 impl pallet_user_privileges::Config for Runtime {
     type RuntimeEvent = RuntimeEvent;
     type ForceOrigin = EnsureRoot<AccountId>;
     type WeightInfo = pallet_user_privileges::weights::SubstrateWeight<Runtime>;
+    type BlocksPerDay = ConstU32<DAYS>;
+    type RuntimeCall = RuntimeCall;
+    type ProposalTimeout = ConstU32<{ 3 * DAYS }>;
+    type MaxApprovals = ConstU32<16>;
+    type ProposableCalls = PrivilegeGatedCalls;
 }
 
 impl pallet_operation::Config for Runtime {
@@ -523,6 +562,9 @@ impl pallet_operation::Config for Runtime {
     type MinimumBurnedDPR = MinimumBurnedDPR;
     type CreditInterface = Credit;
     type UserPrivilegeInterface = UserPrivileges;
+    type MaxRelayers = ConstU32<16>;
+    type BridgeApplicationTimeout = ConstU32<DAYS>;
+    type CircuitBreakerOrigin = EnsureRootOrHalfCouncil;
 }
 
 parameter_types! {
@@ -1213,6 +1255,8 @@ parameter_types! {
     pub const SecsPerBlock: u32 = MILLISECS_PER_BLOCK as u32 / 1000;
     pub const DataPerDPR: u64 = 1024 * 1024 * 1024 * 1024;
     pub const MicropaymentBurn: Percent = Percent::from_percent(10);
+    pub const ChannelDeposit: Balance = 1 * CENTS;
+    pub const RatingWeightUnit: Balance = 1 * DOLLARS;
 }
 
 #[cfg(feature = "runtime-benchmarks")]
@@ -1251,6 +1295,8 @@ impl pallet_micropayment::Config for Runtime {
     type NodeInterface = DeeperNode;
     type MicropaymentBurn = MicropaymentBurn;
     type Slash = Treasury;
+    type ChannelDeposit = ChannelDeposit;
+    type RatingWeightUnit = RatingWeightUnit;
     #[cfg(feature = "runtime-benchmarks")]
     type AccountCreator = bench_mark_account::DefaultAccountCreator;
 }
@@ -1259,6 +1305,9 @@ parameter_types! {
     pub const MinLockAmt: u32 = 100000;
     pub const MaxDurationEras: u8 = 7;
     pub const MaxIpLength: usize = 256;
+    pub const DeeperNodeUnsignedPriority: TransactionPriority = TransactionPriority::max_value() / 2;
+    pub const MaxCreditProofs: u32 = 30;
+    pub const ReputationDecay: Perbill = Perbill::from_percent(90);
 }
 
 impl pallet_deeper_node::Config for Runtime {
@@ -1270,6 +1319,11 @@ impl pallet_deeper_node::Config for Runtime {
     type MaxIpLength = MaxIpLength;
     type WeightInfo = pallet_deeper_node::weights::SubstrateWeight<Runtime>;
     type VerifySignatureInterface = CreditAccumulation;
+    type RegionOrigin = EnsureRootOrHalfCouncil;
+    type DeviceSignature = Signature;
+    type UnsignedPriority = DeeperNodeUnsignedPriority;
+    type MaxCreditProofs = MaxCreditProofs;
+    type ReputationDecay = ReputationDecay;
 }
 
 parameter_types! {
@@ -1307,6 +1361,7 @@ impl pallet_uniques::Config for Runtime {
 
 parameter_types! {
     pub const MaxBurnCreditPerAddress: u32 = 50;
+    pub const AnomalyDeviations: u32 = 3;
 }
 
 impl pallet_credit::Config for Runtime {
@@ -1323,6 +1378,7 @@ impl pallet_credit::Config for Runtime {
     type BurnedTo = Treasury;
     type UserPrivilegeInterface = UserPrivileges;
     type MaxBurnCreditPerAddress = MaxBurnCreditPerAddress;
+    type AnomalyDeviations = AnomalyDeviations;
 }
 
 impl pallet_credit_accumulation::Config for Runtime {
@@ -1332,6 +1388,10 @@ impl pallet_credit_accumulation::Config for Runtime {
     type WeightInfo = pallet_credit_accumulation::weights::SubstrateWeight<Runtime>;
     #[cfg(feature = "runtime-benchmarks")]
     type AccountCreator = bench_mark_account::DefaultAccountCreator;
+    type MaxAtmosSigners = ConstU32<8>;
+    type UserPrivilegeInterface = UserPrivileges;
+    type MaxRelayBatch = ConstU32<64>;
+    type AtmosSignerGracePeriod = ConstU32<{ 7 * DAYS }>;
 }
 
 pub struct EvmDealWithFees;
@@ -1605,7 +1665,7 @@ construct_runtime!(
         Uniques: pallet_uniques::{Pallet, Call, Storage, Event<T>} = 49,
 
         Micropayment: pallet_micropayment::{Pallet, Call, Storage, Event<T>} = 60,
-        DeeperNode: pallet_deeper_node::{Pallet, Call, Storage, Event<T>, Config<T> } = 61,
+        DeeperNode: pallet_deeper_node::{Pallet, Call, Storage, Event<T>, Config<T>, ValidateUnsigned} = 61,
         CreditAccumulation: pallet_credit_accumulation::{Pallet, Call, Storage, Event<T>} = 62,
 
         Statement: pallet_statement::{Pallet, Storage, Event<T>} = 63,
@@ -1691,7 +1751,15 @@ pub type CheckedExtrinsic = generic::CheckedExtrinsic<AccountId, RuntimeCall, Si
 // 	generic::UncheckedExtrinsic<Address, RuntimeCall, Signature, SignedExtra>;
 /// Executive: handles dispatch to the various modules.
 
-pub type Migrations = (pallet_deeper_node::migration::v1::MigrateToV1<Runtime>,);
+pub type Migrations = (
+    pallet_deeper_node::migration::v1::MigrateToV1<Runtime>,
+    pallet_deeper_node::migration::v2::MigrateToV2<Runtime>,
+    pallet_deeper_node::migration::v3::MigrateToV3<Runtime>,
+    pallet_deeper_node::migration::v4::MigrateToV4<Runtime>,
+    pallet_deeper_node::migration::v5::MigrateToV5<Runtime>,
+    pallet_micropayment::migration::v1::MigrateToV1<Runtime>,
+    pallet_credit_accumulation::migration::v1::MigrateToV1<Runtime>,
+);
 
 pub type Executive = frame_executive::Executive<
     Runtime,
@@ -1981,6 +2049,52 @@ sp_api::impl_runtime_apis! {
         }
     }
 
+    impl deeper_node_api::DeeperNodeApi<Block, AccountId, BlockNumber> for Runtime {
+        fn servers_by_country(
+            country: Vec<u8>,
+            start_after: Option<AccountId>,
+            limit: u32,
+        ) -> Vec<(AccountId, Option<node_primitives::deeper_node::EndpointRecord>)> {
+            DeeperNode::active_servers_by_country(&country, start_after, limit)
+        }
+
+        fn servers_by_region(
+            region: Vec<u8>,
+            start_after: Option<AccountId>,
+            limit: u32,
+        ) -> Vec<(AccountId, Option<node_primitives::deeper_node::EndpointRecord>)> {
+            DeeperNode::active_servers_by_region(&region, start_after, limit)
+        }
+
+        fn device_status(
+            account: AccountId,
+        ) -> Option<node_primitives::deeper_node::DeviceStatus<AccountId, BlockNumber>> {
+            DeeperNode::device_status(&account)
+        }
+
+        fn region_tree() -> Vec<(Vec<u8>, Vec<u8>)> {
+            DeeperNode::region_tree()
+        }
+    }
+
+    impl user_privileges_api::UserPrivilegesApi<Block, AccountId> for Runtime {
+        fn privileges_holders(privilege: Privilege) -> Vec<AccountId> {
+            UserPrivileges::privilege_holders(privilege)
+        }
+
+        fn evm_privileges_holders(privilege: Privilege) -> Vec<H160> {
+            UserPrivileges::evm_privilege_holders(privilege)
+        }
+
+        fn privileges_of(account: AccountId) -> Vec<Privilege> {
+            UserPrivileges::privileges_of(&account)
+        }
+
+        fn evm_privileges_of(address: H160) -> Vec<Privilege> {
+            UserPrivileges::evm_privileges_of(&address)
+        }
+    }
+
     impl pallet_contracts::ContractsApi<Block, AccountId, Balance, BlockNumber, Hash, EventRecord>
         for Runtime
     {
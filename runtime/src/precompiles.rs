@@ -14,6 +14,7 @@ use pallet_evm_precompile_credit::CreditDispatch;
 use pallet_evm_precompile_curve25519::{Curve25519Add, Curve25519ScalarMul};
 use pallet_evm_precompile_dispatch::Dispatch;
 use pallet_evm_precompile_ed25519::Ed25519Verify;
+use pallet_evm_precompile_user_privileges::UserPrivilegesDispatch;
 
 pub struct FrontierPrecompiles<R>(PhantomData<R>);
 
@@ -25,7 +26,7 @@ where
         Self(Default::default())
     }
     pub fn used_addresses() -> sp_std::vec::Vec<H160> {
-        sp_std::vec![1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 1024, 1025, 1026, 1027, 1028, 1029]
+        sp_std::vec![1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 1024, 1025, 1026, 1027, 1028, 1029, 1030, 1031]
             .into_iter()
             .map(hash)
             .collect()
@@ -35,7 +36,8 @@ impl<R> PrecompileSet for FrontierPrecompiles<R>
 where
     CreditDispatch<R>: Precompile,
     Dispatch<R>: Precompile,
-    R: pallet_credit::Config + pallet_evm::Config,
+    UserPrivilegesDispatch<R>: Precompile,
+    R: pallet_credit::Config + pallet_user_privileges::Config + pallet_evm::Config,
 {
     fn execute(&self, handle: &mut impl PrecompileHandle) -> Option<PrecompileResult> {
         match handle.code_address() {
@@ -58,6 +60,7 @@ where
             a if a == hash(1028) => Some(Curve25519ScalarMul::execute(handle)),
             a if a == hash(1029) => Some(Ed25519Verify::execute(handle)),
             a if a == hash(1030) => Some(CreditDispatch::<R>::execute(handle)),
+            a if a == hash(1031) => Some(UserPrivilegesDispatch::<R>::execute(handle)),
             _ => None,
         }
     }
@@ -0,0 +1,23 @@
+use codec::Codec;
+use node_primitives::user_privileges::Privilege;
+use sp_core::H160;
+use sp_std::vec::Vec;
+
+sp_api::decl_runtime_apis! {
+    pub trait UserPrivilegesApi<AccountId>
+    where
+        AccountId: Codec,
+    {
+        /// Returns the accounts holding an unexpired privilege.
+        fn privileges_holders(privilege: Privilege) -> Vec<AccountId>;
+
+        /// Returns the evm addresses holding an unexpired privilege.
+        fn evm_privileges_holders(privilege: Privilege) -> Vec<H160>;
+
+        /// Returns the unexpired privileges of an account.
+        fn privileges_of(account: AccountId) -> Vec<Privilege>;
+
+        /// Returns the unexpired privileges of an evm address.
+        fn evm_privileges_of(address: H160) -> Vec<Privilege>;
+    }
+}